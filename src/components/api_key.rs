@@ -0,0 +1,305 @@
+use crate::components::{
+    encryption::EncryptionKey,
+    io::{fetch_file, save_file},
+};
+use chrono::prelude::*;
+use rocket::serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[derive(Default, Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ApiKey {
+    pub id: String,
+    pub name: String,
+    pub hashed: bool,
+    #[serde(skip_serializing)]
+    pub key: String,
+    pub created_at: String,
+}
+
+impl ApiKey {
+    fn create_no_check(id: &str, name: &str, hashed: bool, key: &str, created_at: &str) -> ApiKey {
+        ApiKey {
+            id: id.trim().to_string(),
+            name: name.trim().to_string(),
+            hashed: hashed,
+            key: key.trim().to_string(),
+            created_at: created_at.trim().to_string(),
+        }
+    }
+
+    pub fn create(
+        all_keys: &mut Vec<ApiKey>,
+        name: &str,
+        key: &str,
+        hashed: bool,
+    ) -> Result<String, (usize, String)> {
+        let new_id = EncryptionKey::generate_uuid(8);
+
+        let mut has_error: bool = false;
+        let mut latest_error: (usize, String) = (500, String::new());
+
+        let new_key = ApiKey {
+            id: new_id.clone(),
+            name: "".to_string(),
+            hashed: hashed,
+            key: "".to_string(),
+            created_at: Utc::now().to_string(),
+        };
+        all_keys.push(new_key);
+
+        if !has_error {
+            let name_update = Self::update_name(all_keys, &new_id, name);
+            if let Err(e) = name_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if !has_error {
+            let key_update = Self::update_key(all_keys, &new_id, key);
+            if let Err(e) = key_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if has_error {
+            let delete_key = Self::delete(all_keys, &new_id);
+            if let Err(e) = delete_key {
+                println!("{}", e.1);
+            }
+
+            return Err(latest_error);
+        }
+
+        Ok(new_id)
+    }
+
+    pub fn exist(all_keys: &Vec<ApiKey>, id: &str) -> bool {
+        let mut found = false;
+        for api_key in all_keys.iter() {
+            if api_key.id == id {
+                found = true;
+                break;
+            }
+        }
+
+        found
+    }
+
+    pub fn get(all_keys: &Vec<ApiKey>, id: &str) -> Result<ApiKey, (usize, String)> {
+        for api_key in all_keys.iter() {
+            if api_key.id == id {
+                return Ok(api_key.clone());
+            }
+        }
+
+        Err((404, String::from("Error: API key not found")))
+    }
+
+    pub fn hash(key: &str) -> String {
+        format!("{:x}", Sha256::digest(key.as_bytes()))
+    }
+
+    pub fn verify(all_keys: &Vec<ApiKey>, key: &str) -> Option<ApiKey> {
+        let key = key.trim();
+        if key.len() < 1 {
+            return None;
+        }
+
+        let hashed_key = ApiKey::hash(key);
+        for api_key in all_keys.iter() {
+            let candidate = if api_key.hashed { &hashed_key } else { key };
+            if constant_time_eq(api_key.key.as_bytes(), candidate.as_bytes()) {
+                return Some(api_key.clone());
+            }
+        }
+
+        None
+    }
+
+    pub fn update_name(
+        all_keys: &mut Vec<ApiKey>,
+        id: &String,
+        name: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut found_key: Option<ApiKey> = None;
+
+        if name.trim().len() < 1 || name.trim().len() > 100 {
+            return Err((
+                400,
+                String::from("Error: name should be between 1 and 100 characters"),
+            ));
+        }
+
+        if name.contains(";") || name.contains("\n") {
+            return Err((
+                400,
+                String::from("Error: name contains a character that is not allowed (;)"),
+            ));
+        }
+
+        for api_key in all_keys.iter_mut() {
+            if api_key.id == *id {
+                found_key = Some(api_key.clone());
+                api_key.name = name.trim().to_string();
+                break;
+            }
+        }
+
+        if let None = found_key {
+            return Err((404, String::from("Error: API key not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_key(
+        all_keys: &mut Vec<ApiKey>,
+        id: &String,
+        key: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut found_key: Option<ApiKey> = None;
+
+        if key.trim().len() < 16 {
+            return Err((
+                400,
+                String::from("Error: key should be at least 16 characters long"),
+            ));
+        }
+
+        if key.contains(";") || key.trim().contains(" ") {
+            return Err((
+                400,
+                String::from("Error: key contains an invalid character"),
+            ));
+        }
+
+        if let Some(_) = ApiKey::verify(all_keys, key) {
+            return Err((400, String::from("Error: key already in use")));
+        }
+
+        for api_key in all_keys.iter_mut() {
+            if api_key.id == *id {
+                found_key = Some(api_key.clone());
+                api_key.key = if api_key.hashed {
+                    ApiKey::hash(key.trim())
+                } else {
+                    key.trim().to_string()
+                };
+                break;
+            }
+        }
+
+        if let None = found_key {
+            return Err((404, String::from("Error: API key not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn delete(all_keys: &mut Vec<ApiKey>, id: &str) -> Result<(), (usize, String)> {
+        let mut found_key: Option<ApiKey> = None;
+
+        for api_key in all_keys.iter_mut() {
+            if api_key.id == id.to_string() {
+                found_key = Some(api_key.clone());
+                break;
+            }
+        }
+
+        if let None = found_key {
+            return Err((404, String::from("Error: API key not found")));
+        }
+
+        let updated_keys: Vec<ApiKey> = all_keys
+            .iter_mut()
+            .filter(|api_key| api_key.id != *id)
+            .map(|api_key| api_key.clone())
+            .collect::<Vec<ApiKey>>();
+
+        *all_keys = updated_keys;
+
+        Ok(())
+    }
+
+    pub fn to_string(api_key: ApiKey) -> String {
+        format!(
+            "{};{};{};{};{}",
+            api_key.id, api_key.name, api_key.hashed, api_key.key, api_key.created_at
+        )
+    }
+
+    pub fn from_string(api_key_str: &str) -> ApiKey {
+        let current_key = api_key_str.split(";").collect::<Vec<&str>>();
+
+        ApiKey::create_no_check(
+            current_key[0],
+            current_key[1],
+            current_key[2] == "true",
+            current_key[3],
+            current_key[4],
+        )
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut diff: u8 = 0;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+
+    diff == 0
+}
+
+pub fn stringify_api_keys(api_keys: &Vec<ApiKey>) -> String {
+    let mut stringified_keys = String::new();
+
+    for api_key in api_keys {
+        stringified_keys = format!(
+            "{}{}{}",
+            stringified_keys,
+            if stringified_keys.chars().count() > 1 {
+                "\n"
+            } else {
+                ""
+            },
+            ApiKey::to_string(api_key.clone()),
+        );
+    }
+
+    stringified_keys
+}
+
+pub fn unwrap_api_keys(all_keys_raw: String) -> Vec<ApiKey> {
+    let individual_keys = all_keys_raw
+        .split("\n")
+        .filter(|line| line.split(";").count() >= 5);
+
+    let mut final_keys: Vec<ApiKey> = Vec::<ApiKey>::new();
+
+    for api_key in individual_keys {
+        let tmp_key = ApiKey::from_string(api_key);
+        final_keys.push(tmp_key);
+    }
+
+    final_keys
+}
+
+pub fn fetch_all_api_keys(path: String, encryption_key: &String) -> Vec<ApiKey> {
+    let all_keys_raw = fetch_file(path.clone(), encryption_key);
+    let final_keys = unwrap_api_keys(all_keys_raw);
+    final_keys
+}
+
+pub fn save_all_api_keys(api_keys: &Vec<ApiKey>, path: String, encryption_key: &String) {
+    let stringified_keys = stringify_api_keys(api_keys);
+    save_file(path, stringified_keys, encryption_key);
+    println!("API keys saved!");
+}
@@ -37,6 +37,18 @@ pub mod datapair;
 #[path = "event.rs"]
 pub mod event;
 
+#[path = "cron.rs"]
+pub mod cron;
+
+#[path = "schedule.rs"]
+pub mod schedule;
+
+#[path = "api_key.rs"]
+pub mod api_key;
+
+#[path = "data_log.rs"]
+pub mod data_log;
+
 #[path = "raw_pair.rs"]
 pub mod raw_pair;
 
@@ -0,0 +1,191 @@
+use chrono::prelude::*;
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct CronExpression {
+    pub minutes: Vec<u32>,
+    pub hours: Vec<u32>,
+    pub days_of_month: Vec<u32>,
+    pub months: Vec<u32>,
+    pub days_of_week: Vec<u32>,
+    restricted_day_of_month: bool,
+    restricted_day_of_week: bool,
+}
+
+impl CronExpression {
+    pub fn parse(expression: &str) -> Result<CronExpression, (usize, String)> {
+        let expanded = match expression.trim().to_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *".to_string(),
+            "@monthly" => "0 0 1 * *".to_string(),
+            "@weekly" => "0 0 * * 0".to_string(),
+            "@daily" | "@midnight" => "0 0 * * *".to_string(),
+            "@hourly" => "0 * * * *".to_string(),
+            _ => expression.trim().to_string(),
+        };
+
+        let fields = expanded.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() != 5 {
+            return Err((
+                400,
+                String::from("Error: cron expression should contain exactly 5 fields"),
+            ));
+        }
+
+        let minutes = match Self::parse_field(fields[0], "minute", 0, 59) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+        let hours = match Self::parse_field(fields[1], "hour", 0, 23) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+        let days_of_month = match Self::parse_field(fields[2], "day of month", 1, 31) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+        let months = match Self::parse_field(fields[3], "month", 1, 12) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+        let mut days_of_week = match Self::parse_field(fields[4], "day of week", 0, 7) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+
+        if days_of_week.contains(&7) {
+            days_of_week.retain(|day| *day != 7);
+            if !days_of_week.contains(&0) {
+                days_of_week.insert(0, 0);
+            }
+        }
+
+        Ok(CronExpression {
+            minutes: minutes,
+            hours: hours,
+            days_of_month: days_of_month,
+            months: months,
+            days_of_week: days_of_week,
+            restricted_day_of_month: !fields[2].starts_with("*"),
+            restricted_day_of_week: !fields[4].starts_with("*"),
+        })
+    }
+
+    fn parse_field(
+        field: &str,
+        name: &str,
+        min: u32,
+        max: u32,
+    ) -> Result<Vec<u32>, (usize, String)> {
+        let invalid = || {
+            (
+                400,
+                format!(
+                    "Error: Invalid {} field '{}' in cron expression",
+                    name, field
+                ),
+            )
+        };
+        let mut values = Vec::<u32>::new();
+
+        for part in field.split(",") {
+            let range_step = part.split("/").collect::<Vec<&str>>();
+            if range_step.len() > 2 {
+                return Err(invalid());
+            }
+
+            let step = if range_step.len() == 2 {
+                match range_step[1].parse::<u32>() {
+                    Ok(s) if s > 0 => s,
+                    _ => return Err(invalid()),
+                }
+            } else {
+                1
+            };
+
+            let (start, end) = if range_step[0] == "*" {
+                (min, max)
+            } else {
+                let bounds = range_step[0].split("-").collect::<Vec<&str>>();
+                let start = match bounds[0].parse::<u32>() {
+                    Ok(v) => v,
+                    Err(_) => return Err(invalid()),
+                };
+
+                if bounds.len() == 1 {
+                    (start, if range_step.len() == 2 { max } else { start })
+                } else if bounds.len() == 2 {
+                    match bounds[1].parse::<u32>() {
+                        Ok(v) => (start, v),
+                        Err(_) => return Err(invalid()),
+                    }
+                } else {
+                    return Err(invalid());
+                }
+            };
+
+            if start < min || end > max || start > end {
+                return Err(invalid());
+            }
+
+            let mut value = start;
+            while value <= end {
+                if !values.contains(&value) {
+                    values.push(value);
+                }
+                value += step;
+            }
+        }
+
+        values.sort();
+        Ok(values)
+    }
+
+    fn matches_day(&self, time: &DateTime<Utc>) -> bool {
+        let day_of_month = self.days_of_month.contains(&time.day());
+        let day_of_week = self
+            .days_of_week
+            .contains(&time.weekday().num_days_from_sunday());
+
+        if self.restricted_day_of_month && self.restricted_day_of_week {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+
+    pub fn matches(&self, time: &DateTime<Utc>) -> bool {
+        self.minutes.contains(&time.minute())
+            && self.hours.contains(&time.hour())
+            && self.months.contains(&time.month())
+            && self.matches_day(time)
+    }
+
+    pub fn occurrences(&self, after: i64, until: i64, limit: usize) -> Vec<i64> {
+        let mut found = Vec::<i64>::new();
+        let mut current = (after.div_euclid(60) + 1) * 60;
+
+        while current <= until && found.len() < limit {
+            let time = match Utc.timestamp_opt(current, 0).single() {
+                Some(t) => t,
+                None => break,
+            };
+
+            if !self.months.contains(&time.month()) || !self.matches_day(&time) {
+                current = current - (time.hour() * 3600 + time.minute() * 60) as i64 + 86400;
+                continue;
+            }
+
+            if !self.hours.contains(&time.hour()) {
+                current = current - (time.minute() * 60) as i64 + 3600;
+                continue;
+            }
+
+            if self.minutes.contains(&time.minute()) {
+                found.push(current);
+            }
+
+            current += 60;
+        }
+
+        found
+    }
+}
@@ -1,7 +1,16 @@
+use crate::components::data_log::{
+    append_data_log, clear_data_log, count_data_log, diff_data, fetch_data_log,
+    get_max_log_entries, lock_data_log, replay_data_log, LogEntry,
+};
 use crate::components::datapair::DataPair;
-use crate::components::io::{fetch_file, save_file};
+use crate::components::encryption::EncryptionKey;
+use crate::components::io::{ensure_directory_exists, fetch_file, save_file};
 use crate::utils::constraint::auto_fetch_all_constraints;
+use crate::utils::io::{get_root_data_dir, obtain_lock_name};
+use crate::utils::key_rotation::acquire_rotation_gate;
+use crate::utils::lock::{acquire_write_lock, LockGuard};
 use crate::utils::mapping::auto_fetch_all_mappings;
+use std::fs::{read_dir, read_to_string, remove_file, rename, write, OpenOptions};
 // use crate::encryption::{EncryptionKey};
 use rocket::serde::{Deserialize, Serialize};
 
@@ -664,12 +673,193 @@ pub fn unwrap_data(all_data_raw: String) -> Vec<Data> {
 
 pub fn fetch_all_data(path: String, encryption_key: &String) -> Vec<Data> {
     let all_data_raw = fetch_file(path.clone(), encryption_key);
-    let final_data = unwrap_data(all_data_raw);
+    let mut final_data = unwrap_data(all_data_raw);
+    replay_data_log(&mut final_data, &fetch_data_log(&path, encryption_key));
     final_data
 }
 
 pub fn save_all_data(all_data: &Vec<Data>, path: String, encryption_key: &String) {
-    let stringified_data = stringify_data(all_data);
-    save_file(path, stringified_data, encryption_key);
-    println!("Data saved!");
+    let _gate = acquire_rotation_gate();
+    let _lock = lock_data_log(&path);
+
+    let mut previous_data = unwrap_data(fetch_file(path.clone(), encryption_key));
+    replay_data_log(&mut previous_data, &fetch_data_log(&path, encryption_key));
+
+    write_data_entries(
+        all_data,
+        &diff_data(&previous_data, all_data),
+        path,
+        encryption_key,
+    );
+}
+
+pub fn save_data_entries(
+    all_data: &Vec<Data>,
+    entries: &Vec<LogEntry>,
+    path: String,
+    encryption_key: &String,
+) {
+    let _gate = acquire_rotation_gate();
+    let _lock = lock_data_log(&path);
+    write_data_entries(all_data, entries, path, encryption_key);
+}
+
+fn write_data_entries(
+    all_data: &Vec<Data>,
+    entries: &Vec<LogEntry>,
+    path: String,
+    encryption_key: &String,
+) {
+    if entries.len() < 1 {
+        return;
+    }
+
+    if count_data_log(&path) + entries.len() > get_max_log_entries() {
+        save_file(path.clone(), stringify_data(all_data), encryption_key);
+        clear_data_log(&path);
+        println!("Data compacted!");
+    } else {
+        append_data_log(&path, entries, encryption_key);
+        println!("Data saved!");
+    }
+}
+
+pub fn compact_data(path: String, encryption_key: &String) {
+    let _gate = acquire_rotation_gate();
+    let _lock = lock_data_log(&path);
+
+    let existing_entries = fetch_data_log(&path, encryption_key);
+    if existing_entries.len() > 0 {
+        let mut all_data = unwrap_data(fetch_file(path.clone(), encryption_key));
+        replay_data_log(&mut all_data, &existing_entries);
+
+        save_file(path.clone(), stringify_data(&all_data), encryption_key);
+        clear_data_log(&path);
+        println!("Data compacted!");
+    }
+}
+
+pub fn get_commit_journal_dir() -> String {
+    format!("{}/data/transactions", get_root_data_dir())
+}
+
+pub fn get_commit_staged_path(path: &str) -> String {
+    format!("{}.commit", path)
+}
+
+fn sync_staged_file(path: &str) -> Result<(), String> {
+    match OpenOptions::new().write(true).open(path) {
+        Ok(f) => match f.sync_all() {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Error: Failed syncing {} ({})", path, e)),
+        },
+        Err(e) => Err(format!("Error: Failed staging {} ({})", path, e)),
+    }
+}
+
+fn apply_commit_journal(journal_path: &str, all_paths: &Vec<String>) -> Result<(), String> {
+    for path in all_paths.iter() {
+        let staged_path = get_commit_staged_path(path);
+        if std::path::Path::new(&staged_path).exists() {
+            let _lock = acquire_write_lock(&obtain_lock_name(path));
+            if let Err(e) = rename(&staged_path, path) {
+                return Err(format!("Error: Failed committing {} ({})", path, e));
+            }
+        }
+
+        clear_data_log(path);
+    }
+
+    if let Err(e) = remove_file(journal_path) {
+        println!("Error occured while removing {}: {}", journal_path, e);
+    }
+
+    Ok(())
+}
+
+// Callers must hold the writer lock of every collection (see lock_data_log).
+pub fn commit_all_data(
+    all_staged: &Vec<(String, Vec<Data>)>,
+    encryption_key: &String,
+) -> Result<(), String> {
+    let _gate = acquire_rotation_gate();
+
+    for (path, all_data) in all_staged.iter() {
+        let staged_path = get_commit_staged_path(path);
+        save_file(
+            staged_path.clone(),
+            stringify_data(all_data),
+            encryption_key,
+        );
+
+        if let Err(e) = sync_staged_file(&staged_path) {
+            for (staged, _) in all_staged.iter() {
+                let _ = remove_file(get_commit_staged_path(staged));
+            }
+            return Err(e);
+        }
+    }
+
+    let all_paths = all_staged
+        .iter()
+        .map(|(path, _)| path.clone())
+        .collect::<Vec<String>>();
+
+    let journal_dir = get_commit_journal_dir();
+    ensure_directory_exists(&journal_dir);
+    let journal_path = format!("{}/{}.txt", journal_dir, EncryptionKey::generate_block(16));
+    if let Err(e) = write(&journal_path, all_paths.join("\n")) {
+        for path in all_paths.iter() {
+            let _ = remove_file(get_commit_staged_path(path));
+        }
+        return Err(format!("Error: Failed writing {} ({})", journal_path, e));
+    }
+
+    if let Err(e) = sync_staged_file(&journal_path) {
+        println!("{}", e);
+    }
+
+    apply_commit_journal(&journal_path, &all_paths)
+}
+
+pub fn recover_data_commits() -> usize {
+    let entries = match read_dir(get_commit_journal_dir()) {
+        Ok(e) => e,
+        Err(_) => return 0,
+    };
+
+    let mut recovered = 0;
+    for entry in entries {
+        let journal_path = match entry {
+            Ok(e) => e.path().to_string_lossy().to_string(),
+            Err(_) => continue,
+        };
+
+        let mut all_paths = match read_to_string(&journal_path) {
+            Ok(content) => content
+                .split("\n")
+                .filter(|line| line.trim().len() > 0)
+                .map(|line| line.to_string())
+                .collect::<Vec<String>>(),
+            Err(_) => continue,
+        };
+        all_paths.sort();
+
+        let _gate = acquire_rotation_gate();
+        let _locks = all_paths
+            .iter()
+            .map(|path| lock_data_log(path))
+            .collect::<Vec<LockGuard>>();
+
+        if !std::path::Path::new(&journal_path).exists() {
+            continue;
+        }
+
+        match apply_commit_journal(&journal_path, &all_paths) {
+            Ok(_) => recovered += 1,
+            Err(e) => println!("{}", e),
+        }
+    }
+
+    recovered
 }
@@ -0,0 +1,316 @@
+use crate::components::{
+    data::Data,
+    encryption::EncryptionKey,
+    io::{ensure_file_exists, fetch_file},
+};
+use crate::utils::{
+    config::get_config_value,
+    io::obtain_lock_name,
+    key_rotation::acquire_rotation_gate,
+    lock::{acquire_write_lock, LockGuard},
+    mapping::auto_fetch_all_mappings,
+};
+use std::{fmt, fs::OpenOptions, io::prelude::*};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogOperation {
+    CREATE,
+    UPDATE,
+    DELETE,
+}
+
+impl Default for LogOperation {
+    fn default() -> Self {
+        LogOperation::UPDATE
+    }
+}
+
+impl fmt::Display for LogOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operation_txt = match self {
+            LogOperation::CREATE => "CREATE".to_string(),
+            LogOperation::UPDATE => "UPDATE".to_string(),
+            LogOperation::DELETE => "DELETE".to_string(),
+        };
+
+        write!(f, "{}", operation_txt)
+    }
+}
+
+impl LogOperation {
+    pub fn to(operation: LogOperation) -> String {
+        return match operation.clone() {
+            LogOperation::CREATE => "CREATE".to_string(),
+            LogOperation::UPDATE => "UPDATE".to_string(),
+            LogOperation::DELETE => "DELETE".to_string(),
+        };
+    }
+
+    pub fn from(operation_txt: &str) -> Option<LogOperation> {
+        return match operation_txt.trim().to_uppercase().as_str() {
+            "CREATE" => Some(LogOperation::CREATE),
+            "UPDATE" => Some(LogOperation::UPDATE),
+            "DELETE" => Some(LogOperation::DELETE),
+            _ => None,
+        };
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct LogEntry {
+    pub operation: LogOperation,
+    pub id: String,
+    pub data: Option<Data>,
+}
+
+impl LogEntry {
+    pub fn new(operation: LogOperation, id: &str, data: Option<Data>) -> LogEntry {
+        LogEntry {
+            operation: operation,
+            id: id.to_string(),
+            data: data,
+        }
+    }
+
+    pub fn to_string(entry: LogEntry) -> String {
+        match entry.data {
+            Some(data) => format!(
+                "{};{}",
+                LogOperation::to(entry.operation),
+                Data::to_string(data)
+            ),
+            None => format!("{};{}", LogOperation::to(entry.operation), entry.id),
+        }
+    }
+
+    pub fn from_string(entry_str: &str) -> Option<LogEntry> {
+        let current_entry = entry_str.splitn(2, ";").collect::<Vec<&str>>();
+        if current_entry.len() < 2 {
+            return None;
+        }
+
+        let operation = match LogOperation::from(current_entry[0]) {
+            Some(o) => o,
+            None => return None,
+        };
+
+        if operation == LogOperation::DELETE {
+            return Some(LogEntry {
+                operation: operation,
+                id: current_entry[1].trim().to_string(),
+                data: None,
+            });
+        }
+
+        let mut tmp_data = Vec::<Data>::new();
+        let error = Data::from_string(&mut tmp_data, current_entry[1]);
+        if error.len() > 0 || tmp_data.len() < 1 {
+            println!("Error: Invalid data log entry ({})", error);
+            return None;
+        }
+
+        Some(LogEntry {
+            operation: operation,
+            id: tmp_data[0].id.clone(),
+            data: Some(tmp_data[0].clone()),
+        })
+    }
+}
+
+pub fn get_data_log_path(path: &str) -> String {
+    match path.strip_suffix(".txt") {
+        Some(base) => format!("{}.log", base),
+        None => format!("{}.log", path),
+    }
+}
+
+pub fn get_max_log_entries() -> usize {
+    get_config_value(&auto_fetch_all_mappings(), "DATA_LOG_MAX_ENTRIES", "100")
+        .parse::<usize>()
+        .unwrap_or(100)
+}
+
+pub fn lock_data_log(path: &str) -> LockGuard {
+    acquire_write_lock(&format!(
+        "{}.writer",
+        obtain_lock_name(&get_data_log_path(path))
+    ))
+}
+
+pub fn diff_data(previous_data: &Vec<Data>, all_data: &Vec<Data>) -> Vec<LogEntry> {
+    let mut entries = Vec::<LogEntry>::new();
+
+    for data in all_data.iter() {
+        match previous_data.iter().find(|previous| previous.id == data.id) {
+            Some(previous) => {
+                if Data::to_string(previous.clone()) != Data::to_string(data.clone()) {
+                    entries.push(LogEntry {
+                        operation: LogOperation::UPDATE,
+                        id: data.id.clone(),
+                        data: Some(data.clone()),
+                    });
+                }
+            }
+            None => entries.push(LogEntry {
+                operation: LogOperation::CREATE,
+                id: data.id.clone(),
+                data: Some(data.clone()),
+            }),
+        }
+    }
+
+    for previous in previous_data.iter() {
+        if !all_data.iter().any(|data| data.id == previous.id) {
+            entries.push(LogEntry {
+                operation: LogOperation::DELETE,
+                id: previous.id.clone(),
+                data: None,
+            });
+        }
+    }
+
+    entries
+}
+
+pub fn replay_data_log(all_data: &mut Vec<Data>, entries: &Vec<LogEntry>) {
+    for entry in entries.iter() {
+        match &entry.data {
+            Some(data) => match all_data.iter_mut().find(|current| current.id == entry.id) {
+                Some(current) => *current = data.clone(),
+                None => all_data.push(data.clone()),
+            },
+            None => all_data.retain(|current| current.id != entry.id),
+        }
+    }
+}
+
+pub fn fetch_data_log(path: &str, encryption_key: &String) -> Vec<LogEntry> {
+    let log_path = get_data_log_path(path);
+    if !std::path::Path::new(&log_path).exists() {
+        return vec![];
+    }
+
+    let content = fetch_file(log_path, &String::new());
+    let mut entries = Vec::<LogEntry>::new();
+
+    let lines = content
+        .split("\n")
+        .filter(|line| line.chars().count() >= 3)
+        .collect::<Vec<&str>>();
+
+    if lines.len() > 0 && lines[0] == ";|encrypted|;" {
+        for line in lines.iter().skip(1) {
+            match EncryptionKey::decrypt(line.to_string(), encryption_key) {
+                Ok(d) => {
+                    if let Some(entry) = LogEntry::from_string(&d.0) {
+                        entries.push(entry);
+                    }
+                }
+                Err(e) => println!("Error: Failed decrypting data log of {} ({})", path, e),
+            }
+        }
+    } else {
+        for entry_str in content.split("\n----------\n") {
+            if entry_str.trim().len() < 1 {
+                continue;
+            }
+
+            if let Some(entry) = LogEntry::from_string(entry_str) {
+                entries.push(entry);
+            }
+        }
+    }
+
+    entries
+}
+
+pub fn count_data_log(path: &str) -> usize {
+    let log_path = get_data_log_path(path);
+    if !std::path::Path::new(&log_path).exists() {
+        return 0;
+    }
+
+    let content = fetch_file(log_path, &String::new());
+    let lines = content
+        .split("\n")
+        .filter(|line| line.chars().count() >= 3)
+        .collect::<Vec<&str>>();
+
+    if lines.len() > 0 && lines[0] == ";|encrypted|;" {
+        return lines.len() - 1;
+    }
+
+    content
+        .split("\n----------\n")
+        .filter(|entry_str| entry_str.trim().len() > 0)
+        .count()
+}
+
+pub fn append_data_log(path: &str, entries: &Vec<LogEntry>, encryption_key: &String) {
+    let log_path = get_data_log_path(path);
+    let _gate = acquire_rotation_gate();
+    let _lock = acquire_write_lock(&obtain_lock_name(&log_path));
+    ensure_file_exists(&log_path);
+
+    let is_empty = match std::fs::metadata(&log_path) {
+        Ok(m) => m.len() == 0,
+        _ => true,
+    };
+
+    let mut final_data = String::new();
+    if encryption_key.len() > 2 {
+        if is_empty {
+            final_data = String::from(";|encrypted|;\n");
+        }
+
+        for entry in entries.iter() {
+            let encrypted_entry =
+                EncryptionKey::encrypt(LogEntry::to_string(entry.clone()), encryption_key);
+            final_data = format!("{}{}\n", final_data, encrypted_entry);
+        }
+    } else {
+        for entry in entries.iter() {
+            final_data = format!(
+                "{}{}\n----------\n",
+                final_data,
+                LogEntry::to_string(entry.clone())
+            );
+        }
+    }
+
+    let file = OpenOptions::new().append(true).open(&log_path);
+    match file {
+        Ok(mut f) => {
+            if let Err(e) = f.write_all(final_data.as_bytes()) {
+                println!("Error occured while appending to {}: {}", &log_path, e);
+            }
+
+            if let Err(e) = f.sync_all() {
+                println!("Error occured while syncing {}: {}", &log_path, e);
+            }
+        }
+        Err(e) => println!("Error occured while opening {}: {}", &log_path, e),
+    }
+}
+
+pub fn clear_data_log(path: &str) {
+    let log_path = get_data_log_path(path);
+    if !std::path::Path::new(&log_path).exists() {
+        return;
+    }
+
+    let _gate = acquire_rotation_gate();
+    let _lock = acquire_write_lock(&obtain_lock_name(&log_path));
+    match OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .open(&log_path)
+    {
+        Ok(f) => {
+            if let Err(e) = f.sync_all() {
+                println!("Error occured while syncing {}: {}", &log_path, e);
+            }
+        }
+        Err(e) => println!("Error occured while truncating {}: {}", &log_path, e),
+    }
+}
@@ -33,3 +33,15 @@ pub mod loop_block;
 
 #[path = "template_block.rs"]
 pub mod template_block;
+
+#[path = "delete_block.rs"]
+pub mod delete_block;
+
+#[path = "sort_block.rs"]
+pub mod sort_block;
+
+#[path = "call_block.rs"]
+pub mod call_block;
+
+#[path = "try_block.rs"]
+pub mod try_block;
@@ -285,9 +285,22 @@ impl ConditionBlock {
         }
 
         let mut fail_obj: Option<FailObj> = None;
+        let mut fail_obj_str = format!("[{}]", current_block[0]);
+
+        let remaining_str = current_block[1..].join("]");
+        if remaining_str.starts_with("{") {
+            let broken_remaining = block_str.split("]{").collect::<Vec<&str>>();
+            let headers_str = broken_remaining[1..].join("]{");
+            match headers_str.find(")}") {
+                Some(end) => {
+                    fail_obj_str = format!("{}{{{}}}", fail_obj_str, &headers_str[..end + 1]);
+                }
+                None => return Err((500, String::from("at end of fail_obj headers declaration"))),
+            }
+        }
 
         if current_block[0].trim().len() > 0 {
-            fail_obj = match FailObj::from_string(&format!("[{}]", current_block[0])) {
+            fail_obj = match FailObj::from_string(&fail_obj_str) {
                 Ok(fail_obj) => Some(fail_obj),
                 Err(e) => return Err((500, format!("while processing fail_obj -> {}", e.1))),
             };
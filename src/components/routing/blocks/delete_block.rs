@@ -0,0 +1,487 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::{
+    components::{
+        constraint_property::ConstraintProperty,
+        routing::submodules::{sub_condition::Condition, sub_update_target::UpdateTarget},
+    },
+    utils::{constraint::auto_fetch_all_constraints, mapping::auto_fetch_all_mappings},
+};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeleteBlock {
+    pub global_index: u32,
+    pub block_index: u32,
+    pub ref_col: String,
+    pub save: bool,
+    pub targets: Vec<UpdateTarget>,
+    pub conditions: Vec<Condition>,
+}
+
+impl DeleteBlock {
+    pub fn create(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+        block_index: u32,
+        ref_col: &str,
+        save: bool,
+    ) -> Result<(), (usize, String)> {
+        let mut has_error: bool = false;
+        let mut latest_error: (usize, String) = (500, String::new());
+
+        let new_block = DeleteBlock {
+            global_index: global_index,
+            block_index: block_index,
+            ref_col: "".to_string(),
+            save: false,
+            targets: vec![],
+            conditions: vec![],
+        };
+        all_blocks.push(new_block);
+
+        if !has_error {
+            let ref_col_update = Self::update_ref_col(all_blocks, global_index, ref_col);
+            if let Err(e) = ref_col_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if !has_error {
+            let save_update = Self::update_save(all_blocks, global_index, save);
+            if let Err(e) = save_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if has_error {
+            let delete_block = Self::delete(all_blocks, global_index);
+            if let Err(e) = delete_block {
+                println!("{}", e.1);
+            }
+
+            return Err(latest_error);
+        }
+
+        Ok(())
+    }
+
+    pub fn exist(all_blocks: &Vec<DeleteBlock>, global_index: u32) -> bool {
+        let mut found = false;
+        for block in all_blocks.iter() {
+            if block.global_index == global_index {
+                found = true;
+                break;
+            }
+        }
+
+        found
+    }
+
+    pub fn get(all_blocks: &Vec<DeleteBlock>, global_index: u32) -> Option<DeleteBlock> {
+        for block in all_blocks.iter() {
+            if block.global_index == global_index {
+                return Some(block.clone());
+            }
+        }
+
+        None
+    }
+
+    pub fn update_ref_col(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+        ref_col: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<DeleteBlock> = None;
+
+        let mappings = auto_fetch_all_mappings();
+        let all_constraints = match auto_fetch_all_constraints(&mappings) {
+            Ok(c) => c,
+            Err(e) => return Err((500, e)),
+        };
+        let final_value = match ConstraintProperty::validate(
+            &all_constraints,
+            "delete_block",
+            "ref_col",
+            ref_col,
+        ) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.ref_col = final_value;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Delete Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_save(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+        save: bool,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<DeleteBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.save = save;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Delete Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn add_target(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+        new_target: UpdateTarget,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<DeleteBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.targets.push(new_target);
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Delete Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_target(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+        target_index: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<DeleteBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+
+                let mut updated_targets = Vec::<UpdateTarget>::new();
+                if target_index >= block.targets.len() as u32 {
+                    return Err((
+                        400,
+                        String::from("Error: Index goes over the amount of targets present"),
+                    ));
+                }
+
+                for n in 0..block.targets.len() {
+                    if n as u32 != target_index {
+                        updated_targets.push(block.targets[n].clone());
+                    }
+                }
+
+                block.targets = updated_targets;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Delete Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn set_targets(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+        targets: Vec<UpdateTarget>,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<DeleteBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.targets = targets;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Delete Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn add_condition(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+        new_condition: Condition,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<DeleteBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.conditions.push(new_condition);
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Delete Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_condition(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+        condition_index: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<DeleteBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+
+                let mut updated_conditions = Vec::<Condition>::new();
+                if condition_index >= block.conditions.len() as u32 {
+                    return Err((
+                        400,
+                        String::from("Error: Index goes over the amount of conditions present"),
+                    ));
+                }
+
+                for n in 0..block.conditions.len() {
+                    if n as u32 != condition_index {
+                        updated_conditions.push(block.conditions[n].clone());
+                    }
+                }
+
+                block.conditions = updated_conditions;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Delete Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn set_conditions(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+        conditions: Vec<Condition>,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<DeleteBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.conditions = conditions;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Delete Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn delete(
+        all_blocks: &mut Vec<DeleteBlock>,
+        global_index: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<DeleteBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Delete Block not found")));
+        }
+
+        let updated_blocks: Vec<DeleteBlock> = all_blocks
+            .iter_mut()
+            .filter(|block| block.global_index != global_index)
+            .map(|block| DeleteBlock {
+                global_index: block.global_index,
+                block_index: block.block_index,
+                ref_col: block.ref_col.clone(),
+                save: block.save,
+                targets: block.targets.clone(),
+                conditions: block.conditions.clone(),
+            })
+            .collect::<Vec<DeleteBlock>>();
+
+        *all_blocks = updated_blocks;
+
+        Ok(())
+    }
+
+    pub fn stringify(all_blocks: &Vec<DeleteBlock>) -> String {
+        let mut stringified_blocks = String::new();
+
+        for block in all_blocks {
+            stringified_blocks = format!(
+                "{}{}{}",
+                stringified_blocks,
+                if stringified_blocks.chars().count() > 1 {
+                    "\n"
+                } else {
+                    ""
+                },
+                DeleteBlock::to_string(block.clone()),
+            );
+        }
+
+        stringified_blocks
+    }
+
+    pub fn from_string(
+        all_blocks: &mut Vec<DeleteBlock>,
+        block_str: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut current_block = block_str.split("DELETE (").collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at start of indexes declaration")));
+        }
+
+        current_block = current_block[1].split(")").collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at end of indexes declaration")));
+        }
+
+        current_block = current_block[0].split(",").collect::<Vec<&str>>();
+        if current_block.len() < 2 {
+            return Err((500, String::from("in format of indexes declaration")));
+        }
+
+        let global_index = match current_block[0].trim().parse::<u32>() {
+            Ok(idx) => idx,
+            Err(e) => return Err((500, format!("at global_index -> {}", e))),
+        };
+
+        let block_index = match current_block[1].trim().parse::<u32>() {
+            Ok(idx) => idx,
+            Err(e) => return Err((500, format!("at local_index -> {}", e))),
+        };
+
+        current_block = block_str.split("[").collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at start of ref declaration")));
+        }
+
+        current_block = current_block[1].split("]").collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at end of ref declaration")));
+        }
+
+        current_block = current_block[0].split(",").collect::<Vec<&str>>();
+        if current_block.len() < 2 {
+            return Err((500, String::from("in format of ref declaration")));
+        }
+
+        let ref_col = current_block[0];
+        let save = current_block[1] == "true";
+
+        current_block = block_str.split("] ").collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at start of targets declaration")));
+        }
+
+        let current_block_tmp = current_block[1..].join("] ");
+        current_block = current_block_tmp
+            .split(" conditions=")
+            .collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at end of targets declaration")));
+        }
+
+        let mut all_targets: Vec<UpdateTarget> = Vec::new();
+        let targets_list = current_block[0].split("%").collect::<Vec<&str>>();
+        for target_str in targets_list {
+            if target_str.trim().len() < 1 {
+                continue;
+            }
+
+            if let Err(e) = UpdateTarget::from_string(&mut all_targets, target_str) {
+                return Err((500, format!("while processing target -> {}", e.1)));
+            }
+        }
+
+        let mut all_conditions: Vec<Condition> = Vec::new();
+        let conditions_list = block_str.split("conditions=").collect::<Vec<&str>>();
+        if conditions_list.len() <= 1 {
+            return Err((500, String::from("at start of conditions declaration")));
+        }
+
+        let final_conditions_list = conditions_list[1].trim().split(">").collect::<Vec<&str>>();
+
+        for c_str in final_conditions_list {
+            if c_str.len() <= 1 {
+                continue;
+            }
+
+            if let Err(e) = Condition::from_string(&mut all_conditions, c_str) {
+                return Err((500, format!("while processing condition -> {}", e.1)));
+            };
+        }
+
+        if let Err(e) = DeleteBlock::create(all_blocks, global_index, block_index, ref_col, save) {
+            return Err((500, format!("while processing block -> {}", e.1)));
+        };
+
+        if let Err(e) = DeleteBlock::set_targets(all_blocks, global_index, all_targets) {
+            return Err((500, format!("while processing block -> {}", e.1)));
+        };
+
+        match DeleteBlock::set_conditions(all_blocks, global_index, all_conditions) {
+            Ok(_) => Ok(()),
+            Err(e) => Err((500, format!("while processing block -> {}", e.1))),
+        }
+    }
+
+    pub fn to_string(block: DeleteBlock) -> String {
+        let targets_str = UpdateTarget::stringify(&block.targets);
+        let conditions_str = Condition::stringify(&block.conditions);
+
+        format!(
+            "DELETE ({},{}) [{},{}] {} conditions={}",
+            block.global_index,
+            block.block_index,
+            block.ref_col,
+            if block.save == true { "true" } else { "false" },
+            targets_str,
+            conditions_str
+        )
+    }
+}
@@ -14,6 +14,8 @@ pub struct LoopBlock {
     pub local_name: String,
     pub min: RefData,
     pub max: RefData,
+    pub each: Option<RefData>,
+    pub index_name: String,
 }
 
 impl LoopBlock {
@@ -34,6 +36,8 @@ impl LoopBlock {
             local_name: "".to_string(),
             min: min,
             max: max,
+            each: None,
+            index_name: "".to_string(),
         };
         all_blocks.push(new_block);
 
@@ -161,6 +165,75 @@ impl LoopBlock {
         Ok(())
     }
 
+    pub fn update_each(
+        all_blocks: &mut Vec<LoopBlock>,
+        global_index: u32,
+        each: Option<RefData>,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<LoopBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.each = each;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Loop Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_index_name(
+        all_blocks: &mut Vec<LoopBlock>,
+        global_index: u32,
+        index_name: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<LoopBlock> = None;
+
+        let mut final_value = String::new();
+        if index_name.trim().len() > 0 {
+            let mappings = auto_fetch_all_mappings();
+            let all_constraints = match auto_fetch_all_constraints(&mappings) {
+                Ok(c) => c,
+                Err(e) => return Err((500, e)),
+            };
+            final_value = match ConstraintProperty::validate(
+                &all_constraints,
+                "loop_block",
+                "local_name",
+                index_name,
+            ) {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+        }
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                if final_value.len() > 0 && block.local_name == final_value {
+                    return Err((
+                        400,
+                        String::from("Error: index_name should differ from local_name"),
+                    ));
+                }
+
+                found_block = Some(block.clone());
+                block.index_name = final_value;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Loop Block not found")));
+        }
+
+        Ok(())
+    }
+
     pub fn delete(
         all_blocks: &mut Vec<LoopBlock>,
         global_index: u32,
@@ -187,6 +260,8 @@ impl LoopBlock {
                 local_name: block.local_name.clone(),
                 min: block.min.clone(),
                 max: block.max.clone(),
+                each: block.each.clone(),
+                index_name: block.index_name.clone(),
             })
             .collect::<Vec<LoopBlock>>();
 
@@ -253,47 +328,91 @@ impl LoopBlock {
             return Err((500, String::from("at end of local_name declaration")));
         }
 
-        let local_name = current_block[0];
+        let all_names = current_block[0].split(",").collect::<Vec<&str>>();
+        let local_name = all_names[0];
+        let index_name = if all_names.len() > 1 {
+            all_names[1]
+        } else {
+            ""
+        };
 
-        current_block = block_str.split("(").collect::<Vec<&str>>();
-        if current_block.len() <= 2 {
-            return Err((500, String::from("at start of range declaration")));
-        }
+        let mut min = RefData::default();
+        let mut max = RefData::default();
+        let mut each: Option<RefData> = None;
 
-        current_block = current_block[2].split(")").collect::<Vec<&str>>();
-        if current_block.len() <= 1 {
-            return Err((500, String::from("at end of range declaration")));
-        }
+        current_block = block_str.split("(each=").collect::<Vec<&str>>();
+        if current_block.len() > 1 {
+            current_block = current_block[1].split(")").collect::<Vec<&str>>();
+            if current_block.len() <= 1 {
+                return Err((500, String::from("at end of each declaration")));
+            }
 
-        current_block = current_block[0].split("|").collect::<Vec<&str>>();
-        if current_block.len() <= 1 {
-            return Err((500, String::from("in format of range declaration")));
+            each = match RefData::from_string(current_block[0]) {
+                Ok(e) => Some(e),
+                Err(e) => return Err((500, format!("while processing each -> {}", e.1))),
+            };
+        } else {
+            current_block = block_str.split("(").collect::<Vec<&str>>();
+            if current_block.len() <= 2 {
+                return Err((500, String::from("at start of range declaration")));
+            }
+
+            current_block = current_block[2].split(")").collect::<Vec<&str>>();
+            if current_block.len() <= 1 {
+                return Err((500, String::from("at end of range declaration")));
+            }
+
+            current_block = current_block[0].split("|").collect::<Vec<&str>>();
+            if current_block.len() <= 1 {
+                return Err((500, String::from("in format of range declaration")));
+            }
+
+            min = match RefData::from_string(current_block[0]) {
+                Ok(m) => m,
+                Err(e) => return Err((500, format!("while processing min -> {}", e.1))),
+            };
+
+            max = match RefData::from_string(current_block[1]) {
+                Ok(m) => m,
+                Err(e) => return Err((500, format!("while processing max -> {}", e.1))),
+            };
         }
 
-        let min = match RefData::from_string(current_block[0]) {
-            Ok(m) => m,
-            Err(e) => return Err((500, format!("while processing min -> {}", e.1))),
-        };
+        if let Err(e) =
+            LoopBlock::create(all_blocks, global_index, block_index, local_name, min, max)
+        {
+            return Err((500, format!("while processing block -> {}", e.1)));
+        }
 
-        let max = match RefData::from_string(current_block[1]) {
-            Ok(m) => m,
-            Err(e) => return Err((500, format!("while processing max -> {}", e.1))),
-        };
+        if let Err(e) = LoopBlock::update_each(all_blocks, global_index, each) {
+            return Err((500, format!("while processing each -> {}", e.1)));
+        }
 
-        match LoopBlock::create(all_blocks, global_index, block_index, local_name, min, max) {
+        match LoopBlock::update_index_name(all_blocks, global_index, index_name) {
             Ok(_) => Ok(()),
-            Err(e) => Err((500, format!("while processing block -> {}", e.1))),
+            Err(e) => Err((500, format!("while processing index_name -> {}", e.1))),
         }
     }
 
     pub fn to_string(block: LoopBlock) -> String {
+        let names = if block.index_name.len() > 0 {
+            format!("{},{}", block.local_name, block.index_name)
+        } else {
+            block.local_name.clone()
+        };
+
+        let iteration = match block.each.clone() {
+            Some(each) => format!("(each={})", RefData::to_string(each)),
+            None => format!(
+                "({}|{})",
+                RefData::to_string(block.min.clone()),
+                RefData::to_string(block.max.clone()),
+            ),
+        };
+
         format!(
-            "LOOP ({},{}) [{}] ({}|{})",
-            block.global_index,
-            block.block_index,
-            block.local_name,
-            RefData::to_string(block.min.clone()),
-            RefData::to_string(block.max.clone()),
+            "LOOP ({},{}) [{}] {}",
+            block.global_index, block.block_index, names, iteration,
         )
     }
 }
@@ -10,6 +10,14 @@ pub struct ReturnBlock {
     pub block_index: u32,
     pub pairs: Vec<ObjectPair>,
     pub conditions: Vec<Condition>,
+    #[serde(default = "default_status")]
+    pub status: u32,
+    #[serde(default)]
+    pub headers: Vec<ObjectPair>,
+}
+
+fn default_status() -> u32 {
+    200
 }
 
 impl ReturnBlock {
@@ -19,6 +27,8 @@ impl ReturnBlock {
             block_index: block_index,
             pairs: vec![],
             conditions: vec![],
+            status: default_status(),
+            headers: vec![],
         };
         all_blocks.push(new_block);
     }
@@ -126,6 +136,113 @@ impl ReturnBlock {
         Ok(())
     }
 
+    pub fn update_status(
+        all_blocks: &mut Vec<ReturnBlock>,
+        global_index: u32,
+        status: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<ReturnBlock> = None;
+
+        if status < 100 || status > 599 {
+            return Err((400, String::from("Error: Invalid HTTP status code")));
+        }
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.status = status;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Return Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn add_header(
+        all_blocks: &mut Vec<ReturnBlock>,
+        global_index: u32,
+        new_header: ObjectPair,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<ReturnBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.headers.push(new_header);
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Return Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_header(
+        all_blocks: &mut Vec<ReturnBlock>,
+        global_index: u32,
+        header_index: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<ReturnBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+
+                let mut updated_headers = Vec::<ObjectPair>::new();
+                if header_index >= block.headers.len() as u32 {
+                    return Err((
+                        400,
+                        String::from("Error: Index goes over the amount of headers present"),
+                    ));
+                }
+
+                for n in 0..block.headers.len() {
+                    if n as u32 != header_index {
+                        updated_headers.push(block.headers[n].clone());
+                    }
+                }
+
+                block.headers = updated_headers;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Return Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn set_headers(
+        all_blocks: &mut Vec<ReturnBlock>,
+        global_index: u32,
+        headers: Vec<ObjectPair>,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<ReturnBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.headers = headers;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Return Block not found")));
+        }
+
+        Ok(())
+    }
+
     pub fn add_condition(
         all_blocks: &mut Vec<ReturnBlock>,
         global_index: u32,
@@ -232,6 +349,8 @@ impl ReturnBlock {
                 block_index: block.block_index,
                 pairs: block.pairs.clone(),
                 conditions: block.conditions.clone(),
+                status: block.status,
+                headers: block.headers.clone(),
             })
             .collect::<Vec<ReturnBlock>>();
 
@@ -301,8 +420,27 @@ impl ReturnBlock {
             return Err((500, String::from("at start of conditions declaration")));
         }
 
+        let mut pairs_str = current_block[0];
+        let mut status = default_status();
+        let mut headers_str = "";
+
+        let broken_headers = pairs_str.split(" headers=").collect::<Vec<&str>>();
+        if broken_headers.len() > 1 {
+            pairs_str = broken_headers[0];
+            headers_str = broken_headers[1];
+        }
+
+        let broken_status = pairs_str.split(" status=").collect::<Vec<&str>>();
+        if broken_status.len() > 1 {
+            pairs_str = broken_status[0];
+            status = match broken_status[1].trim().parse::<u32>() {
+                Ok(sts) => sts,
+                Err(e) => return Err((500, format!("at status -> {}", e))),
+            };
+        }
+
         let mut all_pairs: Vec<ObjectPair> = Vec::new();
-        let pairs_list = current_block[0].trim().split(">").collect::<Vec<&str>>();
+        let pairs_list = pairs_str.trim().split(">").collect::<Vec<&str>>();
 
         for p_str in pairs_list {
             if p_str.len() < 1 {
@@ -314,6 +452,19 @@ impl ReturnBlock {
             };
         }
 
+        let mut all_headers: Vec<ObjectPair> = Vec::new();
+        let headers_list = headers_str.trim().split(">").collect::<Vec<&str>>();
+
+        for h_str in headers_list {
+            if h_str.len() < 1 {
+                continue;
+            }
+
+            if let Err(e) = ObjectPair::from_string(&mut all_headers, h_str) {
+                return Err((500, format!("while processing header -> {}", e.1)));
+            };
+        }
+
         current_block = block_str.split("conditions=").collect::<Vec<&str>>();
         if current_block.len() <= 1 {
             return Err((500, String::from("in format of conditions declaration")));
@@ -339,6 +490,16 @@ impl ReturnBlock {
             Err(e) => return Err((500, format!("while processing block -> {}", e.1))),
         };
 
+        match ReturnBlock::update_status(all_blocks, global_index, status) {
+            Ok(f) => f,
+            Err(e) => return Err((500, format!("while processing block -> {}", e.1))),
+        };
+
+        match ReturnBlock::set_headers(all_blocks, global_index, all_headers) {
+            Ok(f) => f,
+            Err(e) => return Err((500, format!("while processing block -> {}", e.1))),
+        };
+
         match ReturnBlock::set_conditions(all_blocks, global_index, all_conditions) {
             Ok(_) => Ok(()),
             Err(e) => Err((500, format!("while processing block -> {}", e.1))),
@@ -349,9 +510,21 @@ impl ReturnBlock {
         let pairs_str = ObjectPair::stringify(&block.pairs);
         let conditions_str = Condition::stringify(&block.conditions);
 
+        let mut response_str = String::new();
+        if block.status != default_status() {
+            response_str = format!(" status={}", block.status);
+        }
+        if block.headers.len() > 0 {
+            response_str = format!(
+                "{} headers={}",
+                response_str,
+                ObjectPair::stringify(&block.headers)
+            );
+        }
+
         format!(
-            "RETURN ({},{}) {} conditions={}",
-            block.global_index, block.block_index, pairs_str, conditions_str
+            "RETURN ({},{}) {}{} conditions={}",
+            block.global_index, block.block_index, pairs_str, response_str, conditions_str
         )
     }
 }
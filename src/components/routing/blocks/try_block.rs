@@ -0,0 +1,293 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::{
+    components::constraint_property::ConstraintProperty,
+    utils::{constraint::auto_fetch_all_constraints, mapping::auto_fetch_all_mappings},
+};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TryBlock {
+    pub global_index: u32,
+    pub block_index: u32,
+    pub local_name: String,
+    pub end: u32,
+    pub fallback: u32,
+}
+
+impl TryBlock {
+    pub fn create(
+        all_blocks: &mut Vec<TryBlock>,
+        global_index: u32,
+        block_index: u32,
+        local_name: &str,
+        end: u32,
+        fallback: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut has_error: bool = false;
+        let mut latest_error: (usize, String) = (500, String::new());
+
+        let new_block = TryBlock {
+            global_index: global_index,
+            block_index: block_index,
+            local_name: "".to_string(),
+            end: global_index,
+            fallback: global_index,
+        };
+        all_blocks.push(new_block);
+
+        if !has_error {
+            let local_name_update = Self::update_local_name(all_blocks, global_index, local_name);
+            if let Err(e) = local_name_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if !has_error {
+            let range_update = Self::update_range(all_blocks, global_index, end, fallback);
+            if let Err(e) = range_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if has_error {
+            let delete_block = Self::delete(all_blocks, global_index);
+            if let Err(e) = delete_block {
+                println!("{}", e.1);
+            }
+
+            return Err(latest_error);
+        }
+
+        Ok(())
+    }
+
+    pub fn exist(all_blocks: &Vec<TryBlock>, global_index: u32) -> bool {
+        let mut found = false;
+        for block in all_blocks.iter() {
+            if block.global_index == global_index {
+                found = true;
+                break;
+            }
+        }
+
+        found
+    }
+
+    pub fn get(all_blocks: &Vec<TryBlock>, global_index: u32) -> Option<TryBlock> {
+        for block in all_blocks.iter() {
+            if block.global_index == global_index {
+                return Some(block.clone());
+            }
+        }
+
+        None
+    }
+
+    pub fn update_local_name(
+        all_blocks: &mut Vec<TryBlock>,
+        global_index: u32,
+        local_name: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<TryBlock> = None;
+
+        let mappings = auto_fetch_all_mappings();
+        let all_constraints = match auto_fetch_all_constraints(&mappings) {
+            Ok(c) => c,
+            Err(e) => return Err((500, e)),
+        };
+        let final_value = match ConstraintProperty::validate(
+            &all_constraints,
+            "try_block",
+            "local_name",
+            local_name,
+        ) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.local_name = final_value;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Try Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_range(
+        all_blocks: &mut Vec<TryBlock>,
+        global_index: u32,
+        end: u32,
+        fallback: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<TryBlock> = None;
+
+        if end <= global_index {
+            return Err((
+                400,
+                String::from("Error: end should come after the Try Block itself"),
+            ));
+        }
+
+        if fallback <= end {
+            return Err((
+                400,
+                String::from("Error: fallback should come after the end of the Try Block"),
+            ));
+        }
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                block.end = end;
+                block.fallback = fallback;
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Try Block not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn delete(
+        all_blocks: &mut Vec<TryBlock>,
+        global_index: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut found_block: Option<TryBlock> = None;
+
+        for block in all_blocks.iter_mut() {
+            if block.global_index == global_index {
+                found_block = Some(block.clone());
+                break;
+            }
+        }
+
+        if let None = found_block {
+            return Err((404, String::from("Error: Try Block not found")));
+        }
+
+        let updated_blocks: Vec<TryBlock> = all_blocks
+            .iter_mut()
+            .filter(|block| block.global_index != global_index)
+            .map(|block| TryBlock {
+                global_index: block.global_index,
+                block_index: block.block_index,
+                local_name: block.local_name.clone(),
+                end: block.end,
+                fallback: block.fallback,
+            })
+            .collect::<Vec<TryBlock>>();
+
+        *all_blocks = updated_blocks;
+
+        Ok(())
+    }
+
+    pub fn stringify(all_blocks: &Vec<TryBlock>) -> String {
+        let mut stringified_blocks = String::new();
+
+        for block in all_blocks {
+            stringified_blocks = format!(
+                "{}{}{}",
+                stringified_blocks,
+                if stringified_blocks.chars().count() > 1 {
+                    "\n"
+                } else {
+                    ""
+                },
+                TryBlock::to_string(block.clone()),
+            );
+        }
+
+        stringified_blocks
+    }
+
+    pub fn from_string(
+        all_blocks: &mut Vec<TryBlock>,
+        block_str: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut current_block = block_str.split("TRY (").collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at start of indexes declaration")));
+        }
+
+        current_block = current_block[1].split(")").collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at end of indexes declaration")));
+        }
+
+        current_block = current_block[0].split(",").collect::<Vec<&str>>();
+        if current_block.len() < 2 {
+            return Err((500, String::from("in format of indexes declaration")));
+        }
+
+        let global_index = match current_block[0].trim().parse::<u32>() {
+            Ok(idx) => idx,
+            Err(e) => return Err((500, format!("at global_index -> {}", e))),
+        };
+
+        let block_index = match current_block[1].trim().parse::<u32>() {
+            Ok(idx) => idx,
+            Err(e) => return Err((500, format!("at local_index -> {}", e))),
+        };
+
+        current_block = block_str.split("[").collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at start of ref declaration")));
+        }
+
+        current_block = current_block[1].split("]").collect::<Vec<&str>>();
+        if current_block.len() <= 1 {
+            return Err((500, String::from("at end of ref declaration")));
+        }
+
+        current_block = current_block[0].split(",").collect::<Vec<&str>>();
+        if current_block.len() < 3 {
+            return Err((500, String::from("in format of ref declaration")));
+        }
+
+        let local_name = current_block[0];
+
+        let end = match current_block[1].trim().parse::<u32>() {
+            Ok(idx) => idx,
+            Err(e) => return Err((500, format!("at end -> {}", e))),
+        };
+
+        let fallback = match current_block[2].trim().parse::<u32>() {
+            Ok(idx) => idx,
+            Err(e) => return Err((500, format!("at fallback -> {}", e))),
+        };
+
+        match TryBlock::create(
+            all_blocks,
+            global_index,
+            block_index,
+            local_name,
+            end,
+            fallback,
+        ) {
+            Ok(_) => Ok(()),
+            Err(e) => Err((500, format!("while processing block -> {}", e.1))),
+        }
+    }
+
+    pub fn to_string(block: TryBlock) -> String {
+        format!(
+            "TRY ({},{}) [{},{},{}]",
+            block.global_index, block.block_index, block.local_name, block.end, block.fallback,
+        )
+    }
+}
@@ -1,7 +1,9 @@
 use rocket::serde::{Deserialize, Serialize};
 
 use crate::{
-    components::constraint_property::ConstraintProperty,
+    components::{
+        constraint_property::ConstraintProperty, routing::submodules::sub_auth_rule::AuthRule,
+    },
     utils::{constraint::auto_fetch_all_constraints, mapping::auto_fetch_all_mappings},
 };
 
@@ -10,6 +12,10 @@ pub struct AuthJWT {
     pub active: bool,
     pub field: String,
     pub ref_col: String,
+    #[serde(default)]
+    pub claims: Vec<String>,
+    #[serde(default)]
+    pub rules: Vec<AuthRule>,
 }
 
 impl AuthJWT {
@@ -21,6 +27,8 @@ impl AuthJWT {
             active: active,
             field: "".to_string(),
             ref_col: "".to_string(),
+            claims: vec![],
+            rules: vec![],
         };
 
         let field_update = Self::update_field(&mut auth_jwt_obj, field);
@@ -80,8 +88,46 @@ impl AuthJWT {
         Ok(())
     }
 
+    pub fn set_claims(auth_obj: &mut AuthJWT, claims: Vec<String>) -> Result<(), (usize, String)> {
+        let mappings = auto_fetch_all_mappings();
+        let all_constraints = match auto_fetch_all_constraints(&mappings) {
+            Ok(c) => c,
+            Err(e) => return Err((500, e)),
+        };
+
+        let mut final_claims = Vec::<String>::new();
+        for claim in claims.iter() {
+            let final_value =
+                match ConstraintProperty::validate(&all_constraints, "auth_jwt", "field", claim) {
+                    Ok(v) => v,
+                    Err(e) => return Err(e),
+                };
+
+            if final_value.trim().len() < 1 {
+                return Err((400, String::from("Error: claim is empty")));
+            }
+
+            if final_claims.contains(&final_value) {
+                return Err((
+                    400,
+                    format!("Error: claim '{}' is defined twice", final_value),
+                ));
+            }
+
+            final_claims.push(final_value);
+        }
+
+        auth_obj.claims = final_claims;
+
+        Ok(())
+    }
+
+    pub fn set_rules(auth_obj: &mut AuthJWT, rules: Vec<AuthRule>) {
+        auth_obj.rules = rules;
+    }
+
     pub fn to_string(auth_obj: AuthJWT) -> String {
-        format!(
+        let mut auth_obj_str = format!(
             "DEFINE auth_jwt [{},{},{}]",
             if auth_obj.active == true {
                 "true"
@@ -90,7 +136,26 @@ impl AuthJWT {
             },
             auth_obj.field,
             auth_obj.ref_col
-        )
+        );
+
+        if auth_obj.claims.len() > 0 {
+            auth_obj_str = format!("{} claims=({})", auth_obj_str, auth_obj.claims.join(","));
+        }
+
+        if auth_obj.rules.len() > 0 {
+            auth_obj_str = format!(
+                "{} rules={}",
+                auth_obj_str,
+                auth_obj
+                    .rules
+                    .iter()
+                    .map(|rule| format!("({})", AuthRule::to_string(rule.clone())))
+                    .collect::<Vec<String>>()
+                    .join(">")
+            );
+        }
+
+        auth_obj_str
     }
 
     pub fn from_string(auth_obj_str: &str) -> Result<AuthJWT, (usize, String)> {
@@ -111,10 +176,45 @@ impl AuthJWT {
             return Err((500, String::from("Invalid auth_jwt (in format)")));
         }
 
-        AuthJWT::create(
+        let mut auth_obj = match AuthJWT::create(
             current_auth_obj[0] == "true",
             current_auth_obj[1],
             current_auth_obj[2],
-        )
+        ) {
+            Ok(aj) => aj,
+            Err(e) => return Err(e),
+        };
+
+        let claims_str = auth_obj_str.split(" claims=(").collect::<Vec<&str>>();
+        if claims_str.len() > 1 {
+            let claims = claims_str[1].split(")").collect::<Vec<&str>>()[0]
+                .split(",")
+                .filter(|claim| claim.trim().len() > 0)
+                .map(|claim| claim.trim().to_string())
+                .collect::<Vec<String>>();
+
+            if let Err(e) = AuthJWT::set_claims(&mut auth_obj, claims) {
+                return Err((500, format!("Invalid auth_jwt (in claims) -> {}", e.1)));
+            }
+        }
+
+        let rules_str = auth_obj_str.split(" rules=").collect::<Vec<&str>>();
+        if rules_str.len() > 1 {
+            let mut rules = Vec::<AuthRule>::new();
+            for rule_str in rules_str[1].trim().split(">") {
+                let trimmed = rule_str
+                    .trim()
+                    .trim_start_matches("(")
+                    .trim_end_matches(")");
+                match AuthRule::from_string(trimmed) {
+                    Ok(rule) => rules.push(rule),
+                    Err(e) => return Err((500, format!("Invalid auth_jwt (in rules) -> {}", e.1))),
+                }
+            }
+
+            AuthJWT::set_rules(&mut auth_obj, rules);
+        }
+
+        Ok(auth_obj)
     }
 }
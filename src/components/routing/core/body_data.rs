@@ -10,6 +10,20 @@ use rocket::serde::{Deserialize, Serialize};
 pub struct BodyData {
     pub id: String,
     pub bdtype: BodyDataType,
+    #[serde(default)]
+    pub element_type: Option<BodyDataType>,
+    #[serde(default = "default_required")]
+    pub required: bool,
+    #[serde(default)]
+    pub min: Option<usize>,
+    #[serde(default)]
+    pub max: Option<usize>,
+    #[serde(default)]
+    pub fields: Vec<BodyData>,
+}
+
+fn default_required() -> bool {
+    true
 }
 
 impl BodyData {
@@ -27,6 +41,11 @@ impl BodyData {
         let new_body_data = BodyData {
             id: tmp_id.clone(),
             bdtype: BodyDataType::default(),
+            element_type: None,
+            required: true,
+            min: None,
+            max: None,
+            fields: vec![],
         };
         all_pairs.push(new_body_data);
 
@@ -117,24 +136,150 @@ impl BodyData {
     ) -> Result<(), (usize, String)> {
         let mut found_body_data: Option<BodyData> = None;
 
+        let mut broken_bdtype = bdtype_txt.trim().split("<").collect::<Vec<&str>>();
+        let mut element_type: Option<BodyDataType> = None;
+
         let mappings = auto_fetch_all_mappings();
         let all_constraints = match auto_fetch_all_constraints(&mappings) {
             Ok(c) => c,
             Err(e) => return Err((500, e)),
         };
-        let final_value =
-            match ConstraintProperty::validate(&all_constraints, "body_data", "bdtype", bdtype_txt)
-            {
+
+        if broken_bdtype.len() > 1 {
+            let element_txt = broken_bdtype[1].split(">").collect::<Vec<&str>>()[0];
+            let element_value = match ConstraintProperty::validate(
+                &all_constraints,
+                "body_data",
+                "bdtype",
+                element_txt,
+            ) {
                 Ok(v) => v,
                 Err(e) => return Err(e),
             };
 
+            element_type = Some(BodyDataType::from(&element_value));
+            broken_bdtype = vec![broken_bdtype[0]];
+        }
+
+        let final_value = match ConstraintProperty::validate(
+            &all_constraints,
+            "body_data",
+            "bdtype",
+            broken_bdtype[0],
+        ) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+
         let bdtype = BodyDataType::from(&final_value);
 
+        if element_type.is_some() && bdtype != BodyDataType::ARRAY {
+            return Err((
+                400,
+                String::from("Error: Only arrays can have an element type"),
+            ));
+        }
+
         for body_data in all_pairs.iter_mut() {
             if body_data.id == *id {
                 found_body_data = Some(body_data.clone());
                 body_data.bdtype = bdtype;
+                body_data.element_type = element_type;
+                break;
+            }
+        }
+
+        if let None = found_body_data {
+            return Err((404, String::from("Error: Body Data not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_required(
+        all_pairs: &mut Vec<BodyData>,
+        id: &String,
+        required: bool,
+    ) -> Result<(), (usize, String)> {
+        let mut found_body_data: Option<BodyData> = None;
+
+        for body_data in all_pairs.iter_mut() {
+            if body_data.id == *id {
+                found_body_data = Some(body_data.clone());
+                body_data.required = required;
+                break;
+            }
+        }
+
+        if let None = found_body_data {
+            return Err((404, String::from("Error: Body Data not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_length(
+        all_pairs: &mut Vec<BodyData>,
+        id: &String,
+        min: Option<usize>,
+        max: Option<usize>,
+    ) -> Result<(), (usize, String)> {
+        let mut found_body_data: Option<BodyData> = None;
+
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err((
+                    400,
+                    String::from("Error: min should not be greater than max"),
+                ));
+            }
+        }
+
+        for body_data in all_pairs.iter_mut() {
+            if body_data.id == *id {
+                if body_data.bdtype != BodyDataType::STRING
+                    && body_data.bdtype != BodyDataType::ARRAY
+                {
+                    return Err((
+                        400,
+                        String::from("Error: Only strings and arrays can have a length"),
+                    ));
+                }
+
+                found_body_data = Some(body_data.clone());
+                body_data.min = min;
+                body_data.max = max;
+                break;
+            }
+        }
+
+        if let None = found_body_data {
+            return Err((404, String::from("Error: Body Data not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn set_fields(
+        all_pairs: &mut Vec<BodyData>,
+        id: &String,
+        fields: Vec<BodyData>,
+    ) -> Result<(), (usize, String)> {
+        let mut found_body_data: Option<BodyData> = None;
+
+        for body_data in all_pairs.iter_mut() {
+            if body_data.id == *id {
+                if body_data.bdtype != BodyDataType::OTHER
+                    && body_data.element_type != Some(BodyDataType::OTHER)
+                {
+                    return Err((
+                        400,
+                        String::from("Error: Only objects and arrays of objects can have fields"),
+                    ));
+                }
+
+                found_body_data = Some(body_data.clone());
+                body_data.fields = fields;
                 break;
             }
         }
@@ -166,6 +311,11 @@ impl BodyData {
             .map(|body_data| BodyData {
                 id: body_data.id.clone(),
                 bdtype: body_data.bdtype.clone(),
+                element_type: body_data.element_type.clone(),
+                required: body_data.required,
+                min: body_data.min,
+                max: body_data.max,
+                fields: body_data.fields.clone(),
             })
             .collect::<Vec<BodyData>>();
 
@@ -175,6 +325,10 @@ impl BodyData {
     }
 
     pub fn stringify(all_pairs: &Vec<BodyData>, is_param: bool) -> String {
+        BodyData::stringify_with_prefix(all_pairs, Self::prefix(is_param))
+    }
+
+    pub fn stringify_with_prefix(all_pairs: &Vec<BodyData>, pre_string: &str) -> String {
         let mut stringified_body_data = String::new();
 
         for body_data in all_pairs {
@@ -186,24 +340,52 @@ impl BodyData {
                 } else {
                     ""
                 },
-                BodyData::to_string(body_data.clone(), is_param),
+                BodyData::to_string_with_prefix(body_data.clone(), pre_string),
             );
+
+            let stringified_fields =
+                BodyData::stringify_fields(&body_data.fields, pre_string, &body_data.id);
+            if stringified_fields.len() > 0 {
+                stringified_body_data =
+                    format!("{}\n{}", stringified_body_data, stringified_fields);
+            }
         }
 
         stringified_body_data
     }
 
+    fn stringify_fields(all_fields: &Vec<BodyData>, pre_string: &str, path: &str) -> String {
+        let mut stringified_fields = Vec::<String>::new();
+
+        for field in all_fields {
+            let field_path = format!("{}/{}", path, field.id);
+            let mut current_field = field.clone();
+            current_field.id = field_path.clone();
+
+            stringified_fields.push(BodyData::to_string_with_prefix(current_field, pre_string));
+
+            let nested_fields = BodyData::stringify_fields(&field.fields, pre_string, &field_path);
+            if nested_fields.len() > 0 {
+                stringified_fields.push(nested_fields);
+            }
+        }
+
+        stringified_fields.join("\n")
+    }
+
     pub fn from_string(
         all_pairs: &mut Vec<BodyData>,
         body_data_str: &str,
         is_param: bool,
     ) -> Result<(), (usize, String)> {
-        let pre_string = if is_param {
-            "ADD PARAMS pair"
-        } else {
-            "ADD BODY pair"
-        };
+        BodyData::from_string_with_prefix(all_pairs, body_data_str, Self::prefix(is_param))
+    }
 
+    pub fn from_string_with_prefix(
+        all_pairs: &mut Vec<BodyData>,
+        body_data_str: &str,
+        pre_string: &str,
+    ) -> Result<(), (usize, String)> {
         let mut current_body_data_obj = body_data_str
             .split(&format!("{} [", pre_string))
             .collect::<Vec<&str>>();
@@ -224,22 +406,112 @@ impl BodyData {
             return Err((500, String::from("Invalid body_data (in format)")));
         }
 
-        BodyData::create(
-            all_pairs,
-            current_body_data_obj[0],
-            current_body_data_obj[1],
-        )
+        let broken_path = current_body_data_obj[0].split("/").collect::<Vec<&str>>();
+        let id = broken_path[broken_path.len() - 1].to_string();
+
+        let current_pairs =
+            match BodyData::find_fields(all_pairs, &broken_path[..broken_path.len() - 1]) {
+                Some(p) => p,
+                None => {
+                    return Err((
+                        500,
+                        format!(
+                            "Invalid body_data (parent of '{}' not found)",
+                            current_body_data_obj[0]
+                        ),
+                    ))
+                }
+            };
+
+        if let Err(e) = BodyData::create(current_pairs, &id, current_body_data_obj[1]) {
+            return Err(e);
+        }
+
+        let mut required = true;
+        let mut min: Option<usize> = None;
+        let mut max: Option<usize> = None;
+
+        for option in current_body_data_obj[2..].iter() {
+            let option = option.trim();
+            if option == "optional" {
+                required = false;
+            } else if option.starts_with("min=") {
+                min = match option[4..].parse::<usize>() {
+                    Ok(m) => Some(m),
+                    Err(e) => return Err((500, format!("Invalid body_data (at min -> {})", e))),
+                };
+            } else if option.starts_with("max=") {
+                max = match option[4..].parse::<usize>() {
+                    Ok(m) => Some(m),
+                    Err(e) => return Err((500, format!("Invalid body_data (at max -> {})", e))),
+                };
+            }
+        }
+
+        if !required {
+            if let Err(e) = BodyData::update_required(current_pairs, &id, required) {
+                return Err(e);
+            }
+        }
+
+        if min.is_some() || max.is_some() {
+            if let Err(e) = BodyData::update_length(current_pairs, &id, min, max) {
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find_fields<'a>(
+        all_pairs: &'a mut Vec<BodyData>,
+        path: &[&str],
+    ) -> Option<&'a mut Vec<BodyData>> {
+        if path.len() == 0 {
+            return Some(all_pairs);
+        }
+
+        for body_data in all_pairs.iter_mut() {
+            if body_data.id == path[0] {
+                return BodyData::find_fields(&mut body_data.fields, &path[1..]);
+            }
+        }
+
+        None
     }
 
     pub fn to_string(body_data: BodyData, is_param: bool) -> String {
-        let bdtype_txt = BodyDataType::to(body_data.bdtype);
+        BodyData::to_string_with_prefix(body_data, Self::prefix(is_param))
+    }
+
+    pub fn to_string_with_prefix(body_data: BodyData, pre_string: &str) -> String {
+        let mut bdtype_txt = BodyDataType::to(body_data.bdtype);
+        if let Some(element_type) = body_data.element_type {
+            bdtype_txt = format!("{}<{}>", bdtype_txt, BodyDataType::to(element_type));
+        }
+
+        let mut options = String::new();
+        if !body_data.required {
+            options = format!("{},optional", options);
+        }
+        if let Some(min) = body_data.min {
+            options = format!("{},min={}", options, min);
+        }
+        if let Some(max) = body_data.max {
+            options = format!("{},max={}", options, max);
+        }
+
+        format!(
+            "{} [{},{}{}]",
+            pre_string, body_data.id, bdtype_txt, options
+        )
+    }
 
-        let pre_string = if is_param {
+    fn prefix(is_param: bool) -> &'static str {
+        if is_param {
             "ADD PARAMS pair"
         } else {
             "ADD BODY pair"
-        };
-
-        format!("{} [{},{}]", pre_string, body_data.id, bdtype_txt)
+        }
     }
 }
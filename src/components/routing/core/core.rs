@@ -6,3 +6,9 @@ pub mod core_body_data;
 
 #[path = "param_data.rs"]
 pub mod core_param_data;
+
+#[path = "rate_limit.rs"]
+pub mod core_rate_limit;
+
+#[path = "test_case.rs"]
+pub mod core_test_case;
@@ -0,0 +1,96 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use super::super::submodules::sub_rate_limit_key::RateLimitKey;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub window: u32,
+    pub key: RateLimitKey,
+}
+
+impl RateLimit {
+    pub fn create(requests: u32, window: u32, key: &str) -> Result<RateLimit, (usize, String)> {
+        let mut rate_limit_obj = RateLimit {
+            requests: 1,
+            window: 1,
+            key: RateLimitKey::from(key),
+        };
+
+        if let Err(e) = Self::update_limit(&mut rate_limit_obj, requests, window) {
+            println!("{}", e.1);
+            return Err(e);
+        }
+
+        Ok(rate_limit_obj)
+    }
+
+    pub fn update_limit(
+        rate_limit_obj: &mut RateLimit,
+        requests: u32,
+        window: u32,
+    ) -> Result<(), (usize, String)> {
+        if requests < 1 {
+            return Err((
+                400,
+                String::from("Error: requests should be greater than 0"),
+            ));
+        }
+
+        if window < 1 {
+            return Err((400, String::from("Error: window should be greater than 0")));
+        }
+
+        rate_limit_obj.requests = requests;
+        rate_limit_obj.window = window;
+
+        Ok(())
+    }
+
+    pub fn update_key(rate_limit_obj: &mut RateLimit, key: &str) {
+        rate_limit_obj.key = RateLimitKey::from(key);
+    }
+
+    pub fn to_string(rate_limit_obj: RateLimit) -> String {
+        format!(
+            "DEFINE rate_limit [{},{},{}]",
+            rate_limit_obj.requests,
+            rate_limit_obj.window,
+            RateLimitKey::to(rate_limit_obj.key)
+        )
+    }
+
+    pub fn from_string(rate_limit_obj_str: &str) -> Result<RateLimit, (usize, String)> {
+        let mut current_rate_limit_obj = rate_limit_obj_str
+            .split("DEFINE rate_limit [")
+            .collect::<Vec<&str>>();
+        if current_rate_limit_obj.len() <= 1 {
+            return Err((
+                500,
+                String::from("Invalid rate_limit (at declaration start)"),
+            ));
+        }
+
+        current_rate_limit_obj = current_rate_limit_obj[1].split("]").collect::<Vec<&str>>();
+        if current_rate_limit_obj.len() <= 1 {
+            return Err((500, String::from("Invalid rate_limit (at declaration end)")));
+        }
+
+        current_rate_limit_obj = current_rate_limit_obj[0].split(",").collect::<Vec<&str>>();
+        if current_rate_limit_obj.len() < 3 {
+            return Err((500, String::from("Invalid rate_limit (in format)")));
+        }
+
+        let requests = match current_rate_limit_obj[0].trim().parse::<u32>() {
+            Ok(r) => r,
+            Err(e) => return Err((500, format!("Invalid rate_limit (at requests -> {})", e))),
+        };
+
+        let window = match current_rate_limit_obj[1].trim().parse::<u32>() {
+            Ok(w) => w,
+            Err(e) => return Err((500, format!("Invalid rate_limit (at window -> {})", e))),
+        };
+
+        RateLimit::create(requests, window, current_rate_limit_obj[2])
+    }
+}
@@ -0,0 +1,278 @@
+use rocket::serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RouteTestCase {
+    pub id: String,
+    pub status: usize,
+    #[serde(default)]
+    pub token: String,
+    #[serde(default)]
+    pub path: String,
+    #[serde(default)]
+    pub params: String,
+    #[serde(default)]
+    pub body: Value,
+    #[serde(default)]
+    pub expected: Value,
+}
+
+impl RouteTestCase {
+    pub fn create(
+        id: &str,
+        status: usize,
+        token: &str,
+        path: &str,
+        params: &str,
+        body: Value,
+        expected: Value,
+    ) -> Result<RouteTestCase, (usize, String)> {
+        let mut has_error: bool = false;
+        let mut latest_error: (usize, String) = (500, String::new());
+
+        let mut test_case = RouteTestCase {
+            id: "".to_string(),
+            status: 200,
+            token: "".to_string(),
+            path: "".to_string(),
+            params: "".to_string(),
+            body: body,
+            expected: expected,
+        };
+
+        let id_update = Self::update_id(&mut test_case, id);
+        if let Err(e) = id_update {
+            has_error = true;
+            println!("{}", e.1);
+            latest_error = e;
+        }
+
+        if !has_error {
+            let status_update = Self::update_status(&mut test_case, status);
+            if let Err(e) = status_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if !has_error {
+            let request_update = Self::update_request(&mut test_case, token, path, params);
+            if let Err(e) = request_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if has_error {
+            return Err(latest_error);
+        }
+
+        Ok(test_case)
+    }
+
+    pub fn update_id(test_case: &mut RouteTestCase, id: &str) -> Result<(), (usize, String)> {
+        let id = id.trim();
+        if id.len() < 1 || id.len() > 100 {
+            return Err((
+                400,
+                String::from("Error: test case id should be between 1 and 100 characters"),
+            ));
+        }
+
+        if !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err((
+                400,
+                String::from("Error: test case id contains an invalid character"),
+            ));
+        }
+
+        test_case.id = id.to_string();
+
+        Ok(())
+    }
+
+    pub fn update_status(
+        test_case: &mut RouteTestCase,
+        status: usize,
+    ) -> Result<(), (usize, String)> {
+        if status < 100 || status > 599 {
+            return Err((
+                400,
+                String::from("Error: expected status should be between 100 and 599"),
+            ));
+        }
+
+        test_case.status = status;
+
+        Ok(())
+    }
+
+    pub fn update_request(
+        test_case: &mut RouteTestCase,
+        token: &str,
+        path: &str,
+        params: &str,
+    ) -> Result<(), (usize, String)> {
+        if !token
+            .trim()
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' || c == '@')
+        {
+            return Err((
+                400,
+                String::from("Error: test case token contains an invalid character"),
+            ));
+        }
+
+        if path.trim().contains(char::is_whitespace) || params.trim().contains(char::is_whitespace)
+        {
+            return Err((
+                400,
+                String::from("Error: test case path and params should not contain whitespace"),
+            ));
+        }
+
+        if path.trim().len() > 0 && !path.trim().starts_with("/") {
+            return Err((
+                400,
+                String::from("Error: test case path should start with /"),
+            ));
+        }
+
+        test_case.token = token.trim().to_string();
+        test_case.path = path.trim().to_string();
+        test_case.params = params.trim().to_string();
+
+        Ok(())
+    }
+
+    pub fn stringify(all_cases: &Vec<RouteTestCase>) -> String {
+        all_cases
+            .iter()
+            .map(|test_case| RouteTestCase::to_string(test_case.clone()))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn to_string(test_case: RouteTestCase) -> String {
+        format!(
+            "ADD TEST case [{},{},{}] path={} params={} body={} expect={}",
+            test_case.id,
+            test_case.status,
+            test_case.token,
+            test_case.path,
+            test_case.params,
+            test_case.body.to_string(),
+            test_case.expected.to_string()
+        )
+    }
+
+    fn take_value(test_case_str: &str, key: &str) -> Result<(String, usize), (usize, String)> {
+        let prefix = format!(" {}=", key);
+        if !test_case_str.starts_with(&prefix) {
+            return Err((500, format!("Invalid test case (at {})", key)));
+        }
+
+        let rest = &test_case_str[prefix.len()..];
+        let end = match rest.find(" ") {
+            Some(e) => e,
+            None => rest.len(),
+        };
+
+        Ok((rest[..end].to_string(), prefix.len() + end))
+    }
+
+    fn take_json(test_case_str: &str, key: &str) -> Result<(Value, usize), (usize, String)> {
+        let prefix = format!(" {}=", key);
+        if !test_case_str.starts_with(&prefix) {
+            return Err((500, format!("Invalid test case (at {})", key)));
+        }
+
+        let rest = &test_case_str[prefix.len()..];
+        let mut stream = serde_json::Deserializer::from_str(rest).into_iter::<Value>();
+        match stream.next() {
+            Some(Ok(value)) => Ok((value, prefix.len() + stream.byte_offset())),
+            _ => Err((500, format!("Invalid test case (in {})", key))),
+        }
+    }
+
+    pub fn from_string(test_case_str: &str) -> Result<RouteTestCase, (usize, String)> {
+        let declaration = match test_case_str.find("ADD TEST case [") {
+            Some(d) => &test_case_str[d + "ADD TEST case [".len()..],
+            None => {
+                return Err((
+                    500,
+                    String::from("Invalid test case (at declaration start)"),
+                ))
+            }
+        };
+
+        let end = match declaration.find("]") {
+            Some(e) => e,
+            None => return Err((500, String::from("Invalid test case (at declaration end)"))),
+        };
+
+        let header = declaration[..end].split(",").collect::<Vec<&str>>();
+        if header.len() < 3 {
+            return Err((500, String::from("Invalid test case (in format)")));
+        }
+
+        let status = match header[1].trim().parse::<usize>() {
+            Ok(s) => s,
+            Err(e) => return Err((500, format!("Invalid test case (at status) -> {}", e))),
+        };
+
+        let mut rest = &declaration[end + 1..];
+
+        let (path, offset) = match Self::take_value(rest, "path") {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+        rest = &rest[offset..];
+
+        let (params, offset) = match Self::take_value(rest, "params") {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+        rest = &rest[offset..];
+
+        let (body, offset) = match Self::take_json(rest, "body") {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+        rest = &rest[offset..];
+
+        let (expected, _) = match Self::take_json(rest, "expect") {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+
+        RouteTestCase::create(header[0], status, header[2], &path, &params, body, expected)
+    }
+}
+
+pub fn json_contains(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Object(actual_map), Value::Object(expected_map)) => {
+            expected_map
+                .iter()
+                .all(|(key, expected_value)| match actual_map.get(key) {
+                    Some(actual_value) => json_contains(actual_value, expected_value),
+                    None => false,
+                })
+        }
+        (Value::Array(actual_arr), Value::Array(expected_arr)) => {
+            actual_arr.len() == expected_arr.len()
+                && actual_arr
+                    .iter()
+                    .zip(expected_arr.iter())
+                    .all(|(a, e)| json_contains(a, e))
+        }
+        _ => actual == expected,
+    }
+}
@@ -9,19 +9,38 @@ use crate::{
 };
 
 use super::{
-    blocks::{create_block::CreateBlock, fetch_block::FetchBlock, update_block::UpdateBlock},
-    core::{core_auth_jwt::AuthJWT, core_body_data::BodyData, core_param_data::ParamData},
+    blocks::{
+        create_block::CreateBlock, delete_block::DeleteBlock, fetch_block::FetchBlock,
+        update_block::UpdateBlock,
+    },
+    core::{
+        core_auth_jwt::AuthJWT, core_body_data::BodyData, core_param_data::ParamData,
+        core_rate_limit::RateLimit, core_test_case::RouteTestCase,
+    },
     mod_route_flow::RouteFlow,
+    submodules::sub_route_method::RouteMethod,
 };
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RouteComponent {
     pub route_id: String,
     pub route_path: String,
+    #[serde(default)]
+    pub method: RouteMethod,
     pub project_id: String,
     pub auth_jwt: Option<AuthJWT>,
+    #[serde(default)]
+    pub auth_api_key: bool,
     pub body: Vec<BodyData>,
+    #[serde(default)]
+    pub path_params: Vec<BodyData>,
     pub params: Option<ParamData>,
+    #[serde(default)]
+    pub rate_limit: Option<RateLimit>,
+    #[serde(default)]
+    pub cache_ttl: u32,
+    #[serde(default)]
+    pub tests: Vec<RouteTestCase>,
     pub flow: RouteFlow,
 }
 
@@ -30,6 +49,7 @@ impl RouteComponent {
         all_routes: &mut Vec<RouteComponent>,
         route_id: &str,
         route_path: &str,
+        method: RouteMethod,
         project_id: &str,
         flow: RouteFlow,
     ) -> Result<(), (usize, String)> {
@@ -42,10 +62,16 @@ impl RouteComponent {
         let new_route = RouteComponent {
             route_id: tmp_route_id.to_string(),
             route_path: "".to_string(),
+            method: method,
             project_id: "".to_string(),
             auth_jwt: None,
+            auth_api_key: false,
             body: vec![],
+            path_params: vec![],
             params: None,
+            rate_limit: None,
+            cache_ttl: 0,
+            tests: vec![],
             flow: flow,
         };
         all_routes.push(new_route);
@@ -100,6 +126,15 @@ impl RouteComponent {
         found
     }
 
+    pub fn has_access_checks(route: &RouteComponent) -> bool {
+        let auth_jwt_active = match &route.auth_jwt {
+            Some(aj) => aj.active,
+            None => false,
+        };
+
+        route.auth_api_key || auth_jwt_active || route.rate_limit.is_some()
+    }
+
     pub fn get(
         all_routes: &Vec<RouteComponent>,
         project_id: &str,
@@ -166,8 +201,16 @@ impl RouteComponent {
     ) -> Result<(), (usize, String)> {
         let mut found_route: Option<RouteComponent> = None;
 
+        let mut method = RouteMethod::default();
         for route in all_routes.iter() {
-            if route.route_path == route_path {
+            if route.route_id == *route_id {
+                method = route.method.clone();
+                break;
+            }
+        }
+
+        for route in all_routes.iter() {
+            if route.route_path == route_path && route.method == method {
                 return Err((403, String::from("Error: route_path is already in use")));
             }
         }
@@ -202,6 +245,48 @@ impl RouteComponent {
         Ok(())
     }
 
+    pub fn update_method(
+        all_routes: &mut Vec<RouteComponent>,
+        route_id: &String,
+        method: RouteMethod,
+    ) -> Result<(), (usize, String)> {
+        let mut found_route: Option<RouteComponent> = None;
+
+        let mut route_path = String::new();
+        for route in all_routes.iter() {
+            if route.route_id == *route_id {
+                route_path = route.route_path.clone();
+                break;
+            }
+        }
+
+        for route in all_routes.iter() {
+            if route.route_id != *route_id
+                && route.route_path == route_path
+                && route.method == method
+            {
+                return Err((
+                    403,
+                    String::from("Error: route_path is already in use for this method"),
+                ));
+            }
+        }
+
+        for route in all_routes.iter_mut() {
+            if route.route_id == *route_id {
+                found_route = Some(route.clone());
+                route.method = method;
+                break;
+            }
+        }
+
+        if let None = found_route {
+            return Err((404, String::from("Error: Route not found")));
+        }
+
+        Ok(())
+    }
+
     pub fn update_project_id(
         all_routes: &mut Vec<RouteComponent>,
         route_id: &String,
@@ -261,6 +346,91 @@ impl RouteComponent {
         Ok(())
     }
 
+    pub fn update_rate_limit(
+        all_routes: &mut Vec<RouteComponent>,
+        route_id: &String,
+        rate_limit: Option<RateLimit>,
+    ) -> Result<(), (usize, String)> {
+        let mut found_route: Option<RouteComponent> = None;
+
+        for route in all_routes.iter_mut() {
+            if route.route_id == *route_id {
+                found_route = Some(route.clone());
+                route.rate_limit = rate_limit;
+                break;
+            }
+        }
+
+        if let None = found_route {
+            return Err((404, String::from("Error: Route not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_auth_api_key(
+        all_routes: &mut Vec<RouteComponent>,
+        route_id: &String,
+        auth_api_key: bool,
+    ) -> Result<(), (usize, String)> {
+        let mut found_route: Option<RouteComponent> = None;
+
+        for route in all_routes.iter_mut() {
+            if route.route_id == *route_id {
+                found_route = Some(route.clone());
+                route.auth_api_key = auth_api_key;
+                break;
+            }
+        }
+
+        if let None = found_route {
+            return Err((404, String::from("Error: Route not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_cache_ttl(
+        all_routes: &mut Vec<RouteComponent>,
+        route_id: &String,
+        cache_ttl: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut found_route: Option<RouteComponent> = None;
+
+        for route in all_routes.iter_mut() {
+            if route.route_id == *route_id {
+                found_route = Some(route.clone());
+                route.cache_ttl = cache_ttl;
+                break;
+            }
+        }
+
+        if let None = found_route {
+            return Err((404, String::from("Error: Route not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn is_read_only(route: &RouteComponent) -> bool {
+        route.flow.creates.len() == 0
+            && route.flow.updates.len() == 0
+            && route.flow.deletes.len() == 0
+            && route.flow.calls.len() == 0
+    }
+
+    pub fn fetched_collections(route: &RouteComponent) -> Vec<String> {
+        let mut all_collections = Vec::<String>::new();
+
+        for fetcher in route.flow.fetchers.iter() {
+            if !all_collections.contains(&fetcher.ref_col) {
+                all_collections.push(fetcher.ref_col.clone());
+            }
+        }
+
+        all_collections
+    }
+
     pub fn add_body_data(
         all_routes: &mut Vec<RouteComponent>,
         route_id: &String,
@@ -342,6 +512,59 @@ impl RouteComponent {
         Ok(())
     }
 
+    pub fn set_path_params(
+        all_routes: &mut Vec<RouteComponent>,
+        route_id: &String,
+        path_params: Vec<BodyData>,
+    ) -> Result<(), (usize, String)> {
+        let mut found_route: Option<RouteComponent> = None;
+
+        for route in all_routes.iter_mut() {
+            if route.route_id == *route_id {
+                found_route = Some(route.clone());
+                route.path_params = path_params;
+                break;
+            }
+        }
+
+        if let None = found_route {
+            return Err((404, String::from("Error: Route not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn set_tests(
+        all_routes: &mut Vec<RouteComponent>,
+        route_id: &String,
+        tests: Vec<RouteTestCase>,
+    ) -> Result<(), (usize, String)> {
+        let mut found_route: Option<RouteComponent> = None;
+
+        for (i, test_case) in tests.iter().enumerate() {
+            if tests[..i].iter().any(|t| t.id == test_case.id) {
+                return Err((
+                    400,
+                    format!("Error: test case '{}' is defined twice", test_case.id),
+                ));
+            }
+        }
+
+        for route in all_routes.iter_mut() {
+            if route.route_id == *route_id {
+                found_route = Some(route.clone());
+                route.tests = tests;
+                break;
+            }
+        }
+
+        if let None = found_route {
+            return Err((404, String::from("Error: Route not found")));
+        }
+
+        Ok(())
+    }
+
     pub fn update_params(
         all_routes: &mut Vec<RouteComponent>,
         route_id: &String,
@@ -409,10 +632,16 @@ impl RouteComponent {
             .map(|route| RouteComponent {
                 route_id: route.route_id.clone(),
                 route_path: route.route_path.clone(),
+                method: route.method.clone(),
                 project_id: route.project_id.clone(),
                 auth_jwt: route.auth_jwt.clone(),
+                auth_api_key: route.auth_api_key,
                 body: route.body.clone(),
+                path_params: route.path_params.clone(),
                 params: route.params.clone(),
+                rate_limit: route.rate_limit.clone(),
+                cache_ttl: route.cache_ttl,
+                tests: route.tests.clone(),
                 flow: route.flow.clone(),
             })
             .collect::<Vec<RouteComponent>>();
@@ -431,14 +660,11 @@ impl RouteComponent {
         for route in all_routes.iter_mut() {
             if let Some(auth_jwt) = route.auth_jwt.clone() {
                 if auth_jwt.ref_col == collection_id {
-                    let new_auth_jwt = match AuthJWT::create(
-                        auth_jwt.active,
-                        &auth_jwt.field,
-                        new_collection_id,
-                    ) {
-                        Ok(auth_jwt) => auth_jwt,
-                        Err(err) => return Err(err),
-                    };
+                    let mut new_auth_jwt = auth_jwt.clone();
+                    if let Err(err) = AuthJWT::update_ref_col(&mut new_auth_jwt, new_collection_id)
+                    {
+                        return Err(err);
+                    }
 
                     if let Err(e) = RouteComponent::update_auth_jwt(
                         &mut updated_routes,
@@ -494,6 +720,20 @@ impl RouteComponent {
             }
             RouteFlow::set_create_blocks(&mut updated_flow, updated_create_blocks);
 
+            let mut updated_delete_blocks = route.flow.deletes.clone();
+            for delete_block in route.flow.deletes.iter() {
+                if delete_block.ref_col == collection_id {
+                    if let Err(e) = DeleteBlock::update_ref_col(
+                        &mut updated_delete_blocks,
+                        delete_block.global_index,
+                        new_collection_id,
+                    ) {
+                        return Err(e);
+                    }
+                }
+            }
+            RouteFlow::set_delete_blocks(&mut updated_flow, updated_delete_blocks);
+
             if let Err(e) =
                 RouteComponent::update_flow(&mut updated_routes, &route.route_id, updated_flow)
             {
@@ -556,6 +796,29 @@ impl RouteComponent {
         let project_id = current_route[0];
         let route_id = current_route[1].to_string();
         let route_path = current_route[2];
+        let method = if current_route.len() > 3 {
+            let mappings = auto_fetch_all_mappings();
+            let all_constraints = match auto_fetch_all_constraints(&mappings) {
+                Ok(c) => c,
+                Err(e) => return Err((500, e)),
+            };
+            let method_txt = match ConstraintProperty::validate(
+                &all_constraints,
+                "route_component",
+                "method",
+                current_route[3].trim(),
+            ) {
+                Ok(v) => v,
+                Err(e) => return Err((500, format!("Error: Invalid route format -> {}", e.1))),
+            };
+
+            match RouteMethod::from(&method_txt) {
+                Ok(m) => m,
+                Err(e) => return Err((500, format!("Error: Invalid route format -> {}", e.1))),
+            }
+        } else {
+            RouteMethod::POST
+        };
 
         current_route = route_str.split("START FLOW").collect::<Vec<&str>>();
         if current_route.len() <= 1 {
@@ -570,7 +833,12 @@ impl RouteComponent {
         current_route = current_route[0].split("\n").collect::<Vec<&str>>();
 
         let mut auth_jwt: Option<AuthJWT> = None;
+        let mut auth_api_key = false;
+        let mut rate_limit: Option<RateLimit> = None;
+        let mut cache_ttl: u32 = 0;
+        let mut tests = Vec::<RouteTestCase>::new();
         let mut body_data = Vec::<BodyData>::new();
+        let mut path_params = Vec::<BodyData>::new();
         let params: Option<ParamData>;
 
         let mut param_arr = Vec::<String>::new();
@@ -589,10 +857,57 @@ impl RouteComponent {
                         return Err((500, format!("Error: Invalid route format -> {}", e.1)));
                     }
                 }
+            } else if line.starts_with("DEFINE auth_api_key") {
+                auth_api_key = line.contains("[true]");
+            } else if line.starts_with("DEFINE rate_limit") {
+                match RateLimit::from_string(line) {
+                    Ok(rl) => {
+                        rate_limit = Some(rl);
+                    }
+                    Err(e) => {
+                        return Err((500, format!("Error: Invalid route format -> {}", e.1)));
+                    }
+                }
+            } else if line.starts_with("DEFINE cache") {
+                let current_cache = line.split("[").collect::<Vec<&str>>();
+                if current_cache.len() <= 1 {
+                    return Err((
+                        500,
+                        String::from("Error: Invalid route format (at DEFINE cache)"),
+                    ));
+                }
+
+                cache_ttl = match current_cache[1].split("]").collect::<Vec<&str>>()[0]
+                    .trim()
+                    .parse::<u32>()
+                {
+                    Ok(c) => c,
+                    Err(e) => {
+                        return Err((
+                            500,
+                            format!("Error: Invalid route format (in DEFINE cache) -> {}", e),
+                        ));
+                    }
+                };
+            } else if line.starts_with("ADD TEST case") {
+                match RouteTestCase::from_string(line) {
+                    Ok(tc) => {
+                        tests.push(tc);
+                    }
+                    Err(e) => {
+                        return Err((500, format!("Error: Invalid route format -> {}", e.1)));
+                    }
+                }
             } else if line.starts_with("ADD BODY pair") {
                 if let Err(e) = BodyData::from_string(&mut body_data, line, false) {
                     return Err((500, format!("Error: Invalid route format -> {}", e.1)));
                 }
+            } else if line.starts_with("ADD PATH pair") {
+                if let Err(e) =
+                    BodyData::from_string_with_prefix(&mut path_params, line, "ADD PATH pair")
+                {
+                    return Err((500, format!("Error: Invalid route format -> {}", e.1)));
+                }
             } else if line.starts_with("DEFINE PARAMS") || line.starts_with("ADD PARAMS") {
                 param_arr.push(line.to_string());
             }
@@ -614,9 +929,14 @@ impl RouteComponent {
             }
         };
 
-        if let Err(e) =
-            RouteComponent::create(all_routes, &route_id, route_path, project_id, flow.clone())
-        {
+        if let Err(e) = RouteComponent::create(
+            all_routes,
+            &route_id,
+            route_path,
+            method,
+            project_id,
+            flow.clone(),
+        ) {
             return Err((500, format!("Error: Invalid route format -> {}", e.1)));
         }
 
@@ -624,14 +944,34 @@ impl RouteComponent {
             return Err((500, format!("Error: Invalid route format -> {}", e.1)));
         }
 
+        if let Err(e) = RouteComponent::update_auth_api_key(all_routes, &route_id, auth_api_key) {
+            return Err((500, format!("Error: Invalid route format -> {}", e.1)));
+        }
+
+        if let Err(e) = RouteComponent::update_rate_limit(all_routes, &route_id, rate_limit) {
+            return Err((500, format!("Error: Invalid route format -> {}", e.1)));
+        }
+
+        if let Err(e) = RouteComponent::update_cache_ttl(all_routes, &route_id, cache_ttl) {
+            return Err((500, format!("Error: Invalid route format -> {}", e.1)));
+        }
+
         if let Err(e) = RouteComponent::set_body(all_routes, &route_id, body_data) {
             return Err((500, format!("Error: Invalid route format -> {}", e.1)));
         }
 
+        if let Err(e) = RouteComponent::set_path_params(all_routes, &route_id, path_params) {
+            return Err((500, format!("Error: Invalid route format -> {}", e.1)));
+        }
+
         if let Err(e) = RouteComponent::update_params(all_routes, &route_id, params) {
             return Err((500, format!("Error: Invalid route format -> {}", e.1)));
         }
 
+        if let Err(e) = RouteComponent::set_tests(all_routes, &route_id, tests) {
+            return Err((500, format!("Error: Invalid route format -> {}", e.1)));
+        }
+
         match RouteComponent::update_flow(all_routes, &route_id, flow) {
             Ok(_) => Ok(()),
             Err(e) => Err((500, format!("Error: Invalid route format -> {}", e.1))),
@@ -640,24 +980,55 @@ impl RouteComponent {
 
     pub fn to_string(route: RouteComponent) -> String {
         let mut route_str = format!(
-            "INIT ROUTE [{},{},{}]",
-            route.project_id, route.route_id, route.route_path
+            "INIT ROUTE [{},{},{},{}]",
+            route.project_id,
+            route.route_id,
+            route.route_path,
+            RouteMethod::to(route.method)
         );
 
         if let Some(auth_jwt) = route.auth_jwt {
             route_str = format!("{}\n\n{}", route_str, AuthJWT::to_string(auth_jwt));
         }
 
+        if route.auth_api_key {
+            route_str = format!("{}\n\nDEFINE auth_api_key [true]", route_str);
+        }
+
+        if let Some(rate_limit) = route.rate_limit {
+            route_str = format!("{}\n\n{}", route_str, RateLimit::to_string(rate_limit));
+        }
+
+        if route.cache_ttl > 0 {
+            route_str = format!("{}\n\nDEFINE cache [{}]", route_str, route.cache_ttl);
+        }
+
         route_str = format!(
             "{}\n\n{}",
             route_str,
             BodyData::stringify(&route.body, false)
         );
 
+        if route.path_params.len() > 0 {
+            route_str = format!(
+                "{}\n\n{}",
+                route_str,
+                BodyData::stringify_with_prefix(&route.path_params, "ADD PATH pair")
+            );
+        }
+
         if let Some(param) = route.params {
             route_str = format!("{}\n\n{}", route_str, ParamData::to_string(param));
         }
 
+        if route.tests.len() > 0 {
+            route_str = format!(
+                "{}\n\n{}",
+                route_str,
+                RouteTestCase::stringify(&route.tests)
+            );
+        }
+
         route_str = format!(
             "{}\nSTART FLOW{}",
             route_str,
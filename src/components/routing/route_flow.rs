@@ -1,10 +1,12 @@
 use rocket::serde::{Deserialize, Serialize};
 
 use super::blocks::{
-    assignment_block::AssignmentBlock, condition_block::ConditionBlock, create_block::CreateBlock,
-    fetch_block::FetchBlock, filter_block::FilterBlock, function_block::FunctionBlock,
-    loop_block::LoopBlock, object_block::ObjectBlock, property_block::PropertyBlock,
-    return_block::ReturnBlock, template_block::TemplateBlock, update_block::UpdateBlock,
+    assignment_block::AssignmentBlock, call_block::CallBlock, condition_block::ConditionBlock,
+    create_block::CreateBlock, delete_block::DeleteBlock, fetch_block::FetchBlock,
+    filter_block::FilterBlock, function_block::FunctionBlock, loop_block::LoopBlock,
+    object_block::ObjectBlock, property_block::PropertyBlock, return_block::ReturnBlock,
+    sort_block::SortBlock, template_block::TemplateBlock, try_block::TryBlock,
+    update_block::UpdateBlock,
 };
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -21,6 +23,14 @@ pub struct RouteFlow {
     pub updates: Vec<UpdateBlock>,
     pub creates: Vec<CreateBlock>,
     pub returns: Vec<ReturnBlock>,
+    #[serde(default)]
+    pub deletes: Vec<DeleteBlock>,
+    #[serde(default)]
+    pub sorts: Vec<SortBlock>,
+    #[serde(default)]
+    pub calls: Vec<CallBlock>,
+    #[serde(default)]
+    pub tries: Vec<TryBlock>,
 }
 
 impl RouteFlow {
@@ -37,6 +47,10 @@ impl RouteFlow {
         update_blocks: Vec<UpdateBlock>,
         create_blocks: Vec<CreateBlock>,
         return_blocks: Vec<ReturnBlock>,
+        delete_blocks: Vec<DeleteBlock>,
+        sort_blocks: Vec<SortBlock>,
+        call_blocks: Vec<CallBlock>,
+        try_blocks: Vec<TryBlock>,
     ) -> RouteFlow {
         RouteFlow {
             fetchers: fetch_blocks,
@@ -51,6 +65,10 @@ impl RouteFlow {
             updates: update_blocks,
             creates: create_blocks,
             returns: return_blocks,
+            deletes: delete_blocks,
+            sorts: sort_blocks,
+            calls: call_blocks,
+            tries: try_blocks,
         }
     }
 
@@ -426,6 +444,130 @@ impl RouteFlow {
         route_flow.returns = blocks;
     }
 
+    pub fn add_delete_block(route_flow: &mut RouteFlow, new_block: DeleteBlock) {
+        route_flow.deletes.push(new_block);
+    }
+
+    pub fn remove_delete_block(
+        route_flow: &mut RouteFlow,
+        block_index: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut updated_blocks = Vec::<DeleteBlock>::new();
+        if block_index >= route_flow.deletes.len() as u32 {
+            return Err((
+                400,
+                String::from("Error: Index goes over the amount of deletes present"),
+            ));
+        }
+
+        for n in 0..route_flow.deletes.len() {
+            if n as u32 != block_index {
+                updated_blocks.push(route_flow.deletes[n].clone());
+            }
+        }
+
+        route_flow.deletes = updated_blocks;
+
+        Ok(())
+    }
+
+    pub fn set_delete_blocks(route_flow: &mut RouteFlow, blocks: Vec<DeleteBlock>) {
+        route_flow.deletes = blocks;
+    }
+
+    pub fn add_sort_block(route_flow: &mut RouteFlow, new_block: SortBlock) {
+        route_flow.sorts.push(new_block);
+    }
+
+    pub fn remove_sort_block(
+        route_flow: &mut RouteFlow,
+        block_index: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut updated_blocks = Vec::<SortBlock>::new();
+        if block_index >= route_flow.sorts.len() as u32 {
+            return Err((
+                400,
+                String::from("Error: Index goes over the amount of sorts present"),
+            ));
+        }
+
+        for n in 0..route_flow.sorts.len() {
+            if n as u32 != block_index {
+                updated_blocks.push(route_flow.sorts[n].clone());
+            }
+        }
+
+        route_flow.sorts = updated_blocks;
+
+        Ok(())
+    }
+
+    pub fn set_sort_blocks(route_flow: &mut RouteFlow, blocks: Vec<SortBlock>) {
+        route_flow.sorts = blocks;
+    }
+
+    pub fn add_call_block(route_flow: &mut RouteFlow, new_block: CallBlock) {
+        route_flow.calls.push(new_block);
+    }
+
+    pub fn remove_call_block(
+        route_flow: &mut RouteFlow,
+        block_index: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut updated_blocks = Vec::<CallBlock>::new();
+        if block_index >= route_flow.calls.len() as u32 {
+            return Err((
+                400,
+                String::from("Error: Index goes over the amount of calls present"),
+            ));
+        }
+
+        for n in 0..route_flow.calls.len() {
+            if n as u32 != block_index {
+                updated_blocks.push(route_flow.calls[n].clone());
+            }
+        }
+
+        route_flow.calls = updated_blocks;
+
+        Ok(())
+    }
+
+    pub fn set_call_blocks(route_flow: &mut RouteFlow, blocks: Vec<CallBlock>) {
+        route_flow.calls = blocks;
+    }
+
+    pub fn add_try_block(route_flow: &mut RouteFlow, new_block: TryBlock) {
+        route_flow.tries.push(new_block);
+    }
+
+    pub fn remove_try_block(
+        route_flow: &mut RouteFlow,
+        block_index: u32,
+    ) -> Result<(), (usize, String)> {
+        let mut updated_blocks = Vec::<TryBlock>::new();
+        if block_index >= route_flow.tries.len() as u32 {
+            return Err((
+                400,
+                String::from("Error: Index goes over the amount of tries present"),
+            ));
+        }
+
+        for n in 0..route_flow.tries.len() {
+            if n as u32 != block_index {
+                updated_blocks.push(route_flow.tries[n].clone());
+            }
+        }
+
+        route_flow.tries = updated_blocks;
+
+        Ok(())
+    }
+
+    pub fn set_try_blocks(route_flow: &mut RouteFlow, blocks: Vec<TryBlock>) {
+        route_flow.tries = blocks;
+    }
+
     pub fn from_string(blocks_str: &str) -> Result<RouteFlow, (usize, String)> {
         let current_flow = blocks_str.split("\n").collect::<Vec<&str>>();
 
@@ -441,6 +583,10 @@ impl RouteFlow {
         let mut update_blocks = Vec::<UpdateBlock>::new();
         let mut create_blocks = Vec::<CreateBlock>::new();
         let mut return_blocks = Vec::<ReturnBlock>::new();
+        let mut delete_blocks = Vec::<DeleteBlock>::new();
+        let mut sort_blocks = Vec::<SortBlock>::new();
+        let mut call_blocks = Vec::<CallBlock>::new();
+        let mut try_blocks = Vec::<TryBlock>::new();
 
         for line in current_flow {
             if line.trim().len() <= 0 {
@@ -495,6 +641,22 @@ impl RouteFlow {
                 if let Err(e) = ReturnBlock::from_string(&mut return_blocks, line) {
                     return Err((500, format!("Invalid RETURN block -> {}", e.1)));
                 }
+            } else if line.starts_with("DELETE") {
+                if let Err(e) = DeleteBlock::from_string(&mut delete_blocks, line) {
+                    return Err((500, format!("Invalid DELETE block -> {}", e.1)));
+                }
+            } else if line.starts_with("SORT") {
+                if let Err(e) = SortBlock::from_string(&mut sort_blocks, line) {
+                    return Err((500, format!("Invalid SORT block -> {}", e.1)));
+                }
+            } else if line.starts_with("CALL") {
+                if let Err(e) = CallBlock::from_string(&mut call_blocks, line) {
+                    return Err((500, format!("Invalid CALL block -> {}", e.1)));
+                }
+            } else if line.starts_with("TRY") {
+                if let Err(e) = TryBlock::from_string(&mut try_blocks, line) {
+                    return Err((500, format!("Invalid TRY block -> {}", e.1)));
+                }
             }
         }
 
@@ -511,6 +673,10 @@ impl RouteFlow {
             update_blocks,
             create_blocks,
             return_blocks,
+            delete_blocks,
+            sort_blocks,
+            call_blocks,
+            try_blocks,
         ))
     }
 
@@ -518,7 +684,7 @@ impl RouteFlow {
         let mut blocks_str = String::new();
 
         blocks_str = format!(
-            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
             blocks_str,
             FetchBlock::stringify(&route_flow.fetchers),
             AssignmentBlock::stringify(&route_flow.assignments),
@@ -532,6 +698,10 @@ impl RouteFlow {
             UpdateBlock::stringify(&route_flow.updates),
             CreateBlock::stringify(&route_flow.creates),
             ReturnBlock::stringify(&route_flow.returns),
+            DeleteBlock::stringify(&route_flow.deletes),
+            SortBlock::stringify(&route_flow.sorts),
+            CallBlock::stringify(&route_flow.calls),
+            TryBlock::stringify(&route_flow.tries),
         );
 
         blocks_str
@@ -0,0 +1,146 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::{
+    components::{constraint_property::ConstraintProperty, datapair::DataPair},
+    utils::{constraint::auto_fetch_all_constraints, mapping::auto_fetch_all_mappings},
+};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuthRule {
+    pub field: String,
+    pub values: Vec<String>,
+}
+
+impl AuthRule {
+    pub fn create(field: &str, values: Vec<String>) -> Result<AuthRule, (usize, String)> {
+        let mut has_error: bool = false;
+        let mut latest_error: (usize, String) = (500, String::new());
+
+        let mut rule = AuthRule {
+            field: "".to_string(),
+            values: vec![],
+        };
+
+        let field_update = Self::update_field(&mut rule, field);
+        if let Err(e) = field_update {
+            has_error = true;
+            println!("{}", e.1);
+            latest_error = e;
+        }
+
+        if !has_error {
+            let values_update = Self::set_values(&mut rule, values);
+            if let Err(e) = values_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if has_error {
+            return Err(latest_error);
+        }
+
+        Ok(rule)
+    }
+
+    pub fn update_field(rule: &mut AuthRule, field: &str) -> Result<(), (usize, String)> {
+        let mappings = auto_fetch_all_mappings();
+        let all_constraints = match auto_fetch_all_constraints(&mappings) {
+            Ok(c) => c,
+            Err(e) => return Err((500, e)),
+        };
+        let final_value =
+            match ConstraintProperty::validate(&all_constraints, "auth_jwt", "field", field) {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+
+        if final_value.trim().len() < 1 {
+            return Err((400, String::from("Error: field of auth rule is empty")));
+        }
+
+        rule.field = final_value;
+
+        Ok(())
+    }
+
+    pub fn set_values(rule: &mut AuthRule, values: Vec<String>) -> Result<(), (usize, String)> {
+        if values.len() < 1 {
+            return Err((
+                400,
+                String::from("Error: auth rule should allow at least one value"),
+            ));
+        }
+
+        let mut final_values = Vec::<String>::new();
+        for value in values.iter() {
+            let trimmed = value.trim();
+            if trimmed.len() < 1 {
+                return Err((400, String::from("Error: auth rule value is empty")));
+            }
+
+            for c in [',', '|', '(', ')', '[', ']', '>', ';', '=', '\n'].iter() {
+                if trimmed.contains(*c) {
+                    return Err((
+                        400,
+                        format!(
+                            "Error: auth rule value contains a character that is not allowed ({})",
+                            c
+                        ),
+                    ));
+                }
+            }
+
+            final_values.push(trimmed.to_string());
+        }
+
+        rule.values = final_values;
+
+        Ok(())
+    }
+
+    pub fn check(rules: &Vec<AuthRule>, pairs: &Vec<DataPair>) -> Result<(), (usize, String)> {
+        for rule in rules.iter() {
+            let mut allowed = false;
+
+            for pair in pairs.iter() {
+                if pair.structure_id == rule.field {
+                    allowed = rule.values.contains(&pair.value.trim().to_string());
+                    break;
+                }
+            }
+
+            if !allowed {
+                return Err((
+                    403,
+                    format!(
+                        "Error: Not authorized (value of {} is not allowed)",
+                        rule.field
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn to_string(rule: AuthRule) -> String {
+        format!("{}={}", rule.field, rule.values.join("|"))
+    }
+
+    pub fn from_string(rule_str: &str) -> Result<AuthRule, (usize, String)> {
+        let current_rule = rule_str.split("=").collect::<Vec<&str>>();
+        if current_rule.len() != 2 {
+            return Err((500, String::from("Invalid auth rule (in format)")));
+        }
+
+        AuthRule::create(
+            current_rule[0].trim(),
+            current_rule[1]
+                .split("|")
+                .map(|value| value.to_string())
+                .collect::<Vec<String>>(),
+        )
+    }
+}
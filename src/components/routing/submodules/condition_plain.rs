@@ -1,5 +1,6 @@
 use super::{
-    sub_condition_type::ConditionType, sub_next_condition_type::NextConditionType,
+    sub_condition_type::ConditionType,
+    sub_next_condition_type::{NextConditionType, MAX_GROUP_DEPTH},
     sub_ref_data::RefData,
 };
 use rocket::serde::{Deserialize, Serialize};
@@ -10,6 +11,10 @@ pub struct ConditionPlain {
     pub condition_type: ConditionType,
     pub not: bool,
     pub next: NextConditionType,
+    #[serde(default)]
+    pub open: u32,
+    #[serde(default)]
+    pub close: u32,
 }
 
 impl ConditionPlain {
@@ -25,10 +30,33 @@ impl ConditionPlain {
             condition_type: ConditionType::from(condition_type),
             not: not,
             next: NextConditionType::from(next),
+            open: 0,
+            close: 0,
         };
         all_conditions.push(new_condition);
     }
 
+    pub fn set_group(
+        condition: &mut ConditionPlain,
+        open: u32,
+        close: u32,
+    ) -> Result<(), (usize, String)> {
+        if open > MAX_GROUP_DEPTH || close > MAX_GROUP_DEPTH {
+            return Err((
+                400,
+                format!(
+                    "Error: Condition groups cannot be nested deeper than {}",
+                    MAX_GROUP_DEPTH
+                ),
+            ));
+        }
+
+        condition.open = open;
+        condition.close = close;
+
+        Ok(())
+    }
+
     pub fn stringify(all_conditions: &Vec<ConditionPlain>) -> String {
         let mut stringified_conditions = String::new();
 
@@ -92,6 +120,34 @@ impl ConditionPlain {
             }
         };
 
+        let mut open = 0;
+        let mut close = 0;
+        for group_str in current_condition[4..].iter() {
+            let broken_group = group_str.split("=").collect::<Vec<&str>>();
+            if broken_group.len() <= 1 {
+                continue;
+            }
+
+            let count = match broken_group[1].trim().parse::<u32>() {
+                Ok(c) => c,
+                Err(e) => {
+                    return Err((
+                        500,
+                        format!(
+                            "Invalid condition (in '{}' format) -> {}",
+                            broken_group[0], e
+                        ),
+                    ))
+                }
+            };
+
+            if broken_group[0] == "open" {
+                open = count;
+            } else if broken_group[0] == "close" {
+                close = count;
+            }
+        }
+
         ConditionPlain::create(
             all_conditions,
             right,
@@ -99,13 +155,36 @@ impl ConditionPlain {
             not,
             next_str[1],
         );
+        if let Some(last_condition) = all_conditions.last_mut() {
+            if let Err(e) = ConditionPlain::set_group(last_condition, open, close) {
+                all_conditions.pop();
+                return Err((
+                    500,
+                    format!("Invalid condition (in group format) -> {}", e.1),
+                ));
+            }
+        }
 
         Ok(())
     }
 
     pub fn to_string(condition: ConditionPlain) -> String {
+        let groups = format!(
+            "{}{}",
+            if condition.open > 0 {
+                format!("|open={}", condition.open)
+            } else {
+                String::new()
+            },
+            if condition.close > 0 {
+                format!("|close={}", condition.close)
+            } else {
+                String::new()
+            }
+        );
+
         format!(
-            "({}|{}|not={}|next={})",
+            "({}|{}|not={}|next={}{})",
             RefData::to_string(condition.right.clone()),
             ConditionType::to(condition.condition_type.clone()),
             if condition.not == true {
@@ -113,7 +192,8 @@ impl ConditionPlain {
             } else {
                 "false"
             },
-            NextConditionType::to(condition.next.clone())
+            NextConditionType::to(condition.next.clone()),
+            groups
         )
     }
 }
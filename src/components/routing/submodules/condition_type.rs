@@ -13,6 +13,12 @@ pub enum ConditionType {
     GREATER_THAN_OR_EQUAL_TO,
     LESS_THAN_OR_EQUAL_TO,
     INCLUDES,
+    MATCHES_REGEX,
+    STARTS_WITH,
+    ENDS_WITH,
+    EQUAL_TO_IGNORE_CASE,
+    IS_EMPTY,
+    IS_NULL,
 }
 
 impl Default for ConditionType {
@@ -31,6 +37,12 @@ impl fmt::Display for ConditionType {
             ConditionType::GREATER_THAN_OR_EQUAL_TO => "GREATER_THAN_OR_EQUAL_TO".to_string(),
             ConditionType::LESS_THAN_OR_EQUAL_TO => "LESS_THAN_OR_EQUAL_TO".to_string(),
             ConditionType::INCLUDES => "INCLUDES".to_string(),
+            ConditionType::MATCHES_REGEX => "MATCHES_REGEX".to_string(),
+            ConditionType::STARTS_WITH => "STARTS_WITH".to_string(),
+            ConditionType::ENDS_WITH => "ENDS_WITH".to_string(),
+            ConditionType::EQUAL_TO_IGNORE_CASE => "EQUAL_TO_IGNORE_CASE".to_string(),
+            ConditionType::IS_EMPTY => "IS_EMPTY".to_string(),
+            ConditionType::IS_NULL => "IS_NULL".to_string(),
         };
 
         write!(f, "{}", ctype_txt)
@@ -47,6 +59,12 @@ impl ConditionType {
             ConditionType::GREATER_THAN_OR_EQUAL_TO => "GREATER_THAN_OR_EQUAL_TO".to_string(),
             ConditionType::LESS_THAN_OR_EQUAL_TO => "LESS_THAN_OR_EQUAL_TO".to_string(),
             ConditionType::INCLUDES => "INCLUDES".to_string(),
+            ConditionType::MATCHES_REGEX => "MATCHES_REGEX".to_string(),
+            ConditionType::STARTS_WITH => "STARTS_WITH".to_string(),
+            ConditionType::ENDS_WITH => "ENDS_WITH".to_string(),
+            ConditionType::EQUAL_TO_IGNORE_CASE => "EQUAL_TO_IGNORE_CASE".to_string(),
+            ConditionType::IS_EMPTY => "IS_EMPTY".to_string(),
+            ConditionType::IS_NULL => "IS_NULL".to_string(),
         };
     }
 
@@ -59,6 +77,12 @@ impl ConditionType {
             "GREATER_THAN_OR_EQUAL_TO" => ConditionType::GREATER_THAN_OR_EQUAL_TO,
             "LESS_THAN_OR_EQUAL_TO" => ConditionType::LESS_THAN_OR_EQUAL_TO,
             "INCLUDES" => ConditionType::INCLUDES,
+            "MATCHES_REGEX" => ConditionType::MATCHES_REGEX,
+            "STARTS_WITH" => ConditionType::STARTS_WITH,
+            "ENDS_WITH" => ConditionType::ENDS_WITH,
+            "EQUAL_TO_IGNORE_CASE" => ConditionType::EQUAL_TO_IGNORE_CASE,
+            "IS_EMPTY" => ConditionType::IS_EMPTY,
+            "IS_NULL" => ConditionType::IS_NULL,
             _ => ConditionType::EQUAL_TO,
         };
     }
@@ -5,10 +5,14 @@ use crate::{
     utils::{constraint::auto_fetch_all_constraints, mapping::auto_fetch_all_mappings},
 };
 
+use super::sub_object_pair::ObjectPair;
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct FailObj {
     pub status: u32,
     pub message: String,
+    #[serde(default)]
+    pub headers: Vec<ObjectPair>,
 }
 
 impl FailObj {
@@ -19,6 +23,7 @@ impl FailObj {
         let mut fail_obj = FailObj {
             status: status,
             message: "".to_string(),
+            headers: vec![],
         };
 
         let message_update = Self::update_message(&mut fail_obj, message);
@@ -52,15 +57,24 @@ impl FailObj {
         Ok(())
     }
 
+    pub fn set_headers(fail_obj: &mut FailObj, headers: Vec<ObjectPair>) {
+        fail_obj.headers = headers;
+    }
+
     pub fn to_string(fail_obj: FailObj) -> String {
         format!(
-            "[{},{}]",
+            "[{},{}]{}",
             fail_obj.status,
             fail_obj
                 .message
                 .split("\n")
                 .collect::<Vec<&str>>()
-                .join("_newline_")
+                .join("_newline_"),
+            if fail_obj.headers.len() > 0 {
+                format!("{{{}}}", ObjectPair::stringify(&fail_obj.headers))
+            } else {
+                String::new()
+            }
         )
     }
 
@@ -90,6 +104,31 @@ impl FailObj {
             }
         };
 
+        let mut all_headers: Vec<ObjectPair> = Vec::new();
+        let broken_headers = fail_obj_str.split("]{").collect::<Vec<&str>>();
+        if broken_headers.len() > 1 {
+            let headers_str = broken_headers[1..].join("]{");
+            let headers_list = match headers_str.rfind("}") {
+                Some(end) => headers_str[..end].split(">").collect::<Vec<&str>>(),
+                None => {
+                    return Err((
+                        500,
+                        String::from("Invalid fail_obj (at end of headers declaration)"),
+                    ))
+                }
+            };
+
+            for h_str in headers_list {
+                if h_str.len() < 1 {
+                    continue;
+                }
+
+                if let Err(e) = ObjectPair::from_string(&mut all_headers, h_str) {
+                    return Err((500, format!("Invalid fail_obj (in headers) -> {}", e.1)));
+                };
+            }
+        }
+
         match FailObj::create(
             status,
             &current_fail_obj[1]
@@ -97,7 +136,10 @@ impl FailObj {
                 .collect::<Vec<&str>>()
                 .join("\n"),
         ) {
-            Ok(fail_obj) => Ok(fail_obj),
+            Ok(mut fail_obj) => {
+                FailObj::set_headers(&mut fail_obj, all_headers);
+                Ok(fail_obj)
+            }
             Err(e) => {
                 return Err((
                     500,
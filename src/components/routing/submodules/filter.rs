@@ -1,5 +1,6 @@
 use super::{
-    sub_next_condition_type::NextConditionType, sub_operation_type::OperationType,
+    sub_next_condition_type::{NextConditionType, MAX_GROUP_DEPTH},
+    sub_operation_type::OperationType,
     sub_ref_data::RefData,
 };
 use rocket::serde::{Deserialize, Serialize};
@@ -10,6 +11,10 @@ pub struct Filter {
     pub operation_type: OperationType,
     pub not: bool,
     pub next: NextConditionType,
+    #[serde(default)]
+    pub open: u32,
+    #[serde(default)]
+    pub close: u32,
 }
 
 impl Filter {
@@ -25,10 +30,29 @@ impl Filter {
             operation_type: OperationType::from(operation_type),
             not: not,
             next: NextConditionType::from(next),
+            open: 0,
+            close: 0,
         };
         all_filters.push(new_filter);
     }
 
+    pub fn set_group(filter: &mut Filter, open: u32, close: u32) -> Result<(), (usize, String)> {
+        if open > MAX_GROUP_DEPTH || close > MAX_GROUP_DEPTH {
+            return Err((
+                400,
+                format!(
+                    "Error: Condition groups cannot be nested deeper than {}",
+                    MAX_GROUP_DEPTH
+                ),
+            ));
+        }
+
+        filter.open = open;
+        filter.close = close;
+
+        Ok(())
+    }
+
     pub fn stringify(all_filters: &Vec<Filter>) -> String {
         let mut stringified_filters = String::new();
 
@@ -89,18 +113,64 @@ impl Filter {
             }
         };
 
+        let mut open = 0;
+        let mut close = 0;
+        for group_str in current_filter[4..].iter() {
+            let broken_group = group_str.split("=").collect::<Vec<&str>>();
+            if broken_group.len() <= 1 {
+                continue;
+            }
+
+            let count = match broken_group[1].trim().parse::<u32>() {
+                Ok(c) => c,
+                Err(e) => {
+                    return Err((
+                        500,
+                        format!("Invalid filter (in '{}' format) -> {}", broken_group[0], e),
+                    ))
+                }
+            };
+
+            if broken_group[0] == "open" {
+                open = count;
+            } else if broken_group[0] == "close" {
+                close = count;
+            }
+        }
+
         Filter::create(all_filters, right, current_filter[1], not, next_str[1]);
+        if let Some(last_filter) = all_filters.last_mut() {
+            if let Err(e) = Filter::set_group(last_filter, open, close) {
+                all_filters.pop();
+                return Err((500, format!("Invalid filter (in group format) -> {}", e.1)));
+            }
+        }
 
         Ok(())
     }
 
     pub fn to_string(filter: Filter) -> String {
+        let groups = format!(
+            "{}{}",
+            if filter.open > 0 {
+                format!("|open={}", filter.open)
+            } else {
+                String::new()
+            },
+            if filter.close > 0 {
+                format!("|close={}", filter.close)
+            } else {
+                String::new()
+            }
+        );
+
         format!(
-            "({}|{}|not={}|next={})",
+            "({}|{}|not={}|next={}{})",
             RefData::to_string(filter.right.clone()),
             OperationType::to(filter.operation_type.clone()),
             if filter.not == true { "true" } else { "false" },
-            NextConditionType::to(filter.next.clone())
+            NextConditionType::to(filter.next.clone()),
+            groups
         )
     }
 }
@@ -9,6 +9,21 @@ pub enum FunctionList {
     V4,
     GENERATE_TIMESTAMP,
     PAGINATE,
+    UPPERCASE,
+    LOWERCASE,
+    TRIM,
+    SPLIT,
+    JOIN,
+    REPLACE,
+    SUBSTRING,
+    HASH_ARGON2,
+    VERIFY_ARGON2,
+    SHA256,
+    DATE_ADD,
+    DATE_DIFF,
+    RANDOM_INT,
+    JSON_PARSE,
+    JSON_STRINGIFY,
 }
 
 impl Default for FunctionList {
@@ -23,6 +38,21 @@ impl fmt::Display for FunctionList {
             FunctionList::V4 => "V4",
             FunctionList::GENERATE_TIMESTAMP => "GENERATE_TIMESTAMP",
             FunctionList::PAGINATE => "PAGINATE",
+            FunctionList::UPPERCASE => "UPPERCASE",
+            FunctionList::LOWERCASE => "LOWERCASE",
+            FunctionList::TRIM => "TRIM",
+            FunctionList::SPLIT => "SPLIT",
+            FunctionList::JOIN => "JOIN",
+            FunctionList::REPLACE => "REPLACE",
+            FunctionList::SUBSTRING => "SUBSTRING",
+            FunctionList::HASH_ARGON2 => "HASH_ARGON2",
+            FunctionList::VERIFY_ARGON2 => "VERIFY_ARGON2",
+            FunctionList::SHA256 => "SHA256",
+            FunctionList::DATE_ADD => "DATE_ADD",
+            FunctionList::DATE_DIFF => "DATE_DIFF",
+            FunctionList::RANDOM_INT => "RANDOM_INT",
+            FunctionList::JSON_PARSE => "JSON_PARSE",
+            FunctionList::JSON_STRINGIFY => "JSON_STRINGIFY",
         };
 
         write!(f, "{}", flist_txt)
@@ -35,6 +65,21 @@ impl FunctionList {
             FunctionList::V4 => "V4".to_string(),
             FunctionList::GENERATE_TIMESTAMP => "GENERATE_TIMESTAMP".to_string(),
             FunctionList::PAGINATE => "PAGINATE".to_string(),
+            FunctionList::UPPERCASE => "UPPERCASE".to_string(),
+            FunctionList::LOWERCASE => "LOWERCASE".to_string(),
+            FunctionList::TRIM => "TRIM".to_string(),
+            FunctionList::SPLIT => "SPLIT".to_string(),
+            FunctionList::JOIN => "JOIN".to_string(),
+            FunctionList::REPLACE => "REPLACE".to_string(),
+            FunctionList::SUBSTRING => "SUBSTRING".to_string(),
+            FunctionList::HASH_ARGON2 => "HASH_ARGON2".to_string(),
+            FunctionList::VERIFY_ARGON2 => "VERIFY_ARGON2".to_string(),
+            FunctionList::SHA256 => "SHA256".to_string(),
+            FunctionList::DATE_ADD => "DATE_ADD".to_string(),
+            FunctionList::DATE_DIFF => "DATE_DIFF".to_string(),
+            FunctionList::RANDOM_INT => "RANDOM_INT".to_string(),
+            FunctionList::JSON_PARSE => "JSON_PARSE".to_string(),
+            FunctionList::JSON_STRINGIFY => "JSON_STRINGIFY".to_string(),
         };
     }
 
@@ -43,7 +88,45 @@ impl FunctionList {
             "V4" => FunctionList::V4,
             "GENERATE_TIMESTAMP" => FunctionList::GENERATE_TIMESTAMP,
             "PAGINATE" => FunctionList::PAGINATE,
+            "UPPERCASE" => FunctionList::UPPERCASE,
+            "LOWERCASE" => FunctionList::LOWERCASE,
+            "TRIM" => FunctionList::TRIM,
+            "SPLIT" => FunctionList::SPLIT,
+            "JOIN" => FunctionList::JOIN,
+            "REPLACE" => FunctionList::REPLACE,
+            "SUBSTRING" => FunctionList::SUBSTRING,
+            "HASH_ARGON2" => FunctionList::HASH_ARGON2,
+            "VERIFY_ARGON2" => FunctionList::VERIFY_ARGON2,
+            "SHA256" => FunctionList::SHA256,
+            "DATE_ADD" => FunctionList::DATE_ADD,
+            "DATE_DIFF" => FunctionList::DATE_DIFF,
+            "RANDOM_INT" => FunctionList::RANDOM_INT,
+            "JSON_PARSE" => FunctionList::JSON_PARSE,
+            "JSON_STRINGIFY" => FunctionList::JSON_STRINGIFY,
             _ => FunctionList::V4,
         };
     }
+
+    pub fn params_range(flist: FunctionList) -> (usize, usize) {
+        return match flist {
+            FunctionList::V4 => (0, 0),
+            FunctionList::GENERATE_TIMESTAMP => (0, 0),
+            FunctionList::PAGINATE => (1, 3),
+            FunctionList::UPPERCASE => (1, 1),
+            FunctionList::LOWERCASE => (1, 1),
+            FunctionList::TRIM => (1, 1),
+            FunctionList::SPLIT => (2, 2),
+            FunctionList::JOIN => (1, 2),
+            FunctionList::REPLACE => (3, 3),
+            FunctionList::SUBSTRING => (2, 3),
+            FunctionList::HASH_ARGON2 => (1, 1),
+            FunctionList::VERIFY_ARGON2 => (2, 2),
+            FunctionList::SHA256 => (1, 1),
+            FunctionList::DATE_ADD => (2, 3),
+            FunctionList::DATE_DIFF => (2, 3),
+            FunctionList::RANDOM_INT => (2, 2),
+            FunctionList::JSON_PARSE => (1, 1),
+            FunctionList::JSON_STRINGIFY => (1, 1),
+        };
+    }
 }
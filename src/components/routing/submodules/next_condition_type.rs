@@ -2,6 +2,8 @@ use std::fmt;
 
 use rocket::serde::{Deserialize, Serialize};
 
+pub const MAX_GROUP_DEPTH: u32 = 8;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum NextConditionType {
     AND,
@@ -18,6 +18,12 @@ pub enum OperationType {
     DIVISION,
     MODULO,
     INCLUDES,
+    MATCHES_REGEX,
+    STARTS_WITH,
+    ENDS_WITH,
+    EQUAL_TO_IGNORE_CASE,
+    IS_EMPTY,
+    IS_NULL,
     NONE,
 }
 
@@ -42,6 +48,12 @@ impl fmt::Display for OperationType {
             OperationType::DIVISION => "DIVISION".to_string(),
             OperationType::MODULO => "MODULO".to_string(),
             OperationType::INCLUDES => "INCLUDES".to_string(),
+            OperationType::MATCHES_REGEX => "MATCHES_REGEX".to_string(),
+            OperationType::STARTS_WITH => "STARTS_WITH".to_string(),
+            OperationType::ENDS_WITH => "ENDS_WITH".to_string(),
+            OperationType::EQUAL_TO_IGNORE_CASE => "EQUAL_TO_IGNORE_CASE".to_string(),
+            OperationType::IS_EMPTY => "IS_EMPTY".to_string(),
+            OperationType::IS_NULL => "IS_NULL".to_string(),
             OperationType::NONE => "NONE".to_string(),
         };
 
@@ -64,6 +76,12 @@ impl OperationType {
             OperationType::DIVISION => "DIVISION".to_string(),
             OperationType::MODULO => "MODULO".to_string(),
             OperationType::INCLUDES => "INCLUDES".to_string(),
+            OperationType::MATCHES_REGEX => "MATCHES_REGEX".to_string(),
+            OperationType::STARTS_WITH => "STARTS_WITH".to_string(),
+            OperationType::ENDS_WITH => "ENDS_WITH".to_string(),
+            OperationType::EQUAL_TO_IGNORE_CASE => "EQUAL_TO_IGNORE_CASE".to_string(),
+            OperationType::IS_EMPTY => "IS_EMPTY".to_string(),
+            OperationType::IS_NULL => "IS_NULL".to_string(),
             OperationType::NONE => "NONE".to_string(),
         };
     }
@@ -82,6 +100,12 @@ impl OperationType {
             "DIVISION" => OperationType::DIVISION,
             "MODULO" => OperationType::MODULO,
             "INCLUDES" => OperationType::INCLUDES,
+            "MATCHES_REGEX" => OperationType::MATCHES_REGEX,
+            "STARTS_WITH" => OperationType::STARTS_WITH,
+            "ENDS_WITH" => OperationType::ENDS_WITH,
+            "EQUAL_TO_IGNORE_CASE" => OperationType::EQUAL_TO_IGNORE_CASE,
+            "IS_EMPTY" => OperationType::IS_EMPTY,
+            "IS_NULL" => OperationType::IS_NULL,
             "NONE" => OperationType::NONE,
             _ => OperationType::EQUAL_TO,
         };
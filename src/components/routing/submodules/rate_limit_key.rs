@@ -0,0 +1,49 @@
+#![allow(non_camel_case_types)]
+
+use std::fmt;
+
+use rocket::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum RateLimitKey {
+    IP,
+    AUTH,
+    GLOBAL,
+}
+
+impl Default for RateLimitKey {
+    fn default() -> Self {
+        RateLimitKey::IP
+    }
+}
+
+impl fmt::Display for RateLimitKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key_txt = match self {
+            RateLimitKey::IP => "IP".to_string(),
+            RateLimitKey::AUTH => "AUTH".to_string(),
+            RateLimitKey::GLOBAL => "GLOBAL".to_string(),
+        };
+
+        write!(f, "{}", key_txt)
+    }
+}
+
+impl RateLimitKey {
+    pub fn to(key: RateLimitKey) -> String {
+        return match key.clone() {
+            RateLimitKey::IP => "IP".to_string(),
+            RateLimitKey::AUTH => "AUTH".to_string(),
+            RateLimitKey::GLOBAL => "GLOBAL".to_string(),
+        };
+    }
+
+    pub fn from(key_txt: &str) -> RateLimitKey {
+        return match key_txt.trim().to_uppercase().as_str() {
+            "IP" => RateLimitKey::IP,
+            "AUTH" => RateLimitKey::AUTH,
+            "GLOBAL" => RateLimitKey::GLOBAL,
+            _ => RateLimitKey::IP,
+        };
+    }
+}
@@ -75,7 +75,7 @@ impl RefData {
                 ""
             },
             BodyDataType::to(ref_data_obj.rtype),
-            ref_data_obj.data
+            escape_data(&ref_data_obj.data)
         )
     }
 
@@ -98,9 +98,57 @@ impl RefData {
         let ref_data = RefData {
             ref_var: current_data_obj_str[0] == "ref",
             rtype: BodyDataType::from(current_data_obj_str[1]),
-            data: current_data_obj_str[2].to_string(),
+            data: unescape_data(current_data_obj_str[2]),
         };
 
         Ok(ref_data)
     }
 }
+
+const ESCAPED_CHARS: [(char, &str); 8] = [
+    ('~', "~7E"),
+    ('[', "~5B"),
+    (']', "~5D"),
+    ('(', "~28"),
+    (')', "~29"),
+    ('|', "~7C"),
+    ('{', "~7B"),
+    ('}', "~7D"),
+];
+
+fn escape_data(data: &str) -> String {
+    let mut escaped = String::new();
+
+    for c in data.chars() {
+        match ESCAPED_CHARS.iter().find(|(e, _)| *e == c) {
+            Some((_, code)) => escaped.push_str(code),
+            None => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn unescape_data(data: &str) -> String {
+    let mut unescaped = String::new();
+    let mut remaining = data;
+
+    while remaining.len() > 0 {
+        match ESCAPED_CHARS
+            .iter()
+            .find(|(_, code)| remaining.starts_with(code))
+        {
+            Some((c, code)) => {
+                unescaped.push(*c);
+                remaining = &remaining[code.len()..];
+            }
+            None => {
+                let c = remaining.chars().next().unwrap();
+                unescaped.push(c);
+                remaining = &remaining[c.len_utf8()..];
+            }
+        }
+    }
+
+    unescaped
+}
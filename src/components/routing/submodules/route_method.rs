@@ -0,0 +1,58 @@
+use std::fmt;
+
+use rocket::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum RouteMethod {
+    GET,
+    POST,
+    PUT,
+    PATCH,
+    DELETE,
+}
+
+impl Default for RouteMethod {
+    fn default() -> Self {
+        RouteMethod::POST
+    }
+}
+
+impl fmt::Display for RouteMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let method_txt = match self {
+            RouteMethod::GET => "GET",
+            RouteMethod::POST => "POST",
+            RouteMethod::PUT => "PUT",
+            RouteMethod::PATCH => "PATCH",
+            RouteMethod::DELETE => "DELETE",
+        };
+
+        write!(f, "{}", method_txt)
+    }
+}
+
+impl RouteMethod {
+    pub fn to(method: RouteMethod) -> String {
+        return match method.clone() {
+            RouteMethod::GET => "GET".to_string(),
+            RouteMethod::POST => "POST".to_string(),
+            RouteMethod::PUT => "PUT".to_string(),
+            RouteMethod::PATCH => "PATCH".to_string(),
+            RouteMethod::DELETE => "DELETE".to_string(),
+        };
+    }
+
+    pub fn from(method_txt: &str) -> Result<RouteMethod, (usize, String)> {
+        return match method_txt.trim().to_uppercase().as_str() {
+            "GET" => Ok(RouteMethod::GET),
+            "POST" => Ok(RouteMethod::POST),
+            "PUT" => Ok(RouteMethod::PUT),
+            "PATCH" => Ok(RouteMethod::PATCH),
+            "DELETE" => Ok(RouteMethod::DELETE),
+            _ => Err((
+                400,
+                format!("Error: Invalid route method '{}'", method_txt.trim()),
+            )),
+        };
+    }
+}
@@ -0,0 +1,45 @@
+#![allow(non_camel_case_types)]
+
+use std::fmt;
+
+use rocket::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum SortDirection {
+    ASC,
+    DESC,
+}
+
+impl Default for SortDirection {
+    fn default() -> Self {
+        SortDirection::ASC
+    }
+}
+
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction_txt = match self {
+            SortDirection::ASC => "ASC".to_string(),
+            SortDirection::DESC => "DESC".to_string(),
+        };
+
+        write!(f, "{}", direction_txt)
+    }
+}
+
+impl SortDirection {
+    pub fn to(direction: SortDirection) -> String {
+        return match direction.clone() {
+            SortDirection::ASC => "ASC".to_string(),
+            SortDirection::DESC => "DESC".to_string(),
+        };
+    }
+
+    pub fn from(direction_txt: &str) -> SortDirection {
+        return match direction_txt.trim().to_uppercase().as_str() {
+            "ASC" => SortDirection::ASC,
+            "DESC" => SortDirection::DESC,
+            _ => SortDirection::ASC,
+        };
+    }
+}
@@ -0,0 +1,94 @@
+use super::sub_sort_direction::SortDirection;
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::{
+    components::constraint_property::ConstraintProperty,
+    utils::{constraint::auto_fetch_all_constraints, mapping::auto_fetch_all_mappings},
+};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SortKey {
+    pub property: String,
+    pub direction: SortDirection,
+}
+
+impl SortKey {
+    pub fn create(
+        all_keys: &mut Vec<SortKey>,
+        property: &str,
+        direction: &str,
+    ) -> Result<(), (usize, String)> {
+        let mappings = auto_fetch_all_mappings();
+        let all_constraints = match auto_fetch_all_constraints(&mappings) {
+            Ok(c) => c,
+            Err(e) => return Err((500, e)),
+        };
+        let final_value = match ConstraintProperty::validate(
+            &all_constraints,
+            "sort_block",
+            "ref_property",
+            property,
+        ) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+
+        let new_key = SortKey {
+            property: final_value,
+            direction: SortDirection::from(direction),
+        };
+        all_keys.push(new_key);
+
+        Ok(())
+    }
+
+    pub fn stringify(all_keys: &Vec<SortKey>) -> String {
+        let mut stringified_keys = String::new();
+
+        for key in all_keys {
+            stringified_keys = format!(
+                "{}{}{}",
+                stringified_keys,
+                if stringified_keys.chars().count() > 1 {
+                    ">"
+                } else {
+                    ""
+                },
+                SortKey::to_string(key.clone()),
+            );
+        }
+
+        stringified_keys
+    }
+
+    pub fn from_string(all_keys: &mut Vec<SortKey>, key_str: &str) -> Result<(), (usize, String)> {
+        let mut current_key = key_str.split("(").collect::<Vec<&str>>();
+        if current_key.len() <= 1 {
+            return Err((500, String::from("Invalid sort key (at declaration start)")));
+        }
+
+        current_key = current_key[1].split(")").collect::<Vec<&str>>();
+        if current_key.len() <= 1 {
+            return Err((500, String::from("Invalid sort key (at declaration end)")));
+        }
+
+        current_key = current_key[0].split("|").collect::<Vec<&str>>();
+        if current_key.len() < 2 {
+            return Err((500, String::from("Invalid sort key (in format)")));
+        }
+
+        if let Err(e) = SortKey::create(all_keys, current_key[0], current_key[1]) {
+            return Err((500, format!("Invalid sort key (in 'property') -> {}", e.1)));
+        }
+
+        Ok(())
+    }
+
+    pub fn to_string(key: SortKey) -> String {
+        format!(
+            "({}|{})",
+            key.property,
+            SortDirection::to(key.direction.clone())
+        )
+    }
+}
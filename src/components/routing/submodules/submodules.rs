@@ -48,3 +48,18 @@ pub mod sub_object_pair;
 
 #[path = "condition_plain.rs"]
 pub mod sub_condition_plain;
+
+#[path = "route_method.rs"]
+pub mod sub_route_method;
+
+#[path = "sort_direction.rs"]
+pub mod sub_sort_direction;
+
+#[path = "sort_key.rs"]
+pub mod sub_sort_key;
+
+#[path = "rate_limit_key.rs"]
+pub mod sub_rate_limit_key;
+
+#[path = "auth_rule.rs"]
+pub mod sub_auth_rule;
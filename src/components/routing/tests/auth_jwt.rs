@@ -1,6 +1,10 @@
 #[cfg(test)]
 #[allow(unused_imports)]
+use crate::components::datapair::DataPair;
+#[allow(unused_imports)]
 use crate::components::routing::core::core_auth_jwt::AuthJWT;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::sub_auth_rule::AuthRule;
 
 fn make_core_one() -> crate::components::routing::core::core_auth_jwt::AuthJWT {
     crate::components::routing::core::core_auth_jwt::AuthJWT::create(true, "uid", "users").unwrap()
@@ -35,3 +39,82 @@ pub fn run_routing_core_auth_jwt_two() {
 
     assert_eq!(auth_jwt_two, auth_jwt_one);
 }
+
+fn make_core_two() -> crate::components::routing::core::core_auth_jwt::AuthJWT {
+    let mut auth_jwt =
+        crate::components::routing::core::core_auth_jwt::AuthJWT::create(true, "uid", "users")
+            .unwrap();
+
+    AuthJWT::set_claims(&mut auth_jwt, vec!["uid".to_string(), "role".to_string()]).unwrap();
+
+    let rule_one =
+        AuthRule::create("role", vec!["admin".to_string(), "editor".to_string()]).unwrap();
+    let rule_two = AuthRule::create("plan", vec!["pro".to_string()]).unwrap();
+    AuthJWT::set_rules(&mut auth_jwt, vec![rule_one, rule_two]);
+
+    auth_jwt
+}
+
+fn get_core_str_two() -> String {
+    "DEFINE auth_jwt [true,uid,users] claims=(uid,role) rules=(role=admin|editor)>(plan=pro)"
+        .to_string()
+}
+
+#[test]
+pub fn run_routing_core_auth_jwt_three() {
+    println!("---> Running Routing Core AUTH_JWT Three");
+    // DEFINE auth_jwt [true,uid,users] claims=(uid,role) rules=(role=admin|editor)>(plan=pro)
+
+    let auth_jwt = make_core_two();
+    assert_eq!(get_core_str_two(), AuthJWT::to_string(auth_jwt.clone()));
+
+    let auth_jwt_two = AuthJWT::from_string(&get_core_str_two()).unwrap();
+    assert_eq!(auth_jwt, auth_jwt_two);
+
+    let mut auth_jwt_three = auth_jwt.clone();
+    assert!(AuthJWT::set_claims(
+        &mut auth_jwt_three,
+        vec!["uid".to_string(), "uid".to_string()]
+    )
+    .is_err());
+    assert!(AuthRule::create("role", vec![]).is_err());
+    assert!(AuthRule::create("role", vec!["admin|root".to_string()]).is_err());
+}
+
+#[test]
+pub fn run_routing_core_auth_jwt_four() {
+    println!("---> Running Routing Core AUTH_JWT Four");
+
+    let auth_jwt = make_core_two();
+
+    let make_pair = |structure_id: &str, value: &str| DataPair {
+        id: structure_id.to_string(),
+        structure_id: structure_id.to_string(),
+        custom_structure_id: String::new(),
+        value: value.to_string(),
+        dtype: String::from("text"),
+    };
+
+    let editor = vec![
+        make_pair("uid", "abc"),
+        make_pair("role", "editor"),
+        make_pair("plan", "pro"),
+    ];
+    assert_eq!(AuthRule::check(&auth_jwt.rules, &editor), Ok(()));
+
+    let viewer = vec![
+        make_pair("uid", "abc"),
+        make_pair("role", "viewer"),
+        make_pair("plan", "pro"),
+    ];
+    assert_eq!(
+        AuthRule::check(&auth_jwt.rules, &viewer),
+        Err((
+            403,
+            String::from("Error: Not authorized (value of role is not allowed)")
+        ))
+    );
+
+    let missing_plan = vec![make_pair("uid", "abc"), make_pair("role", "admin")];
+    assert!(AuthRule::check(&auth_jwt.rules, &missing_plan).is_err());
+}
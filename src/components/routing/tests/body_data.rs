@@ -1,6 +1,10 @@
 #[cfg(test)]
 #[allow(unused_imports)]
 use crate::components::routing::core::core_body_data::BodyData;
+#[allow(unused_imports)]
+use crate::routes::x::x::validate_body_schema;
+#[allow(unused_imports)]
+use rocket::serde::json::json;
 
 fn make_core_one(all_pairs: &mut Vec<crate::components::routing::core::core_body_data::BodyData>) {
     crate::components::routing::core::core_body_data::BodyData::create(all_pairs, "uid", "STRING")
@@ -65,3 +69,93 @@ pub fn run_routing_core_body_data_two() {
 
     assert_eq!(all_pairs_duplicate, all_pairs);
 }
+
+#[test]
+pub fn run_routing_core_body_data_three() {
+    println!("---> Running Routing Core Body Data Three");
+    // ADD PATH pair [post_id,INTEGER]
+
+    let mut all_pairs = Vec::<BodyData>::new();
+    BodyData::from_string_with_prefix(
+        &mut all_pairs,
+        "ADD PATH pair [post_id,INTEGER]",
+        "ADD PATH pair",
+    )
+    .unwrap();
+
+    let mut all_pairs_duplicate = Vec::<BodyData>::new();
+    BodyData::create(&mut all_pairs_duplicate, "post_id", "INTEGER").unwrap();
+
+    assert_eq!(all_pairs_duplicate, all_pairs);
+    assert_eq!(
+        "ADD PATH pair [post_id,INTEGER]",
+        BodyData::stringify_with_prefix(&all_pairs, "ADD PATH pair")
+    );
+}
+
+fn get_core_str_two() -> String {
+    "ADD BODY pair [title,STRING,min=3,max=80]\nADD BODY pair [items,ARRAY<OTHER>,min=1]\nADD BODY pair [items/sku,STRING]\nADD BODY pair [items/qty,INTEGER]\nADD BODY pair [items/note,STRING,optional]"
+        .to_string()
+}
+
+#[test]
+pub fn run_routing_core_body_data_four() {
+    println!("---> Running Routing Core Body Data Four");
+
+    let mut all_pairs = Vec::<BodyData>::new();
+    for line in get_core_str_two().split("\n") {
+        BodyData::from_string(&mut all_pairs, line, false).unwrap();
+    }
+
+    assert_eq!(all_pairs.len(), 2);
+    assert_eq!(all_pairs[1].fields.len(), 3);
+    assert_eq!(all_pairs[1].fields[2].required, false);
+    assert_eq!(get_core_str_two(), BodyData::stringify(&all_pairs, false));
+}
+
+#[test]
+pub fn run_routing_core_body_data_five() {
+    println!("---> Running Routing Core Body Data Five");
+
+    let mut all_pairs = Vec::<BodyData>::new();
+    for line in get_core_str_two().split("\n") {
+        BodyData::from_string(&mut all_pairs, line, false).unwrap();
+    }
+
+    let valid_body = json!({
+        "title": "Order",
+        "items": [{ "sku": "a", "qty": 1 }, { "sku": "b", "qty": 2, "note": "gift" }]
+    });
+    for bdata in all_pairs.iter() {
+        let pointer = format!("/{}", bdata.id);
+        assert!(
+            validate_body_schema(bdata, valid_body[bdata.id.clone()].clone(), &pointer).is_ok()
+        );
+    }
+
+    let invalid_items = json!([{ "sku": "a", "qty": 1 }, { "sku": "b", "qty": "x" }]);
+    assert_eq!(
+        validate_body_schema(&all_pairs[1], invalid_items, "/items"),
+        Err((
+            400,
+            String::from("Error: /items/1/qty should be an integer")
+        ))
+    );
+
+    let missing_sku = json!([{ "qty": 1 }]);
+    assert_eq!(
+        validate_body_schema(&all_pairs[1], missing_sku, "/items"),
+        Err((
+            400,
+            String::from("Error: /items/0/sku should not be undefined")
+        ))
+    );
+
+    assert_eq!(
+        validate_body_schema(&all_pairs[0], json!("ab"), "/title"),
+        Err((
+            400,
+            String::from("Error: /title should have a length of at least 3")
+        ))
+    );
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+#[allow(unused_imports)]
+use crate::components::routing::blocks::call_block::CallBlock;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::sub_object_pair::ObjectPair;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::sub_ref_data::RefData;
+#[allow(unused_imports)]
+use crate::utils::x::definition_store::DefinitionStore;
+#[allow(unused_imports)]
+use crate::utils::x::definitions::define_call::{define_call, MAX_CALL_DEPTH};
+#[allow(unused_imports)]
+use crate::utils::x::transaction::Transaction;
+
+fn make_block_one(all_blocks: &mut Vec<crate::components::routing::blocks::call_block::CallBlock>) {
+    if let Err(e) = crate::components::routing::blocks::call_block::CallBlock::create(
+        all_blocks,
+        2,
+        1,
+        "currentUser",
+        "load_user",
+    ) {
+        println!("Error: {:#?}", e);
+        return;
+    }
+
+    let mut all_pairs = Vec::<ObjectPair>::new();
+
+    let mut ref_data = RefData::create(true, "STRING", "uid").unwrap();
+    ObjectPair::create(&mut all_pairs, "uid", ref_data).unwrap();
+
+    ref_data = RefData::create(false, "BOOLEAN", "true").unwrap();
+    ObjectPair::create(&mut all_pairs, "check_membership", ref_data).unwrap();
+
+    crate::components::routing::blocks::call_block::CallBlock::set_pairs(all_blocks, 2, all_pairs)
+        .unwrap();
+}
+
+fn get_block_str_one() -> String {
+    "CALL (2,1) [currentUser,load_user] (uid=[ref,STRING,uid])>(check_membership=[,BOOLEAN,true])"
+        .to_string()
+}
+
+#[test]
+pub fn run_routing_call_one() {
+    println!("---> Running Routing Call One");
+    // CALL (2,1) [currentUser,load_user] (uid=[ref,STRING,uid])>(check_membership=[,BOOLEAN,true])
+
+    let mut all_blocks = Vec::<CallBlock>::new();
+    make_block_one(&mut all_blocks);
+
+    assert_eq!(
+        get_block_str_one(),
+        CallBlock::to_string(all_blocks[0].clone())
+    );
+}
+
+#[test]
+pub fn run_routing_call_two() {
+    println!("---> Running Routing Call Two");
+
+    // CallBlock {
+    //     global_index: 2,
+    //     block_index: 1,
+    //     local_name: "currentUser",
+    //     route_id: "load_user",
+    //     pairs: [
+    //         ObjectPair {
+    //             id: "uid",
+    //             data: RefData {
+    //                 ref_var: true,
+    //                 rtype: STRING,
+    //                 data: "uid"
+    //             }
+    //         },
+    //         ObjectPair {
+    //             id: "check_membership",
+    //             data: RefData {
+    //                 ref_var: false,
+    //                 rtype: BOOLEAN,
+    //                 data: "true"
+    //             }
+    //         }
+    //     ]
+    // }
+
+    let mut all_blocks = Vec::<CallBlock>::new();
+    CallBlock::from_string(&mut all_blocks, &get_block_str_one()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<CallBlock>::new();
+    make_block_one(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+}
+
+#[test]
+pub fn run_routing_call_three() {
+    println!("---> Running Routing Call Three");
+
+    let mut all_blocks = Vec::<CallBlock>::new();
+    make_block_one(&mut all_blocks);
+
+    let mut all_definitions = Vec::<DefinitionStore>::new();
+    let mut transaction = Transaction::default();
+
+    let result = define_call(
+        all_blocks[0].clone(),
+        &vec![],
+        &mut all_definitions,
+        0,
+        "test",
+        "dashboard",
+        &vec![],
+        &mut transaction,
+        MAX_CALL_DEPTH,
+    );
+
+    assert_eq!(
+        result,
+        Err((
+            500,
+            format!(
+                "Error: Maximum call depth of {} exceeded while calling 'load_user'",
+                MAX_CALL_DEPTH
+            )
+        ))
+    );
+}
@@ -2,11 +2,19 @@
 #[allow(unused_imports)]
 use crate::components::routing::blocks::condition_block::ConditionBlock;
 #[allow(unused_imports)]
+use crate::components::routing::submodules::sub_body_data_type::BodyDataType;
+#[allow(unused_imports)]
 use crate::components::routing::submodules::sub_condition::Condition;
 #[allow(unused_imports)]
 use crate::components::routing::submodules::sub_fail_obj::FailObj;
 #[allow(unused_imports)]
+use crate::components::routing::submodules::sub_next_condition_type::NextConditionType;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::sub_object_pair::ObjectPair;
+#[allow(unused_imports)]
 use crate::components::routing::submodules::sub_ref_data::RefData;
+#[allow(unused_imports)]
+use crate::utils::x::resolver::{evaluate_terms, resolve_conditions, ConditionTerm};
 
 fn make_block_one(
     all_blocks: &mut Vec<crate::components::routing::blocks::condition_block::ConditionBlock>,
@@ -120,3 +128,255 @@ pub fn run_routing_condition_two() {
 
     assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
 }
+
+fn make_block_two(
+    all_blocks: &mut Vec<crate::components::routing::blocks::condition_block::ConditionBlock>,
+) {
+    let mut fail_obj = FailObj::create(429, "Error: Too many requests").unwrap();
+
+    let mut all_headers = Vec::<ObjectPair>::new();
+    let ref_data = RefData::create(false, "STRING", "60").unwrap();
+    ObjectPair::create(&mut all_headers, "Retry-After", ref_data).unwrap();
+    FailObj::set_headers(&mut fail_obj, all_headers);
+
+    crate::components::routing::blocks::condition_block::ConditionBlock::create(
+        all_blocks,
+        3,
+        1,
+        "FAIL",
+        Some(fail_obj),
+    )
+    .unwrap();
+
+    let mut all_conditions = Vec::<Condition>::new();
+
+    let left = RefData::create(true, "INTEGER", "attempts").unwrap();
+    let right = RefData::create(false, "INTEGER", "5").unwrap();
+    Condition::create(
+        &mut all_conditions,
+        left,
+        right,
+        "GREATER_THAN",
+        false,
+        "NONE",
+    );
+
+    crate::components::routing::blocks::condition_block::ConditionBlock::set_conditions(
+        all_blocks,
+        3,
+        all_conditions,
+    )
+    .unwrap();
+}
+
+fn get_block_str_two() -> String {
+    "CONDITION (3,1) [FAIL] [429,Error: Too many requests]{(Retry-After=[,STRING,60])} ([ref,INTEGER,attempts]|GREATER_THAN|[,INTEGER,5]|not=false|next=NONE)".to_string()
+}
+
+#[test]
+pub fn run_routing_condition_three() {
+    println!("---> Running Routing Condition Three");
+    // CONDITION (3,1) [FAIL] [429,Error: Too many requests]{(Retry-After=[,STRING,60])} ([ref,INTEGER,attempts]|GREATER_THAN|[,INTEGER,5]|not=false|next=NONE)
+
+    let mut all_blocks = Vec::<ConditionBlock>::new();
+    make_block_two(&mut all_blocks);
+
+    assert_eq!(
+        get_block_str_two(),
+        ConditionBlock::to_string(all_blocks[0].clone())
+    );
+}
+
+#[test]
+pub fn run_routing_condition_four() {
+    println!("---> Running Routing Condition Four");
+
+    let mut all_blocks = Vec::<ConditionBlock>::new();
+    ConditionBlock::from_string(&mut all_blocks, &get_block_str_two()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<ConditionBlock>::new();
+    make_block_two(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+}
+
+fn make_terms(values: Vec<bool>, groups: Vec<(u32, u32)>, nexts: Vec<&str>) -> Vec<ConditionTerm> {
+    let mut all_terms = Vec::<ConditionTerm>::new();
+
+    for n in 0..values.len() {
+        all_terms.push(ConditionTerm {
+            open: groups[n].0,
+            value: values[n],
+            close: groups[n].1,
+            next: NextConditionType::from(nexts[n]),
+        });
+    }
+
+    all_terms
+}
+
+#[test]
+pub fn run_routing_condition_five() {
+    println!("---> Running Routing Condition Five");
+    // (true OR false) AND false -> false, true OR (false AND false) -> true
+    // Terms are evaluated left to right, groups only decide what is evaluated first
+
+    let grouped = make_terms(
+        vec![true, false, false],
+        vec![(1, 0), (0, 1), (0, 0)],
+        vec!["OR", "AND", "NONE"],
+    );
+    assert_eq!(evaluate_terms(&grouped), Ok(false));
+
+    let nested = make_terms(
+        vec![true, false, false],
+        vec![(0, 0), (1, 0), (0, 1)],
+        vec!["OR", "AND", "NONE"],
+    );
+    assert_eq!(evaluate_terms(&nested), Ok(true));
+
+    let ungrouped = make_terms(
+        vec![true, false, false],
+        vec![(0, 0), (0, 0), (0, 0)],
+        vec!["OR", "AND", "NONE"],
+    );
+    assert_eq!(evaluate_terms(&ungrouped), Ok(false));
+
+    let unrelated_group = make_terms(
+        vec![true, false, false, true],
+        vec![(0, 0), (0, 0), (0, 0), (1, 1)],
+        vec!["OR", "AND", "AND", "NONE"],
+    );
+    assert_eq!(evaluate_terms(&unrelated_group), Ok(false));
+
+    let replaced = make_terms(
+        vec![true, false],
+        vec![(0, 0), (0, 0)],
+        vec!["NONE", "NONE"],
+    );
+    let replaced_grouped = make_terms(
+        vec![true, false],
+        vec![(0, 0), (1, 1)],
+        vec!["NONE", "NONE"],
+    );
+    assert_eq!(evaluate_terms(&replaced), Ok(false));
+    assert_eq!(evaluate_terms(&replaced_grouped), Ok(false));
+
+    let unbalanced = make_terms(vec![true, false], vec![(2, 0), (0, 1)], vec!["AND", "NONE"]);
+    assert!(evaluate_terms(&unbalanced).is_err());
+
+    let too_deep = make_terms(vec![true, false], vec![(5, 0), (4, 9)], vec!["AND", "NONE"]);
+    assert!(evaluate_terms(&too_deep).is_err());
+}
+
+#[test]
+pub fn run_routing_condition_six() {
+    println!("---> Running Routing Condition Six");
+
+    let mut all_conditions = Vec::<Condition>::new();
+
+    let left = RefData::create(true, "STRING", "role").unwrap();
+    let right = RefData::create(false, "STRING", "admin").unwrap();
+    Condition::create(&mut all_conditions, left, right, "EQUAL_TO", false, "OR");
+    Condition::set_group(&mut all_conditions[0], 1, 0).unwrap();
+
+    let left = RefData::create(true, "STRING", "role").unwrap();
+    let right = RefData::create(false, "STRING", "editor").unwrap();
+    Condition::create(&mut all_conditions, left, right, "EQUAL_TO", false, "AND");
+    Condition::set_group(&mut all_conditions[1], 0, 1).unwrap();
+
+    let stringified = Condition::stringify(&all_conditions);
+    assert_eq!(
+        stringified,
+        "([ref,STRING,role]|EQUAL_TO|[,STRING,admin]|not=false|next=OR|open=1)>([ref,STRING,role]|EQUAL_TO|[,STRING,editor]|not=false|next=AND|close=1)"
+    );
+
+    let mut parsed_conditions = Vec::<Condition>::new();
+    for c_str in stringified.split(">") {
+        Condition::from_string(&mut parsed_conditions, c_str).unwrap();
+    }
+
+    assert_eq!(parsed_conditions, all_conditions);
+
+    assert!(Condition::set_group(&mut all_conditions[0], 9, 0).is_err());
+    assert_eq!(all_conditions[0].open, 1);
+
+    let mut invalid_conditions = Vec::<Condition>::new();
+    assert!(Condition::from_string(
+        &mut invalid_conditions,
+        "([ref,STRING,role]|EQUAL_TO|[,STRING,admin]|not=false|next=NONE|open=4000000000)"
+    )
+    .is_err());
+    assert_eq!(invalid_conditions.len(), 0);
+}
+
+fn check_condition(
+    left: RefData,
+    condition_type: &str,
+    right: RefData,
+) -> Result<bool, (usize, String)> {
+    let mut all_conditions = Vec::<Condition>::new();
+    Condition::create(
+        &mut all_conditions,
+        left,
+        right,
+        condition_type,
+        false,
+        "NONE",
+    );
+
+    resolve_conditions(&all_conditions, &vec![], &vec![], 0)
+}
+
+#[test]
+pub fn run_routing_condition_seven() {
+    println!("---> Running Routing Condition Seven");
+
+    let text = RefData::create(false, "STRING", "Draft post").unwrap();
+    let empty = RefData::create(false, "STRING", "").unwrap();
+
+    let prefix = RefData::create(false, "STRING", "Draft").unwrap();
+    assert_eq!(
+        check_condition(text.clone(), "STARTS_WITH", prefix.clone()),
+        Ok(true)
+    );
+    assert_eq!(
+        check_condition(text.clone(), "ENDS_WITH", prefix),
+        Ok(false)
+    );
+
+    let suffix = RefData::create(false, "STRING", "post").unwrap();
+    assert_eq!(check_condition(text.clone(), "ENDS_WITH", suffix), Ok(true));
+
+    let upper = RefData::create(false, "STRING", "DRAFT POST").unwrap();
+    assert_eq!(
+        check_condition(text.clone(), "EQUAL_TO_IGNORE_CASE", upper),
+        Ok(true)
+    );
+
+    let pattern = RefData::create(false, "STRING", "Dr.ft p.st").unwrap();
+    assert_eq!(
+        check_condition(text.clone(), "MATCHES_REGEX", pattern),
+        Ok(true)
+    );
+
+    assert_eq!(
+        check_condition(empty.clone(), "IS_EMPTY", empty.clone()),
+        Ok(true)
+    );
+    assert_eq!(
+        check_condition(text.clone(), "IS_EMPTY", empty.clone()),
+        Ok(false)
+    );
+    assert_eq!(
+        check_condition(text.clone(), "IS_NULL", empty.clone()),
+        Ok(false)
+    );
+
+    let invalid_pattern = RefData {
+        ref_var: false,
+        rtype: BodyDataType::STRING,
+        data: String::from("*abc"),
+    };
+    assert!(check_condition(text, "MATCHES_REGEX", invalid_pattern).is_err());
+}
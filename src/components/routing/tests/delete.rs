@@ -0,0 +1,214 @@
+#[cfg(test)]
+#[allow(unused_imports)]
+use crate::components::routing::blocks::delete_block::DeleteBlock;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::sub_condition::Condition;
+use crate::components::routing::submodules::sub_condition_plain::ConditionPlain;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::sub_ref_data::RefData;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::sub_update_target::UpdateTarget;
+#[allow(unused_imports)]
+use crate::utils::x::definition_store::DefinitionStore;
+#[allow(unused_imports)]
+use crate::utils::x::definitions::define_delete::define_delete;
+#[allow(unused_imports)]
+use crate::utils::x::transaction::Transaction;
+
+fn make_block_one(
+    all_blocks: &mut Vec<crate::components::routing::blocks::delete_block::DeleteBlock>,
+) {
+    if let Err(e) = crate::components::routing::blocks::delete_block::DeleteBlock::create(
+        all_blocks, 33, 9, "posts", true,
+    ) {
+        println!("Error: {:#?}", e);
+        return;
+    }
+
+    let mut all_conditions = Vec::<Condition>::new();
+    let left = RefData::create(true, "STRING", "post_author").unwrap();
+    let mut right = RefData::create(true, "STRING", "uid").unwrap();
+    Condition::create(&mut all_conditions, left, right, "EQUAL_TO", false, "NONE");
+
+    let mut all_targets = Vec::<UpdateTarget>::new();
+    UpdateTarget::create(&mut all_targets, "id").unwrap();
+
+    let mut all_target_filters = Vec::<ConditionPlain>::new();
+    right = RefData::create(true, "STRING", "postID").unwrap();
+    ConditionPlain::create(&mut all_target_filters, right, "EQUAL_TO", false, "NONE");
+    UpdateTarget::set_conditions(&mut all_targets, 0, all_target_filters).unwrap();
+
+    crate::components::routing::blocks::delete_block::DeleteBlock::set_conditions(
+        all_blocks,
+        33,
+        all_conditions,
+    )
+    .unwrap();
+
+    crate::components::routing::blocks::delete_block::DeleteBlock::set_targets(
+        all_blocks,
+        33,
+        all_targets,
+    )
+    .unwrap();
+}
+
+fn get_block_str_one() -> String {
+    "DELETE (33,9) [posts,true] {id|([ref,STRING,postID]|EQUAL_TO|not=false|next=NONE)} conditions=([ref,STRING,post_author]|EQUAL_TO|[ref,STRING,uid]|not=false|next=NONE)".to_string()
+}
+
+fn make_block_two(
+    all_blocks: &mut Vec<crate::components::routing::blocks::delete_block::DeleteBlock>,
+) {
+    if let Err(e) = crate::components::routing::blocks::delete_block::DeleteBlock::create(
+        all_blocks, 34, 10, "comments", false,
+    ) {
+        println!("Error: {:#?}", e);
+        return;
+    }
+
+    let mut all_targets = Vec::<UpdateTarget>::new();
+    UpdateTarget::create(&mut all_targets, "post_id").unwrap();
+
+    let mut all_filters = Vec::<ConditionPlain>::new();
+    let mut right = RefData::create(true, "STRING", "postID").unwrap();
+    ConditionPlain::create(&mut all_filters, right, "EQUAL_TO", false, "AND");
+    right = RefData::create(false, "STRING", "archived").unwrap();
+    ConditionPlain::create(&mut all_filters, right, "NOT_EQUAL_TO", false, "NONE");
+    UpdateTarget::set_conditions(&mut all_targets, 0, all_filters).unwrap();
+
+    crate::components::routing::blocks::delete_block::DeleteBlock::set_targets(
+        all_blocks,
+        34,
+        all_targets,
+    )
+    .unwrap();
+}
+
+fn get_block_str_two() -> String {
+    "DELETE (34,10) [comments,false] {post_id|([ref,STRING,postID]|EQUAL_TO|not=false|next=AND)>([,STRING,archived]|NOT_EQUAL_TO|not=false|next=NONE)} conditions=".to_string()
+}
+
+#[test]
+pub fn run_routing_delete_one() {
+    println!("---> Running Routing Delete One");
+    // DELETE (33,9) [posts,true] {id|([ref,STRING,postID]|EQUAL_TO|not=false|next=NONE)} conditions=([ref,STRING,post_author]|EQUAL_TO|[ref,STRING,uid]|not=false|next=NONE)
+
+    let mut all_blocks = Vec::<DeleteBlock>::new();
+    make_block_one(&mut all_blocks);
+
+    assert_eq!(
+        get_block_str_one(),
+        DeleteBlock::to_string(all_blocks[0].clone())
+    );
+}
+
+#[test]
+pub fn run_routing_delete_two() {
+    println!("---> Running Routing Delete Two");
+
+    // DeleteBlock {
+    //     global_index: 33,
+    //     block_index: 9,
+    //     ref_col: "posts",
+    //     save: true,
+    //     targets: [
+    //         UpdateTarget {
+    //             field: "id",
+    //             conditions: [
+    //                 ConditionPlain {
+    //                     right: RefData {
+    //                         ref_var: true,
+    //                         rtype: STRING,
+    //                         data: "postID"
+    //                     },
+    //                     condition_type: EQUAL_TO,
+    //                     not: false,
+    //                     next: NONE
+    //                 }
+    //             ]
+    //         }
+    //     ],
+    //     conditions: [
+    //         Condition {
+    //             left: RefData {
+    //                 ref_var: true,
+    //                 rtype: STRING,
+    //                 data: "post_author"
+    //             },
+    //             right: RefData {
+    //                 ref_var: true,
+    //                 rtype: STRING,
+    //                 data: "uid"
+    //             },
+    //             condition_type: EQUAL_TO,
+    //             not: false,
+    //             next: NONE
+    //         }
+    //     ]
+    // }
+
+    let mut all_blocks = Vec::<DeleteBlock>::new();
+    DeleteBlock::from_string(&mut all_blocks, &get_block_str_one()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<DeleteBlock>::new();
+    make_block_one(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+}
+
+#[test]
+pub fn run_routing_delete_three() {
+    println!("---> Running Routing Delete Three");
+    // DELETE (34,10) [comments,false] {post_id|([ref,STRING,postID]|EQUAL_TO|not=false|next=AND)>([,STRING,archived]|NOT_EQUAL_TO|not=false|next=NONE)} conditions=
+
+    let mut all_blocks = Vec::<DeleteBlock>::new();
+    make_block_two(&mut all_blocks);
+
+    assert_eq!(
+        get_block_str_two(),
+        DeleteBlock::to_string(all_blocks[0].clone())
+    );
+}
+
+#[test]
+pub fn run_routing_delete_four() {
+    println!("---> Running Routing Delete Four");
+
+    let mut all_blocks = Vec::<DeleteBlock>::new();
+    DeleteBlock::from_string(&mut all_blocks, &get_block_str_two()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<DeleteBlock>::new();
+    make_block_two(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+}
+
+#[test]
+pub fn run_routing_delete_five() {
+    println!("---> Running Routing Delete Five");
+    // DELETE (35,11) [posts,true] {} conditions=
+
+    let mut all_blocks = Vec::<DeleteBlock>::new();
+    DeleteBlock::create(&mut all_blocks, 35, 11, "posts", true).unwrap();
+
+    let mut all_definitions = Vec::<DefinitionStore>::new();
+    let mut transaction = Transaction::default();
+
+    let result = define_delete(
+        all_blocks[0].clone(),
+        &vec![],
+        &mut all_definitions,
+        0,
+        "test",
+        &mut transaction,
+    );
+
+    assert_eq!(
+        result,
+        Err((
+            400,
+            String::from("Error: Delete on 'posts' requires at least one target")
+        ))
+    );
+}
@@ -85,3 +85,117 @@ pub fn run_routing_filter_two() {
 
     assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
 }
+
+fn make_block_two(
+    all_blocks: &mut Vec<crate::components::routing::blocks::filter_block::FilterBlock>,
+) {
+    crate::components::routing::blocks::filter_block::FilterBlock::create(
+        all_blocks,
+        5,
+        2,
+        "visiblePosts",
+        "posts",
+        "status",
+    )
+    .unwrap();
+
+    let mut all_filters = Vec::<Filter>::new();
+
+    let right = RefData::create(false, "STRING", "published").unwrap();
+    Filter::create(&mut all_filters, right, "EQUAL_TO", false, "OR");
+    Filter::set_group(&mut all_filters[0], 1, 0).unwrap();
+
+    let right = RefData::create(false, "STRING", "featured").unwrap();
+    Filter::create(&mut all_filters, right, "EQUAL_TO", false, "AND");
+    Filter::set_group(&mut all_filters[1], 0, 1).unwrap();
+
+    let right = RefData::create(false, "STRING", "deleted").unwrap();
+    Filter::create(&mut all_filters, right, "EQUAL_TO", true, "NONE");
+
+    crate::components::routing::blocks::filter_block::FilterBlock::set_filters(
+        all_blocks,
+        5,
+        all_filters,
+    )
+    .unwrap();
+}
+
+fn get_block_str_two() -> String {
+    "FILTER (5,2) [visiblePosts,posts,status] ([,STRING,published]|EQUAL_TO|not=false|next=OR|open=1)>([,STRING,featured]|EQUAL_TO|not=false|next=AND|close=1)>([,STRING,deleted]|EQUAL_TO|not=true|next=NONE)"
+        .to_string()
+}
+
+#[test]
+pub fn run_routing_filter_three() {
+    println!("---> Running Routing Filter Three");
+    // FILTER (5,2) [visiblePosts,posts,status] ([,STRING,published]|EQUAL_TO|not=false|next=OR|open=1)>([,STRING,featured]|EQUAL_TO|not=false|next=AND|close=1)>([,STRING,deleted]|EQUAL_TO|not=true|next=NONE)
+
+    let mut all_blocks = Vec::<FilterBlock>::new();
+    make_block_two(&mut all_blocks);
+
+    assert_eq!(
+        get_block_str_two(),
+        FilterBlock::to_string(all_blocks[0].clone())
+    );
+}
+
+#[test]
+pub fn run_routing_filter_four() {
+    println!("---> Running Routing Filter Four");
+
+    let mut all_blocks = Vec::<FilterBlock>::new();
+    FilterBlock::from_string(&mut all_blocks, &get_block_str_two()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<FilterBlock>::new();
+    make_block_two(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+}
+
+#[test]
+pub fn run_routing_filter_five() {
+    println!("---> Running Routing Filter Five");
+
+    let mut all_filters = Vec::<Filter>::new();
+
+    let right = RefData::create(false, "STRING", "draft").unwrap();
+    Filter::create(&mut all_filters, right, "STARTS_WITH", false, "OR");
+
+    let right = RefData::create(false, "STRING", "").unwrap();
+    Filter::create(&mut all_filters, right, "IS_EMPTY", false, "NONE");
+
+    let stringified = Filter::stringify(&all_filters);
+    assert_eq!(
+        stringified,
+        "([,STRING,draft]|STARTS_WITH|not=false|next=OR)>([,STRING,]|IS_EMPTY|not=false|next=NONE)"
+    );
+
+    let mut parsed_filters = Vec::<Filter>::new();
+    for f_str in stringified.split(">") {
+        Filter::from_string(&mut parsed_filters, f_str).unwrap();
+    }
+
+    assert_eq!(parsed_filters, all_filters);
+}
+
+#[test]
+pub fn run_routing_filter_six() {
+    println!("---> Running Routing Filter Six");
+
+    let mut all_filters = Vec::<Filter>::new();
+
+    let right = RefData::create(false, "STRING", "^(draft|post)[0-9]{2}$").unwrap();
+    Filter::create(&mut all_filters, right, "MATCHES_REGEX", false, "NONE");
+
+    let stringified = Filter::stringify(&all_filters);
+    assert_eq!(
+        stringified,
+        "([,STRING,^~28draft~7Cpost~29~5B0-9~5D~7B2~7D$]|MATCHES_REGEX|not=false|next=NONE)"
+    );
+
+    let mut parsed_filters = Vec::<Filter>::new();
+    Filter::from_string(&mut parsed_filters, &stringified).unwrap();
+
+    assert_eq!(parsed_filters, all_filters);
+    assert_eq!(parsed_filters[0].right.data, "^(draft|post)[0-9]{2}$");
+}
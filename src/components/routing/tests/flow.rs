@@ -0,0 +1,157 @@
+#[cfg(test)]
+#[allow(unused_imports)]
+use crate::components::{
+    collection::Collection,
+    data::Data,
+    datapair::DataPair,
+    routing::{
+        blocks::delete_block::DeleteBlock,
+        mod_route::RouteComponent,
+        submodules::{
+            sub_condition_plain::ConditionPlain, sub_ref_data::RefData,
+            sub_update_target::UpdateTarget,
+        },
+    },
+    structure::Structure,
+};
+#[allow(unused_imports)]
+use crate::init::mapping::initialize_mappings;
+#[allow(unused_imports)]
+use crate::routes::x::x::finish_flow;
+#[allow(unused_imports)]
+use crate::utils::x::{
+    flow_processor::execute_flow, signal_processor::Signal, transaction::Transaction,
+};
+#[allow(unused_imports)]
+use crate::utils::{
+    collection::{auto_fetch_all_collections, auto_save_all_collections},
+    data::{auto_fetch_all_data, auto_save_all_data},
+};
+#[allow(unused_imports)]
+use serde_json::{Map, Value};
+
+fn setup_collection(project_id: &str, collection_id: &str) {
+    let mappings = initialize_mappings();
+
+    let mut all_collections = auto_fetch_all_collections(&mappings).unwrap();
+    if Collection::get(&all_collections, project_id, collection_id).is_err() {
+        Collection::create(
+            &mut all_collections,
+            collection_id,
+            project_id,
+            "Flow Items",
+            "Items written by flows",
+        )
+        .unwrap();
+
+        let mut all_structures = Vec::<Structure>::new();
+        Structure::create(
+            &mut all_structures,
+            "name",
+            "Name",
+            "Name of an item",
+            "TEXT",
+            "",
+            0,
+            100,
+            false,
+            false,
+            "",
+            false,
+            false,
+        )
+        .unwrap();
+        Collection::add_structure(
+            &mut all_collections,
+            &collection_id.to_string(),
+            all_structures[0].clone(),
+        )
+        .unwrap();
+
+        auto_save_all_collections(&mappings, &all_collections).unwrap();
+    }
+
+    let mut all_data = Vec::<Data>::new();
+    for (id, name) in [("keep", "keep"), ("drop", "remove")].iter() {
+        Data::create(&mut all_data, id, project_id, collection_id, true).unwrap();
+
+        let mut all_pairs = Vec::<DataPair>::new();
+        DataPair::create(
+            &mut all_pairs,
+            &format!("{}_name", id),
+            "name",
+            "",
+            name,
+            "TEXT",
+        )
+        .unwrap();
+        Data::add_pair(&mut all_data, &id.to_string(), all_pairs[0].clone()).unwrap();
+    }
+
+    auto_save_all_data(&mappings, project_id, collection_id, &all_data).unwrap();
+}
+
+fn make_route_one(collection_id: &str) -> RouteComponent {
+    let mut route = RouteComponent::default();
+    route.route_id = String::from("remove_item");
+
+    DeleteBlock::create(&mut route.flow.deletes, 0, 0, collection_id, true).unwrap();
+
+    let mut all_targets = Vec::<UpdateTarget>::new();
+    UpdateTarget::create(&mut all_targets, "name").unwrap();
+
+    let mut all_target_filters = Vec::<ConditionPlain>::new();
+    let right = RefData::create(false, "STRING", "remove").unwrap();
+    ConditionPlain::create(&mut all_target_filters, right, "EQUAL_TO", false, "NONE");
+    UpdateTarget::set_conditions(&mut all_targets, 0, all_target_filters).unwrap();
+
+    DeleteBlock::set_targets(&mut route.flow.deletes, 0, all_targets).unwrap();
+
+    route
+}
+
+fn run_route(project_id: &str, collection_id: &str, dry_run: bool) -> Vec<String> {
+    let route = make_route_one(collection_id);
+    let mut transaction = Transaction::new(dry_run, false);
+
+    let result = execute_flow(
+        &route,
+        project_id,
+        &Value::Object(Map::new()),
+        &vec![],
+        &vec![],
+        &vec![],
+        &mut transaction,
+        &mut None,
+        0,
+    );
+    assert!(matches!(result, Ok(Signal::NONE)));
+
+    let mappings = initialize_mappings();
+    let response = finish_flow(result, &transaction, &mappings);
+    assert_eq!(response.status, 200);
+
+    let all_data = auto_fetch_all_data(&mappings, project_id, collection_id).unwrap();
+    all_data
+        .iter()
+        .map(|d| d.id.clone())
+        .collect::<Vec<String>>()
+}
+
+#[test]
+pub fn run_routing_flow_one() {
+    println!("---> Running Routing Flow One");
+    // DELETE (0,0) [flow_items,true] {name|([,STRING,remove]|EQUAL_TO|not=false|next=NONE)}
+    // No RETURN block, the staged delete is committed once the flow completes
+
+    setup_collection("flow_test", "flow_items");
+
+    assert_eq!(
+        run_route("flow_test", "flow_items", true),
+        vec![String::from("keep"), String::from("drop")]
+    );
+    assert_eq!(
+        run_route("flow_test", "flow_items", false),
+        vec![String::from("keep")]
+    );
+}
@@ -5,6 +5,10 @@ use crate::components::routing::blocks::function_block::FunctionBlock;
 use crate::components::routing::submodules::sub_function::Function;
 #[allow(unused_imports)]
 use crate::components::routing::submodules::sub_ref_data::RefData;
+#[allow(unused_imports)]
+use crate::utils::x::definition_store::{DefinitionData, DefinitionStore};
+#[allow(unused_imports)]
+use crate::utils::x::definitions::define_function::define_function;
 
 fn make_block_one(
     all_blocks: &mut Vec<crate::components::routing::blocks::function_block::FunctionBlock>,
@@ -53,6 +57,29 @@ fn get_block_str_two() -> String {
     "FUNCTION (44,9) [notificationID] {PAGINATE=[,INTEGER,0]>[,INTEGER,20]}".to_string()
 }
 
+fn make_block_three(
+    all_blocks: &mut Vec<crate::components::routing::blocks::function_block::FunctionBlock>,
+) {
+    let mut func = Function::create("REPLACE");
+    let params = vec![
+        RefData::create(false, "STRING", "hello_world").unwrap(),
+        RefData::create(false, "STRING", "_").unwrap(),
+        RefData::create(false, "STRING", "-").unwrap(),
+    ];
+    Function::set_params(&mut func, params);
+
+    if let Err(e) = crate::components::routing::blocks::function_block::FunctionBlock::create(
+        all_blocks, 45, 10, "slug", func,
+    ) {
+        println!("Error: {:#?}", e);
+        return;
+    }
+}
+
+fn get_block_str_three() -> String {
+    "FUNCTION (45,10) [slug] {REPLACE=[,STRING,hello_world]>[,STRING,_]>[,STRING,-]}".to_string()
+}
+
 #[test]
 pub fn run_routing_function_one() {
     println!("---> Running Routing Function One");
@@ -137,3 +164,491 @@ pub fn run_routing_function_four() {
 
     assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
 }
+
+#[test]
+pub fn run_routing_function_five() {
+    println!("---> Running Routing Function Five");
+    // FUNCTION (45,10) [slug] {REPLACE=[,STRING,hello_world]>[,STRING,_]>[,STRING,-]}
+
+    let mut all_blocks = Vec::<FunctionBlock>::new();
+    FunctionBlock::from_string(&mut all_blocks, &get_block_str_three()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<FunctionBlock>::new();
+    make_block_three(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+    assert_eq!(
+        get_block_str_three(),
+        FunctionBlock::to_string(all_blocks[0].clone())
+    );
+
+    let mut all_definitions = Vec::<DefinitionStore>::new();
+    let result = define_function(all_blocks[0].clone(), &vec![], &mut all_definitions, 0);
+
+    assert_eq!(
+        result,
+        Ok(DefinitionData::STRING("hello-world".to_string()))
+    );
+}
+
+fn run_date_add(amount: &str, unit: &str) -> Result<DefinitionData, (usize, String)> {
+    let mut func = Function::create("DATE_ADD");
+    let params = vec![
+        RefData::create(false, "STRING", "2024-01-30 12:00:00 +00:00").unwrap(),
+        RefData::create(false, "INTEGER", amount).unwrap(),
+        RefData::create(false, "STRING", unit).unwrap(),
+    ];
+    Function::set_params(&mut func, params);
+
+    let mut all_blocks = Vec::<FunctionBlock>::new();
+    FunctionBlock::create(&mut all_blocks, 46, 11, "expiry", func).unwrap();
+
+    let mut all_definitions = Vec::<DefinitionStore>::new();
+    define_function(all_blocks[0].clone(), &vec![], &mut all_definitions, 0)
+}
+
+#[test]
+pub fn run_routing_function_six() {
+    println!("---> Running Routing Function Six");
+
+    assert_eq!(
+        run_date_add("2", "DAYS"),
+        Ok(DefinitionData::STRING(
+            "2024-02-01 12:00:00 +00:00".to_string()
+        ))
+    );
+    assert_eq!(
+        run_date_add("-90", "MINUTES"),
+        Ok(DefinitionData::STRING(
+            "2024-01-30 10:30:00 +00:00".to_string()
+        ))
+    );
+
+    let expected_error = Err((
+        500,
+        String::from("Error: Invalid data type for the 'amount' param of the DATE_ADD function"),
+    ));
+    assert_eq!(run_date_add("9223372036854775807", "WEEKS"), expected_error);
+    assert_eq!(
+        run_date_add("-9223372036854775807", "SECONDS"),
+        expected_error
+    );
+    assert_eq!(run_date_add("100000000000", "DAYS"), expected_error);
+}
+
+fn make_function(name: &str, params: Vec<(bool, &str, &str)>) -> FunctionBlock {
+    let mut func = Function::create(name);
+    let mut all_params = Vec::<RefData>::new();
+    for (ref_var, rtype, data) in params {
+        all_params.push(RefData::create(ref_var, rtype, data).unwrap());
+    }
+    Function::set_params(&mut func, all_params);
+
+    let mut all_blocks = Vec::<FunctionBlock>::new();
+    FunctionBlock::create(&mut all_blocks, 47, 12, "result", func).unwrap();
+
+    all_blocks[0].clone()
+}
+
+fn run_function(
+    name: &str,
+    params: Vec<(bool, &str, &str)>,
+) -> Result<DefinitionData, (usize, String)> {
+    let mut all_definitions = Vec::<DefinitionStore>::new();
+    define_function(
+        make_function(name, params),
+        &vec![],
+        &mut all_definitions,
+        0,
+    )
+}
+
+fn invalid_param(param: &str, name: &str) -> Result<DefinitionData, (usize, String)> {
+    Err((
+        500,
+        format!(
+            "Error: Invalid data type for the '{}' param of the {} function",
+            param, name
+        ),
+    ))
+}
+
+fn invalid_count(name: &str) -> Result<DefinitionData, (usize, String)> {
+    Err((
+        500,
+        format!("Error: Invalid number of parameters for {} function", name),
+    ))
+}
+
+fn to_string_array(items: Vec<&str>) -> DefinitionData {
+    DefinitionData::ARRAY(
+        items
+            .iter()
+            .map(|item| DefinitionData::STRING(item.to_string()))
+            .collect(),
+    )
+}
+
+#[test]
+pub fn run_routing_function_seven() {
+    println!("---> Running Routing Function Seven");
+    // Every function added alongside REPLACE survives a to_string / from_string round trip
+
+    let all_functions = vec![
+        ("UPPERCASE", vec![(true, "STRING", "name")]),
+        ("LOWERCASE", vec![(true, "STRING", "name")]),
+        ("TRIM", vec![(true, "STRING", "name")]),
+        (
+            "SPLIT",
+            vec![(true, "STRING", "tags"), (false, "STRING", "-")],
+        ),
+        (
+            "JOIN",
+            vec![(true, "ARRAY", "tags"), (false, "STRING", "-")],
+        ),
+        (
+            "SUBSTRING",
+            vec![
+                (true, "STRING", "name"),
+                (false, "INTEGER", "0"),
+                (false, "INTEGER", "3"),
+            ],
+        ),
+        ("HASH_ARGON2", vec![(true, "STRING", "password")]),
+        (
+            "VERIFY_ARGON2",
+            vec![(true, "STRING", "hash"), (true, "STRING", "password")],
+        ),
+        ("SHA256", vec![(true, "STRING", "name")]),
+        (
+            "DATE_ADD",
+            vec![
+                (true, "STRING", "createdAt"),
+                (false, "INTEGER", "2"),
+                (false, "STRING", "DAYS"),
+            ],
+        ),
+        (
+            "DATE_DIFF",
+            vec![
+                (true, "STRING", "createdAt"),
+                (true, "STRING", "updatedAt"),
+                (false, "STRING", "HOURS"),
+            ],
+        ),
+        (
+            "RANDOM_INT",
+            vec![(false, "INTEGER", "1"), (false, "INTEGER", "6")],
+        ),
+        ("JSON_PARSE", vec![(true, "STRING", "payload")]),
+        ("JSON_STRINGIFY", vec![(true, "OTHER", "user")]),
+    ];
+
+    for (name, params) in all_functions {
+        let block = make_function(name, params);
+        let block_str = FunctionBlock::to_string(block.clone());
+
+        let mut all_blocks = Vec::<FunctionBlock>::new();
+        FunctionBlock::from_string(&mut all_blocks, &block_str).unwrap();
+
+        assert_eq!(all_blocks[0], block);
+        assert_eq!(FunctionBlock::to_string(all_blocks[0].clone()), block_str);
+    }
+}
+
+#[test]
+pub fn run_routing_function_eight() {
+    println!("---> Running Routing Function Eight");
+
+    assert_eq!(
+        run_function("UPPERCASE", vec![(false, "STRING", "Hello")]),
+        Ok(DefinitionData::STRING("HELLO".to_string()))
+    );
+    assert_eq!(
+        run_function("LOWERCASE", vec![(false, "STRING", "Hello")]),
+        Ok(DefinitionData::STRING("hello".to_string()))
+    );
+    assert_eq!(
+        run_function("TRIM", vec![(false, "STRING", "  hello  ")]),
+        Ok(DefinitionData::STRING("hello".to_string()))
+    );
+    assert_eq!(
+        run_function("UPPERCASE", vec![(false, "ARRAY", "a,b")]),
+        invalid_param("text", "UPPERCASE")
+    );
+    assert_eq!(run_function("TRIM", vec![]), invalid_count("TRIM"));
+
+    assert_eq!(
+        run_function(
+            "SPLIT",
+            vec![(false, "STRING", "a-b-c"), (false, "STRING", "-")]
+        ),
+        Ok(to_string_array(vec!["a", "b", "c"]))
+    );
+    assert_eq!(
+        run_function("SPLIT", vec![(false, "STRING", "a-b-c")]),
+        invalid_count("SPLIT")
+    );
+
+    assert_eq!(
+        run_function(
+            "JOIN",
+            vec![(false, "ARRAY", "a,b,1"), (false, "STRING", "-")]
+        ),
+        Ok(DefinitionData::STRING("a-b-1".to_string()))
+    );
+    assert_eq!(
+        run_function("JOIN", vec![(false, "ARRAY", "a,b")]),
+        Ok(DefinitionData::STRING("a,b".to_string()))
+    );
+    assert_eq!(
+        run_function("JOIN", vec![(false, "STRING", "a,b")]),
+        invalid_param("data", "JOIN")
+    );
+
+    assert_eq!(
+        run_function(
+            "REPLACE",
+            vec![(false, "STRING", "a.b"), (false, "STRING", ".")]
+        ),
+        invalid_count("REPLACE")
+    );
+
+    assert_eq!(
+        run_function(
+            "SUBSTRING",
+            vec![(false, "STRING", "hello world"), (false, "INTEGER", "6")]
+        ),
+        Ok(DefinitionData::STRING("world".to_string()))
+    );
+    assert_eq!(
+        run_function(
+            "SUBSTRING",
+            vec![
+                (false, "STRING", "hello"),
+                (false, "INTEGER", "1"),
+                (false, "INTEGER", "3")
+            ]
+        ),
+        Ok(DefinitionData::STRING("ell".to_string()))
+    );
+    assert_eq!(
+        run_function(
+            "SUBSTRING",
+            vec![(false, "STRING", "hello"), (false, "INTEGER", "-1")]
+        ),
+        invalid_param("start", "SUBSTRING")
+    );
+    assert_eq!(
+        run_function(
+            "SUBSTRING",
+            vec![
+                (false, "STRING", "hello"),
+                (false, "STRING", "99999999999999999999999")
+            ]
+        ),
+        invalid_param("start", "SUBSTRING")
+    );
+}
+
+#[test]
+pub fn run_routing_function_nine() {
+    println!("---> Running Routing Function Nine");
+
+    assert_eq!(
+        run_function("SHA256", vec![(false, "STRING", "abc")]),
+        Ok(DefinitionData::STRING(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad".to_string()
+        ))
+    );
+    assert_eq!(
+        run_function("SHA256", vec![(false, "ARRAY", "a,b")]),
+        invalid_param("text", "SHA256")
+    );
+
+    let hash = match run_function("HASH_ARGON2", vec![(false, "STRING", "secret")]) {
+        Ok(DefinitionData::STRING(h)) => h,
+        result => panic!("Unexpected HASH_ARGON2 result: {:?}", result),
+    };
+    assert!(hash.starts_with("$argon2"));
+
+    assert_eq!(
+        run_function(
+            "VERIFY_ARGON2",
+            vec![(false, "STRING", &hash), (false, "STRING", "secret")]
+        ),
+        Ok(DefinitionData::BOOLEAN(true))
+    );
+    assert_eq!(
+        run_function(
+            "VERIFY_ARGON2",
+            vec![(false, "STRING", &hash), (false, "STRING", "wrong")]
+        ),
+        Ok(DefinitionData::BOOLEAN(false))
+    );
+    assert_eq!(
+        run_function(
+            "VERIFY_ARGON2",
+            vec![(false, "STRING", "not-a-hash"), (false, "STRING", "secret")]
+        ),
+        invalid_param("hash", "VERIFY_ARGON2")
+    );
+    assert_eq!(
+        run_function("VERIFY_ARGON2", vec![(false, "STRING", &hash)]),
+        invalid_count("VERIFY_ARGON2")
+    );
+}
+
+#[test]
+pub fn run_routing_function_ten() {
+    println!("---> Running Routing Function Ten");
+    // GENERATE_TIMESTAMP output is accepted by DATE_ADD and DATE_DIFF
+
+    let timestamp = match run_function("GENERATE_TIMESTAMP", vec![]) {
+        Ok(DefinitionData::STRING(t)) => t,
+        result => panic!("Unexpected GENERATE_TIMESTAMP result: {:?}", result),
+    };
+
+    let later = match run_function(
+        "DATE_ADD",
+        vec![
+            (false, "STRING", &timestamp),
+            (false, "INTEGER", "1"),
+            (false, "STRING", "HOUR"),
+        ],
+    ) {
+        Ok(DefinitionData::STRING(t)) => t,
+        result => panic!("Unexpected DATE_ADD result: {:?}", result),
+    };
+
+    assert_eq!(
+        run_function(
+            "DATE_DIFF",
+            vec![
+                (false, "STRING", &timestamp),
+                (false, "STRING", &later),
+                (false, "STRING", "MINUTES")
+            ]
+        ),
+        Ok(DefinitionData::INTEGER(60))
+    );
+    assert_eq!(
+        run_function(
+            "DATE_DIFF",
+            vec![
+                (false, "STRING", "2024-01-30T12:00:00Z"),
+                (false, "STRING", "2024-02-06 12:00:00 +00:00"),
+                (false, "STRING", "WEEKS")
+            ]
+        ),
+        Ok(DefinitionData::INTEGER(1))
+    );
+    assert_eq!(
+        run_function(
+            "DATE_DIFF",
+            vec![
+                (false, "STRING", "2024-02-06 12:00:00 +00:00"),
+                (false, "STRING", "2024-01-30T12:00:00Z")
+            ]
+        ),
+        Ok(DefinitionData::INTEGER(-604800))
+    );
+    assert_eq!(
+        run_function(
+            "DATE_DIFF",
+            vec![
+                (false, "STRING", "yesterday"),
+                (false, "STRING", "2024-01-30T12:00:00Z")
+            ]
+        ),
+        invalid_param("start", "DATE_DIFF")
+    );
+    assert_eq!(
+        run_function(
+            "DATE_DIFF",
+            vec![
+                (false, "STRING", "2024-01-30T12:00:00Z"),
+                (false, "STRING", "2024-01-30T12:00:00Z"),
+                (false, "STRING", "FORTNIGHT")
+            ]
+        ),
+        invalid_param("unit", "DATE_DIFF")
+    );
+    assert_eq!(
+        run_function(
+            "DATE_ADD",
+            vec![
+                (false, "STRING", &timestamp),
+                (false, "STRING", "99999999999999999999999")
+            ]
+        ),
+        invalid_param("amount", "DATE_ADD")
+    );
+}
+
+#[test]
+pub fn run_routing_function_eleven() {
+    println!("---> Running Routing Function Eleven");
+
+    assert_eq!(
+        run_function(
+            "RANDOM_INT",
+            vec![(false, "INTEGER", "4"), (false, "INTEGER", "4")]
+        ),
+        Ok(DefinitionData::INTEGER(4))
+    );
+    match run_function(
+        "RANDOM_INT",
+        vec![(false, "INTEGER", "-3"), (false, "INTEGER", "3")],
+    ) {
+        Ok(DefinitionData::INTEGER(i)) => assert!(i >= -3 && i <= 3),
+        result => panic!("Unexpected RANDOM_INT result: {:?}", result),
+    }
+    assert!(matches!(
+        run_function(
+            "RANDOM_INT",
+            vec![
+                (false, "STRING", &isize::MIN.to_string()),
+                (false, "STRING", &isize::MAX.to_string())
+            ]
+        ),
+        Ok(DefinitionData::INTEGER(_))
+    ));
+    assert_eq!(
+        run_function(
+            "RANDOM_INT",
+            vec![(false, "INTEGER", "5"), (false, "INTEGER", "1")]
+        ),
+        invalid_param("max", "RANDOM_INT")
+    );
+    assert_eq!(
+        run_function(
+            "RANDOM_INT",
+            vec![
+                (false, "INTEGER", "1"),
+                (false, "STRING", "99999999999999999999999")
+            ]
+        ),
+        invalid_param("max", "RANDOM_INT")
+    );
+
+    assert_eq!(
+        run_function("JSON_PARSE", vec![(false, "STRING", "[1,2]")]),
+        Ok(DefinitionData::ARRAY(vec![
+            DefinitionData::INTEGER(1),
+            DefinitionData::INTEGER(2)
+        ]))
+    );
+    assert_eq!(
+        run_function("JSON_PARSE", vec![(false, "STRING", "{bad")]),
+        invalid_param("json", "JSON_PARSE")
+    );
+    assert_eq!(
+        run_function("JSON_STRINGIFY", vec![(false, "ARRAY", "1,2")]),
+        Ok(DefinitionData::STRING("[1,2]".to_string()))
+    );
+    assert_eq!(
+        run_function("JSON_STRINGIFY", vec![]),
+        invalid_count("JSON_STRINGIFY")
+    );
+}
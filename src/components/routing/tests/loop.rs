@@ -1,16 +1,34 @@
+#[allow(unused_imports)]
+use crate::components::routing::blocks::function_block::FunctionBlock;
 #[cfg(test)]
 #[allow(unused_imports)]
 use crate::components::routing::blocks::loop_block::LoopBlock;
 #[allow(unused_imports)]
+use crate::components::routing::mod_route::RouteComponent;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::sub_function::Function;
+#[allow(unused_imports)]
 use crate::components::routing::submodules::sub_ref_data::RefData;
+#[allow(unused_imports)]
+use crate::utils::x::definition_store::DefinitionData;
+#[allow(unused_imports)]
+use crate::utils::x::flow_processor::{execute_flow, TraceStep};
+#[allow(unused_imports)]
+use crate::utils::x::global_block_order::GlobalBlockOrder;
+#[allow(unused_imports)]
+use crate::utils::x::loop_processor::LoopObject;
+#[allow(unused_imports)]
+use crate::utils::x::signal_processor::Signal;
+#[allow(unused_imports)]
+use crate::utils::x::transaction::Transaction;
+#[allow(unused_imports)]
+use serde_json::{Map, Value};
 
-fn make_block_one(all_blocks: &mut Vec<crate::components::routing::blocks::loop_block::LoopBlock>) {
+fn make_block_one(all_blocks: &mut Vec<LoopBlock>) {
     let min = RefData::create(false, "INTEGER", "0").unwrap();
     let max = RefData::create(true, "INTEGER", "currentProfilesLength").unwrap();
 
-    if let Err(e) = crate::components::routing::blocks::loop_block::LoopBlock::create(
-        all_blocks, 5, 1, "x", min, max,
-    ) {
+    if let Err(e) = LoopBlock::create(all_blocks, 5, 1, "x", min, max) {
         println!("Error: {:#?}", e);
         return;
     }
@@ -20,6 +38,29 @@ fn get_block_str_one() -> String {
     "LOOP (5,1) [x] ([,INTEGER,0]|[ref,INTEGER,currentProfilesLength])".to_string()
 }
 
+fn make_block_two(all_blocks: &mut Vec<LoopBlock>) {
+    let each = RefData::create(true, "ARRAY", "posts").unwrap();
+
+    if let Err(e) = LoopBlock::create(
+        all_blocks,
+        6,
+        1,
+        "post",
+        RefData::default(),
+        RefData::default(),
+    ) {
+        println!("Error: {:#?}", e);
+        return;
+    }
+
+    LoopBlock::update_each(all_blocks, 6, Some(each)).unwrap();
+    LoopBlock::update_index_name(all_blocks, 6, "i").unwrap();
+}
+
+fn get_block_str_two() -> String {
+    "LOOP (6,1) [post,i] (each=[ref,ARRAY,posts])".to_string()
+}
+
 #[test]
 pub fn run_routing_loop_one() {
     println!("---> Running Routing Loop One");
@@ -62,3 +103,195 @@ pub fn run_routing_loop_two() {
 
     assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
 }
+
+#[test]
+pub fn run_routing_loop_three() {
+    println!("---> Running Routing Loop Three");
+    // LOOP (6,1) [post,i] (each=[ref,ARRAY,posts])
+
+    let mut all_blocks = Vec::<LoopBlock>::new();
+    make_block_two(&mut all_blocks);
+
+    assert_eq!(
+        get_block_str_two(),
+        LoopBlock::to_string(all_blocks[0].clone())
+    );
+}
+
+#[test]
+pub fn run_routing_loop_four() {
+    println!("---> Running Routing Loop Four");
+
+    let mut all_blocks = Vec::<LoopBlock>::new();
+    LoopBlock::from_string(&mut all_blocks, &get_block_str_two()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<LoopBlock>::new();
+    make_block_two(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+}
+
+fn make_function(
+    route: &mut RouteComponent,
+    global_index: u32,
+    local_name: &str,
+    func_name: &str,
+    params: Vec<RefData>,
+) {
+    let mut func = Function::create(func_name);
+    for param in params {
+        Function::add_param(&mut func, param);
+    }
+
+    FunctionBlock::create(&mut route.flow.functions, global_index, 0, local_name, func).unwrap();
+}
+
+fn make_loop(route: &mut RouteComponent, global_index: u32, local_name: &str, each: &str) {
+    LoopBlock::create(
+        &mut route.flow.loops,
+        global_index,
+        0,
+        local_name,
+        RefData::default(),
+        RefData::default(),
+    )
+    .unwrap();
+
+    let each = RefData::create(true, "ARRAY", each).unwrap();
+    LoopBlock::update_each(&mut route.flow.loops, global_index, Some(each)).unwrap();
+}
+
+fn make_route_one() -> RouteComponent {
+    let mut route = RouteComponent::default();
+    route.route_id = String::from("nested_loops");
+
+    make_function(
+        &mut route,
+        0,
+        "groups",
+        "SPLIT",
+        vec![
+            RefData::create(false, "STRING", "x-y;z").unwrap(),
+            RefData::create(false, "STRING", ";").unwrap(),
+        ],
+    );
+
+    make_loop(&mut route, 1, "group", "groups");
+    LoopBlock::update_index_name(&mut route.flow.loops, 1, "g").unwrap();
+
+    make_function(
+        &mut route,
+        2,
+        "parts",
+        "SPLIT",
+        vec![
+            RefData::create(true, "STRING", "group").unwrap(),
+            RefData::create(false, "STRING", "-").unwrap(),
+        ],
+    );
+
+    make_loop(&mut route, 3, "part", "parts");
+    make_function(
+        &mut route,
+        4,
+        "upper",
+        "UPPERCASE",
+        vec![RefData::create(true, "STRING", "part").unwrap()],
+    );
+
+    make_loop(&mut route, 5, "again", "groups");
+    make_function(
+        &mut route,
+        6,
+        "lower",
+        "LOWERCASE",
+        vec![RefData::create(true, "STRING", "again").unwrap()],
+    );
+
+    route
+}
+
+fn collect_values(trace: &Vec<TraceStep>, ref_name: &str) -> Vec<DefinitionData> {
+    let mut all_values = Vec::<DefinitionData>::new();
+
+    for step in trace.iter() {
+        if step.ref_name != ref_name {
+            continue;
+        }
+
+        for definition in step.definitions.iter() {
+            if definition.ref_name == ref_name {
+                all_values.push(definition.data.clone());
+            }
+        }
+    }
+
+    all_values
+}
+
+#[test]
+pub fn run_routing_loop_five() {
+    println!("---> Running Routing Loop Five");
+
+    let route = make_route_one();
+
+    let mut global_blocks = Vec::<GlobalBlockOrder>::new();
+    GlobalBlockOrder::process_blocks(&route, &mut global_blocks);
+
+    let all_loops = LoopObject::detect_loops(&global_blocks, &route).unwrap();
+    assert_eq!(all_loops.len(), 3);
+
+    assert_eq!(all_loops[0].ref_var, "group");
+    assert_eq!(all_loops[0].depth, 0);
+    assert_eq!((all_loops[0].start_index, all_loops[0].body_index), (1, 3));
+    assert_eq!(all_loops[0].end_index, 6);
+
+    assert_eq!(all_loops[1].ref_var, "part");
+    assert_eq!(all_loops[1].depth, 1);
+    assert_eq!((all_loops[1].start_index, all_loops[1].body_index), (4, 5));
+    assert_eq!(all_loops[1].end_index, 6);
+
+    assert_eq!(all_loops[2].ref_var, "again");
+    assert_eq!(all_loops[2].depth, 0);
+    assert_eq!(all_loops[2].end_index, 8);
+}
+
+#[test]
+pub fn run_routing_loop_six() {
+    println!("---> Running Routing Loop Six");
+
+    let route = make_route_one();
+
+    let mut transaction = Transaction::default();
+    let mut all_steps = Vec::<TraceStep>::new();
+
+    let result = execute_flow(
+        &route,
+        "test",
+        &Value::Object(Map::new()),
+        &vec![],
+        &vec![],
+        &vec![],
+        &mut transaction,
+        &mut Some(&mut all_steps),
+        0,
+    );
+    assert_eq!(result, Ok(Signal::NONE));
+
+    assert_eq!(
+        collect_values(&all_steps, "upper"),
+        vec![
+            DefinitionData::STRING(String::from("X")),
+            DefinitionData::STRING(String::from("Y")),
+            DefinitionData::STRING(String::from("Z")),
+        ]
+    );
+
+    assert_eq!(
+        collect_values(&all_steps, "lower"),
+        vec![
+            DefinitionData::STRING(String::from("x-y")),
+            DefinitionData::STRING(String::from("z")),
+        ]
+    );
+}
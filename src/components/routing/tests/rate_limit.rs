@@ -0,0 +1,61 @@
+#[cfg(test)]
+#[allow(unused_imports)]
+use crate::components::routing::core::core_rate_limit::RateLimit;
+#[allow(unused_imports)]
+use crate::utils::rate_limit::check_rate_limit;
+
+fn make_core_one() -> crate::components::routing::core::core_rate_limit::RateLimit {
+    crate::components::routing::core::core_rate_limit::RateLimit::create(2, 60, "IP").unwrap()
+}
+
+fn get_core_str_one() -> String {
+    "DEFINE rate_limit [2,60,IP]".to_string()
+}
+
+#[test]
+pub fn run_routing_core_rate_limit_one() {
+    println!("---> Running Routing Core Rate Limit One");
+    // DEFINE rate_limit [2,60,IP]
+
+    let rate_limit = make_core_one();
+
+    assert_eq!(get_core_str_one(), RateLimit::to_string(rate_limit));
+}
+
+#[test]
+pub fn run_routing_core_rate_limit_two() {
+    println!("---> Running Routing Core Rate Limit Two");
+
+    let rate_limit_one = RateLimit::from_string(&get_core_str_one()).unwrap();
+    let rate_limit_two = make_core_one();
+
+    assert_eq!(rate_limit_two, rate_limit_one);
+    assert!(RateLimit::from_string("DEFINE rate_limit [0,60,GLOBAL]").is_err());
+}
+
+#[test]
+pub fn run_routing_core_rate_limit_three() {
+    println!("---> Running Routing Core Rate Limit Three");
+
+    let rate_limit = make_core_one();
+    let route_id = uuid::Uuid::new_v4().to_string();
+
+    assert_eq!(
+        check_rate_limit("test", &route_id, &rate_limit, "127.0.0.1"),
+        Ok(())
+    );
+    assert_eq!(
+        check_rate_limit("test", &route_id, &rate_limit, "127.0.0.1"),
+        Ok(())
+    );
+
+    match check_rate_limit("test", &route_id, &rate_limit, "127.0.0.1") {
+        Ok(_) => panic!("Expected the third request to be rate limited"),
+        Err(retry_after) => assert!(retry_after > 0 && retry_after <= 60),
+    }
+
+    assert_eq!(
+        check_rate_limit("test", &route_id, &rate_limit, "127.0.0.2"),
+        Ok(())
+    );
+}
@@ -80,3 +80,71 @@ pub fn run_routing_return_two() {
 
     assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
 }
+
+fn make_block_two(
+    all_blocks: &mut Vec<crate::components::routing::blocks::return_block::ReturnBlock>,
+) {
+    crate::components::routing::blocks::return_block::ReturnBlock::create(all_blocks, 12, 2);
+
+    let mut all_pairs = Vec::<ObjectPair>::new();
+    let ref_data = RefData::create(true, "STRING", "postID").unwrap();
+    ObjectPair::create(&mut all_pairs, "id", ref_data).unwrap();
+
+    crate::components::routing::blocks::return_block::ReturnBlock::set_pairs(
+        all_blocks, 12, all_pairs,
+    )
+    .unwrap();
+
+    crate::components::routing::blocks::return_block::ReturnBlock::update_status(
+        all_blocks, 12, 201,
+    )
+    .unwrap();
+
+    let mut all_headers = Vec::<ObjectPair>::new();
+    let ref_data = RefData::create(true, "STRING", "postURL").unwrap();
+    ObjectPair::create(&mut all_headers, "Location", ref_data).unwrap();
+    let ref_data = RefData::create(false, "STRING", "no-store").unwrap();
+    ObjectPair::create(&mut all_headers, "Cache-Control", ref_data).unwrap();
+
+    crate::components::routing::blocks::return_block::ReturnBlock::set_headers(
+        all_blocks,
+        12,
+        all_headers,
+    )
+    .unwrap();
+}
+
+fn get_block_str_two() -> String {
+    "RETURN (12,2) (id=[ref,STRING,postID]) status=201 headers=(Location=[ref,STRING,postURL])>(Cache-Control=[,STRING,no-store]) conditions="
+        .to_string()
+}
+
+#[test]
+pub fn run_routing_return_three() {
+    println!("---> Running Routing Return Three");
+    // RETURN (12,2) (id=[ref,STRING,postID]) status=201 headers=(Location=[ref,STRING,postURL])>(Cache-Control=[,STRING,no-store]) conditions=
+
+    let mut all_blocks = Vec::<ReturnBlock>::new();
+    make_block_two(&mut all_blocks);
+
+    assert_eq!(
+        get_block_str_two(),
+        ReturnBlock::to_string(all_blocks[0].clone())
+    );
+}
+
+#[test]
+pub fn run_routing_return_four() {
+    println!("---> Running Routing Return Four");
+
+    let mut all_blocks = Vec::<ReturnBlock>::new();
+    ReturnBlock::from_string(&mut all_blocks, &get_block_str_two()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<ReturnBlock>::new();
+    make_block_two(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+    assert_eq!(all_blocks[0].status, 201);
+
+    assert!(ReturnBlock::update_status(&mut all_blocks, 12, 42).is_err());
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+#[allow(unused_imports)]
+use crate::components::routing::blocks::{create_block::CreateBlock, fetch_block::FetchBlock};
+#[allow(unused_imports)]
+use crate::components::routing::mod_route::RouteComponent;
+#[allow(unused_imports)]
+use crate::utils::route_cache::{
+    cache_response, fetch_cached_response, get_route_cache_key, invalidate_route_cache,
+};
+#[allow(unused_imports)]
+use crate::utils::x::route_validator::{validate_route, Diagnostic};
+
+fn make_route_one() -> RouteComponent {
+    let mut route = RouteComponent::default();
+    route.route_id = uuid::Uuid::new_v4().to_string();
+    route.project_id = String::from("blog");
+    route.cache_ttl = 30;
+
+    FetchBlock::create(&mut route.flow.fetchers, 0, 0, "posts", "posts").unwrap();
+
+    route
+}
+
+#[test]
+pub fn run_routing_cache_one() {
+    println!("---> Running Routing Cache One");
+
+    let mut route = make_route_one();
+    assert!(RouteComponent::to_string(route.clone()).contains("DEFINE cache [30]"));
+    assert!(RouteComponent::is_read_only(&route));
+    assert_eq!(
+        RouteComponent::fetched_collections(&route),
+        vec![String::from("posts")]
+    );
+
+    route.flow.creates.push(CreateBlock::default());
+    assert!(!RouteComponent::is_read_only(&route));
+
+    let diagnostics = validate_route(&route, "blog", &vec![], &vec![]);
+    assert!(diagnostics.contains(&Diagnostic {
+        block_name: String::from("CACHE"),
+        global_index: -1,
+        message: String::from("Error: Only read-only routes can be cached"),
+    }));
+}
+
+#[test]
+pub fn run_routing_cache_two() {
+    println!("---> Running Routing Cache Two");
+
+    let route = make_route_one();
+    let key = get_route_cache_key(&route, "page=1", &vec![], "{}");
+
+    assert_ne!(key, get_route_cache_key(&route, "page=2", &vec![], "{}"));
+    assert_eq!(fetch_cached_response(&key), None);
+
+    cache_response(
+        &key,
+        "blog",
+        vec![String::from("posts")],
+        String::from("cached"),
+        30,
+    );
+    assert_eq!(fetch_cached_response(&key), Some(String::from("cached")));
+
+    invalidate_route_cache("blog", "comments");
+    assert_eq!(fetch_cached_response(&key), Some(String::from("cached")));
+
+    invalidate_route_cache("blog", "posts");
+    assert_eq!(fetch_cached_response(&key), None);
+}
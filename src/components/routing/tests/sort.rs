@@ -0,0 +1,148 @@
+#[cfg(test)]
+#[allow(unused_imports)]
+use crate::components::raw_pair::{RawPair, StructurePair};
+#[allow(unused_imports)]
+use crate::components::routing::blocks::sort_block::SortBlock;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::sub_sort_key::SortKey;
+#[allow(unused_imports)]
+use crate::utils::x::definition_store::{DefinitionData, DefinitionStore};
+#[allow(unused_imports)]
+use crate::utils::x::definitions::define_sort::define_sort;
+#[allow(unused_imports)]
+use crate::utils::x::global_block_order::GlobalBlockOrder;
+
+fn make_block_one(all_blocks: &mut Vec<crate::components::routing::blocks::sort_block::SortBlock>) {
+    if let Err(e) = crate::components::routing::blocks::sort_block::SortBlock::create(
+        all_blocks,
+        5,
+        3,
+        "latestPosts",
+        "posts",
+        "created_at",
+        "DESC",
+    ) {
+        println!("Error: {:#?}", e);
+        return;
+    }
+
+    let mut all_keys = Vec::<SortKey>::new();
+    SortKey::create(&mut all_keys, "views", "DESC").unwrap();
+    SortKey::create(&mut all_keys, "author.name", "ASC").unwrap();
+
+    crate::components::routing::blocks::sort_block::SortBlock::set_keys(all_blocks, 5, all_keys)
+        .unwrap();
+}
+
+fn get_block_str_one() -> String {
+    "SORT (5,3) [latestPosts,posts,created_at,DESC] (views|DESC)>(author.name|ASC)".to_string()
+}
+
+fn make_post(id: &str, created_at: &str, views: &str) -> DefinitionData {
+    DefinitionData::DATA(RawPair {
+        data_id: id.to_string(),
+        structures: vec![
+            StructurePair {
+                id: "created_at".to_string(),
+                value: created_at.to_string(),
+                rtype: "STRING".to_string(),
+            },
+            StructurePair {
+                id: "views".to_string(),
+                value: views.to_string(),
+                rtype: "INTEGER".to_string(),
+            },
+        ],
+        custom_structures: vec![],
+        published: true,
+    })
+}
+
+#[test]
+pub fn run_routing_sort_one() {
+    println!("---> Running Routing Sort One");
+    // SORT (5,3) [latestPosts,posts,created_at,DESC] (views|DESC)>(author.name|ASC)
+
+    let mut all_blocks = Vec::<SortBlock>::new();
+    make_block_one(&mut all_blocks);
+
+    assert_eq!(
+        get_block_str_one(),
+        SortBlock::to_string(all_blocks[0].clone())
+    );
+}
+
+#[test]
+pub fn run_routing_sort_two() {
+    println!("---> Running Routing Sort Two");
+
+    // SortBlock {
+    //     global_index: 5,
+    //     block_index: 3,
+    //     local_name: "latestPosts",
+    //     ref_var: "posts",
+    //     ref_property: "created_at",
+    //     direction: DESC,
+    //     keys: [
+    //         SortKey {
+    //             property: "views",
+    //             direction: DESC
+    //         },
+    //         SortKey {
+    //             property: "author.name",
+    //             direction: ASC
+    //         }
+    //     ]
+    // }
+
+    let mut all_blocks = Vec::<SortBlock>::new();
+    SortBlock::from_string(&mut all_blocks, &get_block_str_one()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<SortBlock>::new();
+    make_block_one(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+}
+
+#[test]
+pub fn run_routing_sort_three() {
+    println!("---> Running Routing Sort Three");
+
+    let mut all_blocks = Vec::<SortBlock>::new();
+    make_block_one(&mut all_blocks);
+
+    let global_blocks = vec![GlobalBlockOrder {
+        index: 0,
+        block_index: 0,
+        name: String::from("FETCH"),
+        ref_name: String::from("posts"),
+    }];
+
+    let mut all_definitions = vec![DefinitionStore {
+        block_name: String::from("FETCH"),
+        ref_name: String::from("posts"),
+        index: 0,
+        data: DefinitionData::ARRAY(vec![
+            make_post("first", "2022-01-10 08:00:00 +04:00", "9"),
+            make_post("second", "2022-01-10 07:00:00 +00:00", "2"),
+            make_post("third", "2022-01-10 07:00:00 +00:00", "10"),
+        ]),
+    }];
+
+    let sorted = define_sort(
+        all_blocks[0].clone(),
+        &global_blocks,
+        &mut all_definitions,
+        0,
+    )
+    .unwrap();
+
+    assert_eq!(
+        sorted,
+        DefinitionData::ARRAY(vec![
+            make_post("third", "2022-01-10 07:00:00 +00:00", "10"),
+            make_post("second", "2022-01-10 07:00:00 +00:00", "2"),
+            make_post("first", "2022-01-10 08:00:00 +04:00", "9"),
+        ])
+    );
+}
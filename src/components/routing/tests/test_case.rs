@@ -0,0 +1,83 @@
+#[cfg(test)]
+#[allow(unused_imports)]
+use crate::components::routing::core::core_test_case::{json_contains, RouteTestCase};
+#[allow(unused_imports)]
+use serde_json::json;
+
+fn make_core_one() -> crate::components::routing::core::core_test_case::RouteTestCase {
+    crate::components::routing::core::core_test_case::RouteTestCase::create(
+        "fetch_user",
+        200,
+        "admin",
+        "/users/abc",
+        "limit=1&offset=0",
+        serde_json::json!({"name": "John Doe", "tags": ["a b", "c"]}),
+        serde_json::json!({"status": 200, "user": {"name": "John Doe"}}),
+    )
+    .unwrap()
+}
+
+fn get_core_str_one() -> String {
+    "ADD TEST case [fetch_user,200,admin] path=/users/abc params=limit=1&offset=0 body={\"name\":\"John Doe\",\"tags\":[\"a b\",\"c\"]} expect={\"status\":200,\"user\":{\"name\":\"John Doe\"}}".to_string()
+}
+
+#[test]
+pub fn run_routing_core_test_case_one() {
+    println!("---> Running Routing Core Test Case One");
+
+    let test_case = make_core_one();
+
+    assert_eq!(get_core_str_one(), RouteTestCase::to_string(test_case));
+}
+
+#[test]
+pub fn run_routing_core_test_case_two() {
+    println!("---> Running Routing Core Test Case Two");
+
+    let test_case_one = RouteTestCase::from_string(&get_core_str_one()).unwrap();
+    let test_case_two = make_core_one();
+
+    assert_eq!(test_case_two, test_case_one);
+
+    let test_case_three = RouteTestCase::from_string(
+        "ADD TEST case [empty,404,] path= params= body=null expect={\"status\":404}",
+    )
+    .unwrap();
+    assert_eq!(test_case_three.token, "");
+    assert_eq!(test_case_three.path, "");
+    assert_eq!(test_case_three.body, serde_json::Value::Null);
+
+    assert!(RouteTestCase::from_string(
+        "ADD TEST case [bad,700,] path= params= body=null expect=null"
+    )
+    .is_err());
+    assert!(RouteTestCase::from_string(
+        "ADD TEST case [bad,200,] path=users params= body=null expect=null"
+    )
+    .is_err());
+    assert!(RouteTestCase::from_string(
+        "ADD TEST case [bad,200,] path= params= body={\"a\": expect=null"
+    )
+    .is_err());
+}
+
+#[test]
+pub fn run_routing_core_test_case_three() {
+    println!("---> Running Routing Core Test Case Three");
+
+    let actual =
+        json!({"status": 200, "user": {"name": "John Doe", "age": 30}, "tags": ["a", "b"]});
+
+    assert!(json_contains(&actual, &json!({"status": 200})));
+    assert!(json_contains(
+        &actual,
+        &json!({"user": {"name": "John Doe"}})
+    ));
+    assert!(json_contains(&actual, &json!({"tags": ["a", "b"]})));
+    assert!(!json_contains(&actual, &json!({"tags": ["a"]})));
+    assert!(!json_contains(
+        &actual,
+        &json!({"user": {"name": "Jane Doe"}})
+    ));
+    assert!(!json_contains(&actual, &json!({"missing": null})));
+}
@@ -4,6 +4,12 @@ pub mod test_auth_jwt;
 #[path = "body_data.rs"]
 pub mod test_body_data;
 
+#[path = "rate_limit.rs"]
+pub mod test_rate_limit;
+
+#[path = "route_cache.rs"]
+pub mod test_route_cache;
+
 #[path = "param_data.rs"]
 pub mod test_param_data;
 
@@ -42,3 +48,24 @@ pub mod test_loop;
 
 #[path = "template.rs"]
 pub mod test_template;
+
+#[path = "delete.rs"]
+pub mod test_delete;
+
+#[path = "sort.rs"]
+pub mod test_sort;
+
+#[path = "call.rs"]
+pub mod test_call;
+
+#[path = "validator.rs"]
+pub mod test_validator;
+
+#[path = "try.rs"]
+pub mod test_try;
+
+#[path = "test_case.rs"]
+pub mod test_test_case;
+
+#[path = "flow.rs"]
+pub mod test_flow;
@@ -0,0 +1,139 @@
+#[cfg(test)]
+#[allow(unused_imports)]
+use crate::components::routing::blocks::{
+    function_block::FunctionBlock, return_block::ReturnBlock, try_block::TryBlock,
+};
+#[allow(unused_imports)]
+use crate::components::routing::mod_route::RouteComponent;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::{
+    sub_function::Function, sub_object_pair::ObjectPair, sub_ref_data::RefData,
+};
+#[allow(unused_imports)]
+use crate::utils::x::flow_processor::execute_flow;
+#[allow(unused_imports)]
+use crate::utils::x::signal_processor::Signal;
+#[allow(unused_imports)]
+use crate::utils::x::transaction::Transaction;
+#[allow(unused_imports)]
+use serde_json::{json, Map, Value};
+
+fn make_block_one(all_blocks: &mut Vec<crate::components::routing::blocks::try_block::TryBlock>) {
+    if let Err(e) = crate::components::routing::blocks::try_block::TryBlock::create(
+        all_blocks, 0, 0, "err", 1, 3,
+    ) {
+        println!("Error: {:#?}", e);
+        return;
+    }
+}
+
+fn get_block_str_one() -> String {
+    "TRY (0,0) [err,1,3]".to_string()
+}
+
+fn make_route_one(text: &str) -> RouteComponent {
+    let mut route = RouteComponent::default();
+    route.route_id = String::from("parse_payload");
+
+    make_block_one(&mut route.flow.tries);
+
+    let mut func = Function::create("JSON_PARSE");
+    Function::add_param(&mut func, RefData::create(false, "STRING", text).unwrap());
+    FunctionBlock::create(&mut route.flow.functions, 1, 0, "parsed", func).unwrap();
+
+    ReturnBlock::create(&mut route.flow.returns, 2, 0);
+    let mut all_pairs = Vec::<ObjectPair>::new();
+    let ref_data = RefData::create(false, "BOOLEAN", "true").unwrap();
+    ObjectPair::create(&mut all_pairs, "ok", ref_data).unwrap();
+    ReturnBlock::set_pairs(&mut route.flow.returns, 2, all_pairs).unwrap();
+
+    ReturnBlock::create(&mut route.flow.returns, 3, 0);
+    ReturnBlock::update_status(&mut route.flow.returns, 3, 400).unwrap();
+    let mut all_pairs = Vec::<ObjectPair>::new();
+    let ref_data = RefData::create(true, "OTHER", "err").unwrap();
+    ObjectPair::create(&mut all_pairs, "error", ref_data).unwrap();
+    ReturnBlock::set_pairs(&mut route.flow.returns, 3, all_pairs).unwrap();
+
+    route
+}
+
+fn run_route(route: &RouteComponent) -> Result<Signal, (usize, String)> {
+    let mut transaction = Transaction::default();
+
+    execute_flow(
+        route,
+        "test",
+        &Value::Object(Map::new()),
+        &vec![],
+        &vec![],
+        &vec![],
+        &mut transaction,
+        &mut None,
+        0,
+    )
+}
+
+#[test]
+pub fn run_routing_try_one() {
+    println!("---> Running Routing Try One");
+    // TRY (0,0) [err,1,3]
+
+    let mut all_blocks = Vec::<TryBlock>::new();
+    make_block_one(&mut all_blocks);
+
+    assert_eq!(
+        get_block_str_one(),
+        TryBlock::to_string(all_blocks[0].clone())
+    );
+}
+
+#[test]
+pub fn run_routing_try_two() {
+    println!("---> Running Routing Try Two");
+
+    let mut all_blocks = Vec::<TryBlock>::new();
+    TryBlock::from_string(&mut all_blocks, &get_block_str_one()).unwrap();
+
+    let mut all_blocks_duplicate = Vec::<TryBlock>::new();
+    make_block_one(&mut all_blocks_duplicate);
+
+    assert_eq!(all_blocks_duplicate[0], all_blocks[0]);
+
+    let mut invalid_blocks = Vec::<TryBlock>::new();
+    assert!(TryBlock::create(&mut invalid_blocks, 4, 0, "err", 6, 5).is_err());
+    assert_eq!(invalid_blocks.len(), 0);
+}
+
+#[test]
+pub fn run_routing_try_three() {
+    println!("---> Running Routing Try Three");
+
+    let route = make_route_one("42");
+
+    match run_route(&route) {
+        Ok(Signal::RETURN(r, status, _)) => {
+            assert_eq!(status, 200);
+            assert_eq!(r, json!({"ok": true}));
+        }
+        other => panic!("Unexpected result: {:#?}", other),
+    }
+}
+
+#[test]
+pub fn run_routing_try_four() {
+    println!("---> Running Routing Try Four");
+
+    let route = make_route_one("not json");
+
+    match run_route(&route) {
+        Ok(Signal::RETURN(r, status, _)) => {
+            assert_eq!(status, 400);
+            assert_eq!(r["error"]["structures"][0]["value"], json!(500));
+            assert_eq!(
+                r["error"]["structures"][1]["value"],
+                json!("Error: Invalid data type for the 'json' param of the JSON_PARSE function")
+            );
+        }
+        other => panic!("Unexpected result: {:#?}", other),
+    }
+}
@@ -0,0 +1,222 @@
+#[cfg(test)]
+#[allow(unused_imports)]
+use crate::components::collection::Collection;
+#[allow(unused_imports)]
+use crate::components::routing::blocks::{
+    call_block::CallBlock, condition_block::ConditionBlock, fetch_block::FetchBlock,
+    filter_block::FilterBlock, function_block::FunctionBlock,
+};
+#[allow(unused_imports)]
+use crate::components::routing::mod_route::RouteComponent;
+#[allow(unused_imports)]
+use crate::components::routing::submodules::{
+    sub_condition::Condition, sub_fail_obj::FailObj, sub_filter::Filter, sub_function::Function,
+    sub_operation_type::OperationType, sub_ref_data::RefData,
+};
+#[allow(unused_imports)]
+use crate::components::structure::Structure;
+#[allow(unused_imports)]
+use crate::utils::x::route_validator::{validate_route, Diagnostic};
+
+fn make_route_one() -> RouteComponent {
+    let mut route = RouteComponent::default();
+    route.route_id = String::from("list_posts");
+
+    FetchBlock::create(&mut route.flow.fetchers, 0, 0, "posts", "posts").unwrap();
+
+    FilterBlock::create(&mut route.flow.filters, 1, 0, "matching", "posts", "author").unwrap();
+    let mut all_filters = Vec::<Filter>::new();
+    let right = RefData::create(true, "STRING", "uid").unwrap();
+    Filter::create(&mut all_filters, right, "EQUAL_TO", false, "NONE");
+    FilterBlock::set_filters(&mut route.flow.filters, 1, all_filters).unwrap();
+
+    let func = Function::create("PAGINATE");
+    FunctionBlock::create(&mut route.flow.functions, 2, 0, "page", func).unwrap();
+
+    route
+}
+
+fn make_collections_one() -> Vec<Collection> {
+    let mut collection = Collection::default();
+    collection.id = String::from("posts");
+    collection.project_id = String::from("blog");
+
+    let mut structure = Structure::default();
+    structure.id = String::from("title");
+    collection.structures.push(structure);
+
+    vec![collection]
+}
+
+#[test]
+pub fn run_routing_validator_one() {
+    println!("---> Running Routing Validator One");
+
+    let route = make_route_one();
+    let diagnostics = validate_route(&route, "blog", &make_collections_one(), &vec![]);
+
+    assert_eq!(
+        diagnostics,
+        vec![
+            Diagnostic {
+                block_name: String::from("FILTER"),
+                global_index: 1,
+                message: String::from("Error: Referencing undefined variable 'uid'"),
+            },
+            Diagnostic {
+                block_name: String::from("FILTER"),
+                global_index: 1,
+                message: String::from(
+                    "Error: Property 'author' does not exist in collection 'posts'"
+                ),
+            },
+            Diagnostic {
+                block_name: String::from("FUNCTION"),
+                global_index: 2,
+                message: String::from(
+                    "Error: Invalid number of parameters for PAGINATE function (expected 1 to 3, found 0)"
+                ),
+            },
+        ]
+    );
+}
+
+#[test]
+pub fn run_routing_validator_two() {
+    println!("---> Running Routing Validator Two");
+
+    let route = make_route_one();
+    let diagnostics = validate_route(&route, "other_project", &make_collections_one(), &vec![]);
+
+    assert_eq!(
+        diagnostics[0],
+        Diagnostic {
+            block_name: String::from("FETCH"),
+            global_index: 0,
+            message: String::from("Error: Collection 'posts' does not exist"),
+        }
+    );
+}
+
+#[test]
+pub fn run_routing_validator_three() {
+    println!("---> Running Routing Validator Three");
+
+    let mut route = RouteComponent::default();
+    route.route_id = String::from("find_posts");
+
+    FetchBlock::create(&mut route.flow.fetchers, 0, 0, "posts", "posts").unwrap();
+    FilterBlock::create(&mut route.flow.filters, 1, 0, "matching", "posts", "title").unwrap();
+    let mut all_filters = Vec::<Filter>::new();
+    let right = RefData::create(false, "STRING", "secret").unwrap();
+    Filter::create(&mut all_filters, right, "GREATER_THAN", false, "NONE");
+    FilterBlock::set_filters(&mut route.flow.filters, 1, all_filters.clone()).unwrap();
+
+    let mut all_collections = make_collections_one();
+    assert_eq!(
+        validate_route(&route, "blog", &all_collections, &vec![]),
+        vec![]
+    );
+
+    all_collections[0].structures[0].encrypted = true;
+    assert_eq!(
+        validate_route(&route, "blog", &all_collections, &vec![]),
+        vec![Diagnostic {
+            block_name: String::from("FILTER"),
+            global_index: 1,
+            message: String::from(
+                "Error: Only equality filters are supported on encrypted property 'title'"
+            ),
+        }]
+    );
+
+    all_filters[0].operation_type = OperationType::EQUAL_TO;
+    FilterBlock::set_filters(&mut route.flow.filters, 1, all_filters).unwrap();
+    assert_eq!(
+        validate_route(&route, "blog", &all_collections, &vec![]),
+        vec![]
+    );
+}
+
+#[test]
+pub fn run_routing_validator_four() {
+    println!("---> Running Routing Validator Four");
+
+    let mut route = RouteComponent::default();
+    route.route_id = String::from("dashboard");
+    route.project_id = String::from("blog");
+    CallBlock::create(&mut route.flow.calls, 0, 0, "currentUser", "load_user").unwrap();
+
+    let mut target_route = RouteComponent::default();
+    target_route.route_id = String::from("load_user");
+    target_route.project_id = String::from("blog");
+    target_route.auth_api_key = true;
+
+    assert_eq!(
+        validate_route(
+            &route,
+            "blog",
+            &make_collections_one(),
+            &vec![target_route.clone()]
+        ),
+        vec![Diagnostic {
+            block_name: String::from("CALL"),
+            global_index: 0,
+            message: String::from(
+                "Error: Route 'load_user' requires authentication or rate limiting and cannot be called"
+            ),
+        }]
+    );
+
+    target_route.auth_api_key = false;
+    assert_eq!(
+        validate_route(&route, "blog", &make_collections_one(), &vec![target_route]),
+        vec![]
+    );
+}
+
+#[test]
+pub fn run_routing_validator_five() {
+    println!("---> Running Routing Validator Five");
+
+    let mut route = RouteComponent::default();
+    route.route_id = String::from("check_price");
+
+    let fail_obj = FailObj::create(400, "Error: Unexpected price").unwrap();
+    ConditionBlock::create(&mut route.flow.conditions, 0, 0, "FAIL", Some(fail_obj)).unwrap();
+
+    let mut all_conditions = Vec::<Condition>::new();
+    let left = RefData::create(false, "INTEGER", "10").unwrap();
+    let right = RefData::create(false, "FLOAT", "10.0").unwrap();
+    Condition::create(
+        &mut all_conditions,
+        left,
+        right,
+        "NOT_EQUAL_TO",
+        false,
+        "NONE",
+    );
+    ConditionBlock::set_conditions(&mut route.flow.conditions, 0, all_conditions).unwrap();
+
+    assert_eq!(
+        validate_route(&route, "blog", &make_collections_one(), &vec![]),
+        vec![]
+    );
+
+    let mut all_conditions = Vec::<Condition>::new();
+    let left = RefData::create(false, "INTEGER", "10").unwrap();
+    let right = RefData::create(false, "BOOLEAN", "true").unwrap();
+    Condition::create(&mut all_conditions, left, right, "EQUAL_TO", false, "NONE");
+    ConditionBlock::set_conditions(&mut route.flow.conditions, 0, all_conditions).unwrap();
+
+    assert_eq!(
+        validate_route(&route, "blog", &make_collections_one(), &vec![]),
+        vec![Diagnostic {
+            block_name: String::from("CONDITION"),
+            global_index: 0,
+            message: String::from(
+                "Error: Cannot compare INTEGER '10' and BOOLEAN 'true' with EQUAL_TO"
+            ),
+        }]
+    );
+}
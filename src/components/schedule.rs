@@ -0,0 +1,417 @@
+#![allow(non_camel_case_types)]
+
+use std::fmt;
+
+use crate::components::{
+    encryption::EncryptionKey,
+    io::{fetch_file, save_file},
+};
+use chrono::prelude::*;
+use rocket::serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::cron::CronExpression;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum CatchUpPolicy {
+    SKIP,
+    ONCE,
+    ALL,
+}
+
+impl Default for CatchUpPolicy {
+    fn default() -> Self {
+        CatchUpPolicy::SKIP
+    }
+}
+
+impl fmt::Display for CatchUpPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let policy_txt = match self {
+            CatchUpPolicy::SKIP => "SKIP".to_string(),
+            CatchUpPolicy::ONCE => "ONCE".to_string(),
+            CatchUpPolicy::ALL => "ALL".to_string(),
+        };
+
+        write!(f, "{}", policy_txt)
+    }
+}
+
+impl CatchUpPolicy {
+    pub fn to(policy: CatchUpPolicy) -> String {
+        return match policy.clone() {
+            CatchUpPolicy::SKIP => "SKIP".to_string(),
+            CatchUpPolicy::ONCE => "ONCE".to_string(),
+            CatchUpPolicy::ALL => "ALL".to_string(),
+        };
+    }
+
+    pub fn from(policy_txt: &str) -> CatchUpPolicy {
+        return match policy_txt.trim().to_uppercase().as_str() {
+            "SKIP" => CatchUpPolicy::SKIP,
+            "ONCE" => CatchUpPolicy::ONCE,
+            "ALL" => CatchUpPolicy::ALL,
+            _ => CatchUpPolicy::SKIP,
+        };
+    }
+}
+
+#[derive(Default, Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Schedule {
+    pub id: String,
+    pub route_id: String,
+    pub cron: String,
+    pub catch_up: CatchUpPolicy,
+    pub last_run: i64,
+    pub body: String,
+}
+
+impl Schedule {
+    fn create_no_check(
+        id: &str,
+        route_id: &str,
+        cron: &str,
+        catch_up: CatchUpPolicy,
+        last_run: i64,
+        body: &str,
+    ) -> Schedule {
+        Schedule {
+            id: id.trim().to_string(),
+            route_id: route_id.trim().to_string(),
+            cron: cron.trim().to_string(),
+            catch_up: catch_up,
+            last_run: last_run,
+            body: body.trim().to_string(),
+        }
+    }
+
+    pub fn create(
+        all_schedules: &mut Vec<Schedule>,
+        route_id: &str,
+        cron: &str,
+        catch_up: CatchUpPolicy,
+        body: &str,
+    ) -> Result<String, (usize, String)> {
+        let new_id = EncryptionKey::generate_uuid(16);
+
+        let mut has_error: bool = false;
+        let mut latest_error: (usize, String) = (500, String::new());
+
+        let new_schedule = Schedule {
+            id: new_id.clone(),
+            route_id: "".to_string(),
+            cron: "".to_string(),
+            catch_up: catch_up,
+            last_run: Utc::now().timestamp(),
+            body: "{}".to_string(),
+        };
+        all_schedules.push(new_schedule);
+
+        if !has_error {
+            let route_id_update = Self::update_route_id(all_schedules, &new_id, route_id);
+            if let Err(e) = route_id_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if !has_error {
+            let cron_update = Self::update_cron(all_schedules, &new_id, cron);
+            if let Err(e) = cron_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if !has_error {
+            let body_update = Self::update_body(all_schedules, &new_id, body);
+            if let Err(e) = body_update {
+                has_error = true;
+                println!("{}", e.1);
+                latest_error = e;
+            }
+        }
+
+        if has_error {
+            let delete_schedule = Self::delete(all_schedules, &new_id);
+            if let Err(e) = delete_schedule {
+                println!("{}", e.1);
+            }
+
+            return Err(latest_error);
+        }
+
+        Ok(new_id)
+    }
+
+    pub fn exist(all_schedules: &Vec<Schedule>, id: &str) -> bool {
+        let mut found = false;
+        for schedule in all_schedules.iter() {
+            if schedule.id.to_lowercase() == id.to_lowercase() {
+                found = true;
+                break;
+            }
+        }
+
+        found
+    }
+
+    pub fn get(all_schedules: &Vec<Schedule>, id: &str) -> Result<Schedule, (usize, String)> {
+        for schedule in all_schedules.iter() {
+            if schedule.id.to_lowercase() == id.to_lowercase() {
+                return Ok(schedule.clone());
+            }
+        }
+
+        Err((404, String::from("Error: Schedule not found")))
+    }
+
+    pub fn update_route_id(
+        all_schedules: &mut Vec<Schedule>,
+        id: &String,
+        route_id: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut found_schedule: Option<Schedule> = None;
+
+        if route_id.trim().len() < 1 {
+            return Err((400, String::from("Error: route_id should not be empty")));
+        }
+
+        if route_id.contains(";") || route_id.trim().contains(" ") {
+            return Err((
+                400,
+                String::from("Error: route_id contains an invalid character"),
+            ));
+        }
+
+        for schedule in all_schedules.iter_mut() {
+            if schedule.id == *id {
+                found_schedule = Some(schedule.clone());
+                schedule.route_id = route_id.trim().to_string();
+                break;
+            }
+        }
+
+        if let None = found_schedule {
+            return Err((404, String::from("Error: Schedule not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_cron(
+        all_schedules: &mut Vec<Schedule>,
+        id: &String,
+        cron: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut found_schedule: Option<Schedule> = None;
+
+        if let Err(e) = CronExpression::parse(cron) {
+            return Err(e);
+        }
+
+        for schedule in all_schedules.iter_mut() {
+            if schedule.id == *id {
+                found_schedule = Some(schedule.clone());
+                schedule.cron = cron.split_whitespace().collect::<Vec<&str>>().join(" ");
+                break;
+            }
+        }
+
+        if let None = found_schedule {
+            return Err((404, String::from("Error: Schedule not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_catch_up(
+        all_schedules: &mut Vec<Schedule>,
+        id: &String,
+        catch_up: CatchUpPolicy,
+    ) -> Result<(), (usize, String)> {
+        let mut found_schedule: Option<Schedule> = None;
+
+        for schedule in all_schedules.iter_mut() {
+            if schedule.id == *id {
+                found_schedule = Some(schedule.clone());
+                schedule.catch_up = catch_up;
+                break;
+            }
+        }
+
+        if let None = found_schedule {
+            return Err((404, String::from("Error: Schedule not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_last_run(
+        all_schedules: &mut Vec<Schedule>,
+        id: &String,
+        last_run: i64,
+    ) -> Result<(), (usize, String)> {
+        let mut found_schedule: Option<Schedule> = None;
+
+        for schedule in all_schedules.iter_mut() {
+            if schedule.id == *id {
+                found_schedule = Some(schedule.clone());
+                schedule.last_run = last_run;
+                break;
+            }
+        }
+
+        if let None = found_schedule {
+            return Err((404, String::from("Error: Schedule not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn update_body(
+        all_schedules: &mut Vec<Schedule>,
+        id: &String,
+        body: &str,
+    ) -> Result<(), (usize, String)> {
+        let mut found_schedule: Option<Schedule> = None;
+
+        let final_value = match serde_json::from_str::<Value>(body) {
+            Ok(v) if v.is_object() => v.to_string(),
+            _ => {
+                return Err((
+                    400,
+                    String::from("Error: body should be a valid JSON object"),
+                ))
+            }
+        };
+
+        for schedule in all_schedules.iter_mut() {
+            if schedule.id == *id {
+                found_schedule = Some(schedule.clone());
+                schedule.body = final_value;
+                break;
+            }
+        }
+
+        if let None = found_schedule {
+            return Err((404, String::from("Error: Schedule not found")));
+        }
+
+        Ok(())
+    }
+
+    pub fn delete(all_schedules: &mut Vec<Schedule>, id: &str) -> Result<(), (usize, String)> {
+        let mut found_schedule: Option<Schedule> = None;
+
+        for schedule in all_schedules.iter_mut() {
+            if schedule.id == id.to_string() {
+                found_schedule = Some(schedule.clone());
+                break;
+            }
+        }
+
+        if let None = found_schedule {
+            return Err((404, String::from("Error: Schedule not found")));
+        }
+
+        let updated_schedules: Vec<Schedule> = all_schedules
+            .iter_mut()
+            .filter(|schedule| schedule.id != *id)
+            .map(|schedule| schedule.clone())
+            .collect::<Vec<Schedule>>();
+
+        *all_schedules = updated_schedules;
+
+        Ok(())
+    }
+
+    pub fn delete_by_route(all_schedules: &mut Vec<Schedule>, route_id: &str) {
+        all_schedules.retain(|schedule| schedule.route_id != route_id);
+    }
+
+    pub fn obtain_properties() -> String {
+        String::from("id;route_id;cron;catch_up;last_run;body")
+    }
+
+    pub fn to_string(schedule: Schedule) -> String {
+        format!(
+            "{};{};{};{};{};{}",
+            schedule.id,
+            schedule.route_id,
+            schedule.cron,
+            CatchUpPolicy::to(schedule.catch_up),
+            schedule.last_run,
+            schedule
+                .body
+                .split("\n")
+                .collect::<Vec<&str>>()
+                .join("_newline_"),
+        )
+    }
+
+    pub fn from_string(schedule_str: &str) -> Schedule {
+        let current_schedule = schedule_str.split(";").collect::<Vec<&str>>();
+
+        Schedule::create_no_check(
+            current_schedule[0],
+            current_schedule[1],
+            current_schedule[2],
+            CatchUpPolicy::from(current_schedule[3]),
+            current_schedule[4].trim().parse::<i64>().unwrap_or(0),
+            &current_schedule[5..]
+                .join(";")
+                .split("_newline_")
+                .collect::<Vec<&str>>()
+                .join("\n"),
+        )
+    }
+}
+
+pub fn stringify_schedules(schedules: &Vec<Schedule>) -> String {
+    let mut stringified_schedules = String::new();
+
+    for schedule in schedules {
+        stringified_schedules = format!(
+            "{}{}{}",
+            stringified_schedules,
+            if stringified_schedules.chars().count() > 1 {
+                "\n"
+            } else {
+                ""
+            },
+            Schedule::to_string(schedule.clone()),
+        );
+    }
+
+    stringified_schedules
+}
+
+pub fn unwrap_schedules(all_schedules_raw: String) -> Vec<Schedule> {
+    let individual_schedules = all_schedules_raw
+        .split("\n")
+        .filter(|line| line.split(";").count() >= 6);
+
+    let mut final_schedules: Vec<Schedule> = Vec::<Schedule>::new();
+
+    for schedule in individual_schedules {
+        let tmp_schedule = Schedule::from_string(schedule);
+        final_schedules.push(tmp_schedule);
+    }
+
+    final_schedules
+}
+
+pub fn fetch_all_schedules(path: String, encryption_key: &String) -> Vec<Schedule> {
+    let all_schedules_raw = fetch_file(path.clone(), encryption_key);
+    let final_schedules = unwrap_schedules(all_schedules_raw);
+    final_schedules
+}
+
+pub fn save_all_schedules(schedules: &Vec<Schedule>, path: String, encryption_key: &String) {
+    let stringified_schedules = stringify_schedules(schedules);
+    save_file(path, stringified_schedules, encryption_key);
+    println!("Schedules saved!");
+}
@@ -49,6 +49,8 @@ pub fn initialize_configs(mappings: &Vec<Mapping>) -> Vec<Config> {
         "UPLOAD_SIZE",
         "SHOULD_INITIALIZE",
         "CORS_WHITELIST",
+        "STORAGE_BACKEND",
+        "FIELD_ENCRYPTION_MODE",
     ];
 
     for key in config_keys_template {
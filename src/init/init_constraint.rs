@@ -16,6 +16,12 @@ pub fn initialize_constraints(mappings: &Vec<Mapping>) -> Vec<Constraint> {
         _ => "Test123*".to_string(),
     };
 
+    let route_path_allowed = vec!['-', '_', '/', ':'];
+    let ref_data_allowed = vec![
+        '-', '_', ':', ';', ' ', '.', '^', '$', '*', '+', '?', '\\', '[', ']', '(', ')', '|', '{',
+        '}',
+    ];
+
     if let Err(e) = all_constraints_path.clone() {
         println!("{}", e);
         // return all_constraints;
@@ -672,7 +678,7 @@ pub fn initialize_constraints(mappings: &Vec<Mapping>) -> Vec<Constraint> {
             1,
             200,
             vec![],
-            vec!['-', '_', '/'],
+            route_path_allowed.clone(),
         ) {
             println!("{}", e.1);
         }
@@ -956,6 +962,58 @@ pub fn initialize_constraints(mappings: &Vec<Mapping>) -> Vec<Constraint> {
         }
     }
 
+    if !Constraint::exist(&all_constraints, "sort_block") {
+        if let Err(e) = Constraint::create(&mut all_constraints, "sort_block") {
+            println!("{}", e.1);
+        }
+
+        let mut all_properties = Vec::<ConstraintProperty>::new();
+        if let Err(e) = ConstraintProperty::create(
+            &mut all_properties,
+            "local_name",
+            true,
+            true,
+            1,
+            100,
+            vec![],
+            vec!['-', '_'],
+        ) {
+            println!("{}", e.1);
+        }
+
+        if let Err(e) = ConstraintProperty::create(
+            &mut all_properties,
+            "ref_var",
+            true,
+            true,
+            1,
+            100,
+            vec![],
+            vec!['-', '_'],
+        ) {
+            println!("{}", e.1);
+        }
+
+        if let Err(e) = ConstraintProperty::create(
+            &mut all_properties,
+            "ref_property",
+            true,
+            true,
+            1,
+            100,
+            vec![],
+            vec!['-', '_', '.'],
+        ) {
+            println!("{}", e.1);
+        }
+
+        if let Err(e) =
+            Constraint::set_properties(&mut all_constraints, "sort_block", all_properties)
+        {
+            println!("{}", e.1);
+        }
+    }
+
     if !Constraint::exist(&all_constraints, "function_block") {
         if let Err(e) = Constraint::create(&mut all_constraints, "function_block") {
             println!("{}", e.1);
@@ -1138,6 +1196,97 @@ pub fn initialize_constraints(mappings: &Vec<Mapping>) -> Vec<Constraint> {
         }
     }
 
+    if !Constraint::exist(&all_constraints, "delete_block") {
+        if let Err(e) = Constraint::create(&mut all_constraints, "delete_block") {
+            println!("{}", e.1);
+        }
+
+        let mut all_properties = Vec::<ConstraintProperty>::new();
+        if let Err(e) = ConstraintProperty::create(
+            &mut all_properties,
+            "ref_col",
+            true,
+            true,
+            1,
+            100,
+            vec![],
+            vec!['-', '_'],
+        ) {
+            println!("{}", e.1);
+        }
+
+        if let Err(e) =
+            Constraint::set_properties(&mut all_constraints, "delete_block", all_properties)
+        {
+            println!("{}", e.1);
+        }
+    }
+
+    if !Constraint::exist(&all_constraints, "call_block") {
+        if let Err(e) = Constraint::create(&mut all_constraints, "call_block") {
+            println!("{}", e.1);
+        }
+
+        let mut all_properties = Vec::<ConstraintProperty>::new();
+        if let Err(e) = ConstraintProperty::create(
+            &mut all_properties,
+            "local_name",
+            true,
+            true,
+            1,
+            100,
+            vec![],
+            vec!['-', '_'],
+        ) {
+            println!("{}", e.1);
+        }
+
+        if let Err(e) = ConstraintProperty::create(
+            &mut all_properties,
+            "route_id",
+            true,
+            true,
+            1,
+            100,
+            vec![],
+            vec!['-', '_'],
+        ) {
+            println!("{}", e.1);
+        }
+
+        if let Err(e) =
+            Constraint::set_properties(&mut all_constraints, "call_block", all_properties)
+        {
+            println!("{}", e.1);
+        }
+    }
+
+    if !Constraint::exist(&all_constraints, "try_block") {
+        if let Err(e) = Constraint::create(&mut all_constraints, "try_block") {
+            println!("{}", e.1);
+        }
+
+        let mut all_properties = Vec::<ConstraintProperty>::new();
+        if let Err(e) = ConstraintProperty::create(
+            &mut all_properties,
+            "local_name",
+            true,
+            true,
+            1,
+            100,
+            vec![],
+            vec!['-', '_'],
+        ) {
+            println!("{}", e.1);
+        }
+
+        if let Err(e) =
+            Constraint::set_properties(&mut all_constraints, "try_block", all_properties)
+        {
+            println!("{}", e.1);
+        }
+    }
+
     if !Constraint::exist(&all_constraints, "fail_obj") {
         if let Err(e) = Constraint::create(&mut all_constraints, "fail_obj") {
             println!("{}", e.1);
@@ -1228,7 +1377,7 @@ pub fn initialize_constraints(mappings: &Vec<Mapping>) -> Vec<Constraint> {
             0,
             200,
             vec![],
-            vec!['-', '_', ':', ';', ' ', '.'],
+            ref_data_allowed.clone(),
         ) {
             println!("{}", e.1);
         }
@@ -1265,6 +1414,27 @@ pub fn initialize_constraints(mappings: &Vec<Mapping>) -> Vec<Constraint> {
         }
     }
 
+    migrate_additional_allowed(
+        &mut all_constraints,
+        "route_component",
+        "route_path",
+        &route_path_allowed,
+    );
+    migrate_additional_allowed(&mut all_constraints, "ref_data", "data", &ref_data_allowed);
+    migrate_missing_property(
+        &mut all_constraints,
+        "route_component",
+        ConstraintProperty {
+            property_name: String::from("method"),
+            is_alphabetic: true,
+            is_numeric: false,
+            min: 3,
+            max: 6,
+            not_allowed: vec![],
+            additional_allowed: vec![],
+        },
+    );
+
     if let Ok(path) = all_constraints_path {
         save_all_constraints(
             &all_constraints,
@@ -1275,3 +1445,64 @@ pub fn initialize_constraints(mappings: &Vec<Mapping>) -> Vec<Constraint> {
 
     all_constraints
 }
+
+fn migrate_additional_allowed(
+    all_constraints: &mut Vec<Constraint>,
+    component_name: &str,
+    property_name: &str,
+    additional_allowed: &Vec<char>,
+) {
+    let constraint = match Constraint::get(all_constraints, component_name) {
+        Ok(c) => c,
+        Err(e) => {
+            println!("{}", e.1);
+            return;
+        }
+    };
+
+    let mut property = match ConstraintProperty::get(&constraint.properties, property_name) {
+        Ok(p) => p,
+        Err(e) => {
+            println!("{}", e.1);
+            return;
+        }
+    };
+
+    let missing = additional_allowed
+        .iter()
+        .filter(|c| !property.additional_allowed.contains(c))
+        .map(|c| *c)
+        .collect::<Vec<char>>();
+    if missing.len() < 1 {
+        return;
+    }
+
+    property.additional_allowed.extend(missing);
+    if let Err(e) =
+        Constraint::update_property(all_constraints, component_name, property_name, property)
+    {
+        println!("{}", e.1);
+    }
+}
+
+fn migrate_missing_property(
+    all_constraints: &mut Vec<Constraint>,
+    component_name: &str,
+    property: ConstraintProperty,
+) {
+    let constraint = match Constraint::get(all_constraints, component_name) {
+        Ok(c) => c,
+        Err(e) => {
+            println!("{}", e.1);
+            return;
+        }
+    };
+
+    if ConstraintProperty::exist(&constraint.properties, &property.property_name) {
+        return;
+    }
+
+    if let Err(e) = Constraint::add_property(all_constraints, component_name, property) {
+        println!("{}", e.1);
+    }
+}
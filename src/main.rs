@@ -36,12 +36,17 @@ use init::constraint::initialize_constraints;
 use rocket_cors::{AllowedHeaders, AllowedOrigins};
 use std::collections::HashMap;
 use utils::{
-    config::get_config_value, io::auto_reset_locks, mapping::auto_fetch_all_mappings,
+    config::get_config_value,
+    data::{auto_recover_data_commits, start_data_compaction},
+    io::auto_reset_locks,
+    mapping::auto_fetch_all_mappings,
     redis::init_redis,
+    scheduler::start_scheduler,
 };
 
 use rocket::{
     catchers,
+    fairing::AdHoc,
     fs::{relative, FileServer},
     get,
     http::Method,
@@ -74,6 +79,9 @@ fn rocket() -> _ {
     println!("\nResetting RW locks");
     auto_reset_locks();
 
+    println!("Recovering interrupted data commits");
+    auto_recover_data_commits();
+
     // let allowed_origins = AllowedOrigins::some_exact(&["https://www.acme.com"]);
     let allowed_origins = AllowedOrigins::all();
 
@@ -209,6 +217,24 @@ fn rocket() -> _ {
                 routes::routing::convert_blocks::main,
                 routes::routing::convert_kdl::main,
                 routes::routing::delete::main,
+                routes::routing::trace::main,
+                routes::routing::test::main,
+            ],
+        )
+        .mount(
+            fpath("/schedule"),
+            routes![
+                routes::schedule::fetch_all::main,
+                routes::schedule::create::main,
+                routes::schedule::delete::main,
+            ],
+        )
+        .mount(
+            fpath("/api_key"),
+            routes![
+                routes::api_key::fetch_all::main,
+                routes::api_key::create::main,
+                routes::api_key::delete::main,
             ],
         )
         .mount(
@@ -220,12 +246,23 @@ fn rocket() -> _ {
             ],
         )
         .mount(fpath("/repl"), routes![routes::repl::repl_process::main,])
-        .mount(fpath("/x"), routes![routes::x::x::main])
+        .mount(
+            fpath("/x"),
+            routes![
+                routes::x::x::main_get,
+                routes::x::x::main,
+                routes::x::x::main_put,
+                routes::x::x::main_patch,
+                routes::x::x::main_delete,
+            ],
+        )
         .mount(
             fpath("/misc"),
             routes![
                 routes::misc::test_mongo::main,
-                routes::misc::test_smtp::main
+                routes::misc::test_smtp::main,
+                routes::misc::locks::main,
+                routes::misc::rotate_key::main
             ],
         )
         .register(
@@ -240,6 +277,16 @@ fn rocket() -> _ {
         )
         .attach(Template::fairing())
         .attach(cors.clone())
+        .attach(AdHoc::on_liftoff("Scheduler", |_| {
+            Box::pin(async move {
+                rocket::tokio::spawn(start_scheduler());
+            })
+        }))
+        .attach(AdHoc::on_liftoff("Data Compaction", |_| {
+            Box::pin(async move {
+                rocket::tokio::spawn(start_data_compaction());
+            })
+        }))
         .manage(init_redis())
         .manage(cors)
 }
@@ -8,10 +8,17 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
 use crate::{
-    components::{collection::Collection, mapping::Mapping, user::User},
+    components::{
+        api_key::ApiKey, collection::Collection, data::Data, mapping::Mapping, user::User,
+    },
     utils::{
-        collection::auto_fetch_all_collections, config::get_config_value,
-        data::auto_fetch_all_data, mapping::auto_fetch_all_mappings, user::auto_fetch_all_users,
+        api_key::auto_fetch_all_api_keys,
+        collection::auto_fetch_all_collections,
+        config::get_config_value,
+        data::auto_fetch_all_data,
+        field_encryption::{auto_fetch_data_key, has_encrypted_data, reveal_data},
+        mapping::auto_fetch_all_mappings,
+        user::auto_fetch_all_users,
     },
 };
 
@@ -43,6 +50,22 @@ impl<'r> FromRequest<'r> for Token {
     }
 }
 
+pub struct ApiKeyHeader(pub String);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ApiKeyHeader {
+    type Error = ApiTokenError;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let api_key = match req.headers().get_one("x-api-key") {
+            Some(key) => key.to_string(),
+            None => String::new(),
+        };
+
+        Outcome::Success(ApiKeyHeader(api_key))
+    }
+}
+
 impl Display for Token {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -118,20 +141,69 @@ pub async fn verify_jwt(payload: String, token: String) -> Result<String, (usize
     Ok(String::from("Successfully Authenticated!"))
 }
 
+pub fn decode_jwt_payload(mappings: &Vec<Mapping>, token: &str) -> Result<String, (usize, String)> {
+    let secret = get_config_value(mappings, "TOKEN_KEY", "secret");
+
+    let mut proper_token = "";
+    for v in token.split(" ") {
+        proper_token = v;
+    }
+
+    match decode::<Claims>(
+        &proper_token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::new(Algorithm::HS512),
+    ) {
+        Ok(dec) => Ok(dec.claims.payload),
+        Err(e) => Err((
+            500,
+            format!("{} ({})", String::from("Error: Failed decoding JWT"), e),
+        )),
+    }
+}
+
+pub fn verify_api_key(project_id: &str, api_key: &str) -> Result<ApiKey, (usize, String)> {
+    if api_key.trim().len() < 1 {
+        return Err((401, String::from("Error: No API key provided")));
+    }
+
+    let mappings = auto_fetch_all_mappings();
+    let all_keys = match auto_fetch_all_api_keys(&mappings, project_id) {
+        Ok(k) => k,
+        _ => {
+            return Err((500, String::from("Error: Failed fetching API keys")));
+        }
+    };
+
+    match ApiKey::verify(&all_keys, api_key) {
+        Some(k) => Ok(k),
+        None => Err((401, String::from("Error: Invalid API key"))),
+    }
+}
+
 pub async fn verify_jwt_x(
-    payload: String,
+    payload: Option<String>,
     token: String,
     project_id: &str,
     ref_col: &str,
     field: &str,
-) -> Result<String, (usize, String)> {
+) -> Result<Data, (usize, String)> {
     let mappings = auto_fetch_all_mappings();
-    let secret = get_config_value(&mappings, "TOKEN_KEY", "secret");
 
     if ref_col.trim().len() < 1 || field.trim().len() < 1 {
         return Err((500, String::from("Error: Invalid field or ref_col")));
     }
 
+    let decoded_payload = match decode_jwt_payload(&mappings, &token) {
+        Ok(p) => p,
+        Err(e) => return Err(e),
+    };
+
+    let payload = match payload {
+        Some(p) => p,
+        None => decoded_payload.clone(),
+    };
+
     let all_collections = match auto_fetch_all_collections(&mappings) {
         Ok(u) => u,
         _ => {
@@ -164,53 +236,39 @@ pub async fn verify_jwt_x(
         ));
     }
 
-    let all_data = match auto_fetch_all_data(&mappings, &project_id, ref_col) {
+    let mut all_data = match auto_fetch_all_data(&mappings, &project_id, ref_col) {
         Ok(d) => d,
         _ => {
             return Err((500, String::from("Error: Failed fetching data")));
         }
     };
 
-    let mut found = false;
+    if has_encrypted_data(&all_data) {
+        reveal_data(&mut all_data, &auto_fetch_data_key(&mappings, project_id));
+    }
+
+    let mut found: Option<Data> = None;
     for d in all_data {
-        for pair in d.pairs {
+        for pair in d.pairs.iter() {
             if pair.structure_id == field && pair.value == payload {
-                found = true;
+                found = Some(d.clone());
                 break;
             }
         }
 
-        if found {
+        if found.is_some() {
             break;
         }
     }
 
-    if !found {
-        return Err((404, format!("Error: Data with this {} not found", field)));
-    }
-
-    let mut proper_token = "";
-    for v in token.split(" ") {
-        proper_token = v;
-    }
-
-    let decoded = match decode::<Claims>(
-        &proper_token,
-        &DecodingKey::from_secret(secret.as_bytes()),
-        &Validation::new(Algorithm::HS512),
-    ) {
-        Ok(dec) => dec,
-        Err(e) => {
-            return Err((
-                500,
-                format!("{} ({})", String::from("Error: Failed decoding JWT"), e),
-            ));
-        }
+    let record = match found {
+        Some(d) => d,
+        None => return Err((404, format!("Error: Data with this {} not found", field))),
     };
 
-    if decoded.claims.payload != payload {
+    if decoded_payload != payload {
         return Err((403, format!("Error: Incorrect {}", field)));
     }
 
-    Ok(String::from("Successfully Authenticated!"))
+    Ok(record)
 }
@@ -0,0 +1,111 @@
+use rocket::post;
+use rocket::serde::json::{json, Json, Value};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::components::api_key::ApiKey;
+use crate::components::encryption::EncryptionKey;
+use crate::components::project::Project;
+use crate::components::user::{Role, User};
+use crate::middlewares::token::{verify_jwt, Token};
+use crate::utils::{
+    api_key::auto_fetch_all_api_keys, api_key::auto_save_all_api_keys, event::auto_create_event,
+    mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects, user::auto_fetch_all_users,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CreateApiKeyInput {
+    uid: String,
+    project_id: String,
+    name: String,
+    hashed: bool,
+}
+
+#[post("/create", format = "json", data = "<data>")]
+pub async fn main(data: Json<CreateApiKeyInput>, token: Token) -> Value {
+    let uid = &data.uid;
+    let project_id = &data.project_id;
+
+    match verify_jwt(uid.clone(), token.0).await {
+        Err(info) => return json!({"status": info.0, "message": info.1}),
+        _ => {}
+    };
+
+    let mappings = auto_fetch_all_mappings();
+    let mut all_keys = match auto_fetch_all_api_keys(&mappings, &project_id) {
+        Ok(k) => k,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching API keys"});
+        }
+    };
+
+    let users = match auto_fetch_all_users(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching users"});
+        }
+    };
+
+    let current_user = User::get(&users, uid).unwrap();
+
+    let all_projects = match auto_fetch_all_projects(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching projects"});
+        }
+    };
+
+    let project = match Project::get(&all_projects, project_id) {
+        Ok(p) => p,
+        Err(_) => {
+            return json!({"status": 404, "message": "Error: No Project with this project_id found"})
+        }
+    };
+
+    let members = project.members.clone();
+    let mut allowed = false;
+
+    if current_user.role != Role::ROOT {
+        if current_user.role == Role::ADMIN {
+            for member in members {
+                if member.to_lowercase() == uid.to_string() {
+                    allowed = true;
+                    break;
+                }
+            }
+        }
+    } else {
+        allowed = true;
+    }
+
+    if !allowed {
+        return json!({"status": 403, "message": "Error: Not authorized to create API keys for this Project"});
+    }
+
+    let key = EncryptionKey::generate_uuid(8);
+    let key_id = match ApiKey::create(&mut all_keys, &data.name, &key, data.hashed) {
+        Ok(id) => id,
+        Err(e) => return json!({"status": e.0, "message": e.1}),
+    };
+
+    if let Err(e) = auto_create_event(
+        &mappings,
+        "api_key_create",
+        format!(
+            "An API key with id <{}> was created under pro[{}] by usr[{}]",
+            key_id, project_id, uid
+        ),
+        format!("/project/{}", project_id),
+    ) {
+        return json!({"status": e.0, "message": e.1});
+    }
+
+    match auto_save_all_api_keys(&mappings, &project_id, &all_keys) {
+        Ok(_) => {
+            return json!({"status": 200, "message": "API key successfully created!", "key_id": key_id, "key": key})
+        }
+        Err(e) => {
+            json!({"status": 500, "message": e})
+        }
+    }
+}
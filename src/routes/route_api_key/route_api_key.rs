@@ -0,0 +1,8 @@
+#[path = "api_key_fetch_all.rs"]
+pub mod fetch_all;
+
+#[path = "api_key_create.rs"]
+pub mod create;
+
+#[path = "api_key_delete.rs"]
+pub mod delete;
@@ -3,14 +3,16 @@ use rocket::serde::json::{json, Json, Value};
 use rocket::serde::{Deserialize, Serialize};
 
 use crate::components::collection::Collection;
+use crate::components::data::Data;
+use crate::components::data_log::{LogEntry, LogOperation};
 use crate::components::project::Project;
 use crate::components::raw_pair::RawPair;
 use crate::components::user::{Role, User};
 use crate::middlewares::token::{verify_jwt, Token};
 use crate::utils::{
-    collection::auto_fetch_all_collections, data::auto_fetch_all_data, data::auto_save_all_data,
-    event::auto_create_event, mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
-    user::auto_fetch_all_users,
+    collection::auto_fetch_all_collections, data::auto_fetch_all_data,
+    data::auto_save_data_entries, event::auto_create_event, mapping::auto_fetch_all_mappings,
+    project::auto_fetch_all_projects, user::auto_fetch_all_users,
 };
 
 use crate::utils::x::convertors::convert_rawpair_to_data::rawpair_to_data;
@@ -106,6 +108,11 @@ pub async fn main(data: Json<CreateDataInput>, token: Token) -> Value {
         Err(e) => return json!({"status": e.0, "message": e.1}),
     };
 
+    let entries = match Data::get(&all_data, project_id, collection_id, &data_id) {
+        Ok(d) => vec![LogEntry::new(LogOperation::CREATE, &data_id, Some(d))],
+        Err(e) => return json!({"status": e.0, "message": e.1}),
+    };
+
     if let Err(e) = auto_create_event(
         &mappings,
         "data_create",
@@ -118,7 +125,7 @@ pub async fn main(data: Json<CreateDataInput>, token: Token) -> Value {
         return json!({"status": e.0, "message": e.1});
     }
 
-    match auto_save_all_data(&mappings, &project_id, &collection_id, &all_data) {
+    match auto_save_data_entries(&mappings, &project_id, &collection_id, &all_data, &entries) {
         Ok(_) => {
             return json!({"status": 200, "message": "Data successfully created!", "data_id": data_id})
         }
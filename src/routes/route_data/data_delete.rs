@@ -3,13 +3,14 @@ use rocket::serde::json::{json, Value};
 
 use crate::components::collection::Collection;
 use crate::components::data::Data;
+use crate::components::data_log::{LogEntry, LogOperation};
 use crate::components::project::Project;
 use crate::components::user::{Role, User};
 use crate::middlewares::token::{verify_jwt, Token};
 use crate::utils::{
-    collection::auto_fetch_all_collections, data::auto_fetch_all_data, data::auto_save_all_data,
-    event::auto_create_event, mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
-    user::auto_fetch_all_users,
+    collection::auto_fetch_all_collections, data::auto_fetch_all_data,
+    data::auto_save_data_entries, event::auto_create_event, mapping::auto_fetch_all_mappings,
+    project::auto_fetch_all_projects, user::auto_fetch_all_users,
 };
 
 #[delete("/delete?<uid>&<project_id>&<collection_id>&<data_id>")]
@@ -124,11 +125,12 @@ pub async fn main(
         return json!({"status": e.0, "message": e.1});
     }
 
-    match auto_save_all_data(
+    match auto_save_data_entries(
         &mappings,
         &passed_project_id,
         &passed_collection_id,
         &all_data,
+        &vec![LogEntry::new(LogOperation::DELETE, &passed_data_id, None)],
     ) {
         Ok(_) => return json!({"status": 200, "message": "Data successfully deleted!"}),
         Err(e) => {
@@ -9,8 +9,12 @@ use crate::components::user::{Role, User};
 use crate::middlewares::paginate::paginate;
 use crate::middlewares::token::{verify_jwt, Token};
 use crate::utils::{
-    collection::auto_fetch_all_collections, data::auto_fetch_all_data,
-    mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects, user::auto_fetch_all_users,
+    collection::auto_fetch_all_collections,
+    data::auto_fetch_all_data,
+    field_encryption::{get_reveal_key, reveal_rawpair},
+    mapping::auto_fetch_all_mappings,
+    project::auto_fetch_all_projects,
+    user::auto_fetch_all_users,
 };
 
 use crate::utils::x::convertors::convert_data_to_rawpair::data_to_rawpair;
@@ -121,12 +125,14 @@ pub async fn main(
     };
 
     let current_data = Data::get_all(&all_data, &passed_project_id, &passed_collection_id);
+    let data_key = get_reveal_key(&mappings, &collection, current_user.role != Role::VIEWER);
     let mut raw_pairs = Vec::<RawPair>::new();
     let mut data_ids = Vec::<String>::new();
 
     for data in current_data {
         match data_to_rawpair(&data, &collection) {
-            Ok(rp) => {
+            Ok(mut rp) => {
+                reveal_rawpair(&mut rp, &collection, data_key.as_deref());
                 raw_pairs.push(rp);
                 data_ids.push(data.id.clone());
             }
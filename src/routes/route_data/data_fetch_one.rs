@@ -7,8 +7,12 @@ use crate::components::project::Project;
 use crate::components::user::{Role, User};
 use crate::middlewares::token::{verify_jwt, Token};
 use crate::utils::{
-    collection::auto_fetch_all_collections, data::auto_fetch_all_data,
-    mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects, user::auto_fetch_all_users,
+    collection::auto_fetch_all_collections,
+    data::auto_fetch_all_data,
+    field_encryption::{get_reveal_key, reveal_rawpair},
+    mapping::auto_fetch_all_mappings,
+    project::auto_fetch_all_projects,
+    user::auto_fetch_all_users,
 };
 
 use crate::utils::x::convertors::convert_data_to_rawpair::data_to_rawpair;
@@ -125,12 +129,15 @@ pub async fn main(
         }
     };
 
-    let raw_pair = match data_to_rawpair(&current_data, &collection) {
+    let mut raw_pair = match data_to_rawpair(&current_data, &collection) {
         Ok(rp) => rp,
         Err(e) => {
             return json!({"status": e.0, "message": e.1});
         }
     };
 
+    let data_key = get_reveal_key(&mappings, &collection, current_user.role != Role::VIEWER);
+    reveal_rawpair(&mut raw_pair, &collection, data_key.as_deref());
+
     return json!({"status": 200, "message": "Data successfully fetched!", "pair": raw_pair, "data_id": data_id});
 }
@@ -4,13 +4,14 @@ use rocket::serde::{Deserialize, Serialize};
 
 use crate::components::collection::Collection;
 use crate::components::data::Data;
+use crate::components::data_log::{LogEntry, LogOperation};
 use crate::components::project::Project;
 use crate::components::user::{Role, User};
 use crate::middlewares::token::{verify_jwt, Token};
 use crate::utils::{
-    collection::auto_fetch_all_collections, data::auto_fetch_all_data, data::auto_save_all_data,
-    event::auto_create_event, mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
-    user::auto_fetch_all_users,
+    collection::auto_fetch_all_collections, data::auto_fetch_all_data,
+    data::auto_save_data_entries, event::auto_create_event, mapping::auto_fetch_all_mappings,
+    project::auto_fetch_all_projects, user::auto_fetch_all_users,
 };
 
 #[derive(Serialize, Deserialize)]
@@ -102,6 +103,11 @@ pub async fn main(data: Json<PublishDataInput>, token: Token) -> Value {
         return json!({"status": e.0, "message": e.1});
     }
 
+    let entries = match Data::get(&all_data, project_id, collection_id, data_id) {
+        Ok(d) => vec![LogEntry::new(LogOperation::UPDATE, data_id, Some(d))],
+        Err(e) => return json!({"status": e.0, "message": e.1}),
+    };
+
     if let Err(e) = auto_create_event(
         &mappings,
         "data_publish",
@@ -118,7 +124,7 @@ pub async fn main(data: Json<PublishDataInput>, token: Token) -> Value {
         return json!({"status": e.0, "message": e.1});
     }
 
-    match auto_save_all_data(&mappings, &project_id, &collection_id, &all_data) {
+    match auto_save_data_entries(&mappings, &project_id, &collection_id, &all_data, &entries) {
         Ok(_) => {
             return json!({"status": 200, "message": format!("Data successfully {}published!", if *publish { "" } else { "un" } )})
         }
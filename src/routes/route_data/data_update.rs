@@ -4,14 +4,15 @@ use rocket::serde::{Deserialize, Serialize};
 
 use crate::components::collection::Collection;
 use crate::components::data::Data;
+use crate::components::data_log::{LogEntry, LogOperation};
 use crate::components::project::Project;
 use crate::components::raw_pair::RawPair;
 use crate::components::user::{Role, User};
 use crate::middlewares::token::{verify_jwt, Token};
 use crate::utils::{
-    collection::auto_fetch_all_collections, data::auto_fetch_all_data, data::auto_save_all_data,
-    event::auto_create_event, mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
-    user::auto_fetch_all_users,
+    collection::auto_fetch_all_collections, data::auto_fetch_all_data,
+    data::auto_save_data_entries, event::auto_create_event, field_encryption::unmask_rawpair,
+    mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects, user::auto_fetch_all_users,
 };
 
 use crate::utils::x::convertors::convert_rawpair_to_data::rawpair_to_data;
@@ -104,11 +105,21 @@ pub async fn main(data: Json<UpdateDataInput>, token: Token) -> Value {
         return json!({"status": 403, "message": "Error: Not authorized to update Data in this Collection"});
     }
 
-    let new_data_id = match rawpair_to_data(&mut all_data, &collection, raw_pair, true) {
+    let mut raw_pair = raw_pair.clone();
+    if let Ok(current_data) = Data::get(&all_data, project_id, collection_id, data_id) {
+        unmask_rawpair(&mut raw_pair, &collection, &current_data);
+    }
+
+    let new_data_id = match rawpair_to_data(&mut all_data, &collection, &raw_pair, true) {
         Ok(id) => id,
         Err(e) => return json!({"status": e.0, "message": e.1}),
     };
 
+    let new_data = match Data::get(&all_data, project_id, collection_id, &new_data_id) {
+        Ok(d) => d,
+        Err(e) => return json!({"status": e.0, "message": e.1}),
+    };
+
     match Data::delete(&mut all_data, &data_id) {
         Ok(_) => {}
         Err(e) => {
@@ -116,6 +127,11 @@ pub async fn main(data: Json<UpdateDataInput>, token: Token) -> Value {
         }
     }
 
+    let entries = vec![
+        LogEntry::new(LogOperation::CREATE, &new_data_id, Some(new_data)),
+        LogEntry::new(LogOperation::DELETE, data_id, None),
+    ];
+
     if let Err(e) = auto_create_event(
         &mappings,
         "data_update",
@@ -131,7 +147,7 @@ pub async fn main(data: Json<UpdateDataInput>, token: Token) -> Value {
         return json!({"status": e.0, "message": e.1});
     }
 
-    match auto_save_all_data(&mappings, &project_id, &collection_id, &all_data) {
+    match auto_save_data_entries(&mappings, &project_id, &collection_id, &all_data, &entries) {
         Ok(_) => {
             return json!({"status": 200, "message": "Data successfully updated!", "data_id": new_data_id})
         }
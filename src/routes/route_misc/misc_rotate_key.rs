@@ -0,0 +1,57 @@
+use rocket::post;
+use rocket::serde::json::{json, Json, Value};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::components::user::{Role, User};
+use crate::middlewares::token::{verify_jwt, Token};
+use crate::utils::{
+    key_rotation::rotate_encryption_key, mapping::auto_fetch_all_mappings,
+    user::auto_fetch_all_users,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RotateKeyInput {
+    uid: String,
+    password: String,
+    new_password: String,
+}
+
+#[post("/rotate_key", format = "json", data = "<data>")]
+pub async fn main(data: Json<RotateKeyInput>, token: Token) -> Value {
+    let uid = &data.uid;
+    let password = &data.password;
+    let new_password = &data.new_password;
+
+    match verify_jwt(uid.clone(), token.0).await {
+        Err(info) => return json!({"status": info.0, "message": info.1}),
+        _ => {}
+    };
+
+    let mappings = auto_fetch_all_mappings();
+
+    let users = match auto_fetch_all_users(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching users"});
+        }
+    };
+
+    let current_user = User::get(&users, uid).unwrap();
+    if current_user.role != Role::ROOT {
+        return json!({"status": 403, "message": "Error: Not enough privileges to carry out this operation"});
+    }
+
+    match rotate_encryption_key(&mappings, password, new_password) {
+        Ok(resumed) => {
+            let message = if new_password.len() > 0 {
+                "Encryption key successfully rotated! The new password was saved as TMP_PASSWORD in .env, update TMP_PASSWORD anywhere else it is set before restarting"
+            } else {
+                "Encryption key successfully rotated!"
+            };
+
+            return json!({"status": 200, "message": message, "resumed": resumed, "password_persisted": new_password.len() > 0});
+        }
+        Err(e) => return json!({"status": e.0, "message": e.1}),
+    }
+}
@@ -3,3 +3,9 @@ pub mod test_mongo;
 
 #[path = "misc_test_smtp.rs"]
 pub mod test_smtp;
+
+#[path = "misc_locks.rs"]
+pub mod locks;
+
+#[path = "misc_rotate_key.rs"]
+pub mod rotate_key;
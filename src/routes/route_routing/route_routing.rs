@@ -18,3 +18,9 @@ pub mod convert_kdl;
 
 #[path = "routing_delete.rs"]
 pub mod delete;
+
+#[path = "routing_trace.rs"]
+pub mod trace;
+
+#[path = "routing_test.rs"]
+pub mod test;
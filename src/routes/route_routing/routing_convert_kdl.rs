@@ -6,8 +6,10 @@ use crate::components::project::Project;
 use crate::components::routing::mod_route::RouteComponent;
 use crate::components::user::{Role, User};
 use crate::middlewares::token::{verify_jwt, Token};
+use crate::utils::x::route_validator::validate_route;
 use crate::utils::{
-    mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects, user::auto_fetch_all_users,
+    collection::auto_fetch_all_collections, mapping::auto_fetch_all_mappings,
+    project::auto_fetch_all_projects, route::auto_fetch_all_routes, user::auto_fetch_all_users,
 };
 
 #[derive(Serialize, Deserialize)]
@@ -79,5 +81,29 @@ pub async fn main(data: Json<ConvertRouteKDL>, token: Token) -> Value {
         return json!({"status": e.0, "message": e.1, "success": false });
     };
 
+    let all_collections = match auto_fetch_all_collections(&mappings) {
+        Ok(c) => c,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching collections", "success": false});
+        }
+    };
+
+    let project_routes = match auto_fetch_all_routes(&project_id) {
+        Ok(r) => r,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching routes", "success": false});
+        }
+    };
+
+    let diagnostics = validate_route(
+        &all_routes[0],
+        project_id,
+        &all_collections,
+        &project_routes,
+    );
+    if diagnostics.len() > 0 {
+        return json!({"status": 400, "message": "Error: Route flow failed validation", "diagnostics": diagnostics, "success": false });
+    }
+
     return json!({"status": 200, "message": "Route KDL successfully converted to blocks!", "route": all_routes[0], "success": true });
 }
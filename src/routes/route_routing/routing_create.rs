@@ -6,8 +6,10 @@ use crate::components::project::Project;
 use crate::components::routing::mod_route::RouteComponent;
 use crate::components::user::{Role, User};
 use crate::middlewares::token::{verify_jwt, Token};
+use crate::utils::x::route_validator::validate_route;
 use crate::utils::{
-    event::auto_create_event, mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
+    collection::auto_fetch_all_collections, event::auto_create_event,
+    mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
     route::auto_fetch_all_routes, route::auto_save_all_routes, user::auto_fetch_all_users,
 };
 
@@ -104,6 +106,23 @@ pub async fn main(data: Json<CreateRouteInput>, token: Token) -> Value {
         return json!({"status": e.0, "message": e.1});
     }
 
+    let all_collections = match auto_fetch_all_collections(&mappings) {
+        Ok(c) => c,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching collections"});
+        }
+    };
+
+    let diagnostics = validate_route(
+        &all_routes[all_routes.len() - 1],
+        project_id,
+        &all_collections,
+        &all_routes,
+    );
+    if diagnostics.len() > 0 {
+        return json!({"status": 400, "message": "Error: Route flow failed validation", "diagnostics": diagnostics});
+    }
+
     if let Err(e) = auto_create_event(
         &mappings,
         if *update {
@@ -3,11 +3,13 @@ use rocket::serde::json::{json, Value};
 
 use crate::components::project::Project;
 use crate::components::routing::mod_route::RouteComponent;
+use crate::components::schedule::Schedule;
 use crate::components::user::{Role, User};
 use crate::middlewares::token::{verify_jwt, Token};
 use crate::utils::{
     event::auto_create_event, mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
-    route::auto_fetch_all_routes, route::auto_save_all_routes, user::auto_fetch_all_users,
+    route::auto_fetch_all_routes, route::auto_save_all_routes, schedule::auto_fetch_all_schedules,
+    schedule::auto_save_all_schedules, user::auto_fetch_all_users,
 };
 
 #[delete("/delete?<uid>&<project_id>&<route_id>")]
@@ -92,6 +94,18 @@ pub async fn main(
         return json!({"status": e.0, "message": e.1});
     }
 
+    let mut all_schedules = match auto_fetch_all_schedules(&passed_project_id) {
+        Ok(s) => s,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching schedules"});
+        }
+    };
+
+    Schedule::delete_by_route(&mut all_schedules, &passed_route_id);
+    if let Err(e) = auto_save_all_schedules(&passed_project_id, &all_schedules) {
+        return json!({"status": 500, "message": e});
+    }
+
     if let Err(e) = auto_create_event(
         &mappings,
         "route_delete",
@@ -0,0 +1,104 @@
+use rocket::post;
+use rocket::serde::json::{json, Json, Value};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::components::project::Project;
+use crate::components::routing::mod_route::RouteComponent;
+use crate::components::user::{Role, User};
+use crate::middlewares::token::{verify_jwt, Token};
+use crate::utils::x::test_runner::{run_route_tests, TestResult};
+use crate::utils::{
+    mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
+    route::auto_fetch_all_routes, user::auto_fetch_all_users,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct TestRouteInput {
+    uid: String,
+    project_id: String,
+    route_id: Option<String>,
+}
+
+#[post("/test", format = "json", data = "<data>")]
+pub async fn main(data: Json<TestRouteInput>, token: Token) -> Value {
+    let uid = &data.uid;
+    let project_id = &data.project_id;
+    let route_id = &data.route_id;
+
+    match verify_jwt(uid.clone(), token.0).await {
+        Err(info) => return json!({"status": info.0, "message": info.1}),
+        _ => {}
+    };
+
+    let mappings = auto_fetch_all_mappings();
+    let users = match auto_fetch_all_users(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching users"});
+        }
+    };
+
+    let current_user = User::get(&users, uid).unwrap();
+
+    let all_projects = match auto_fetch_all_projects(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching projects"});
+        }
+    };
+
+    let project = match Project::get(&all_projects, project_id) {
+        Ok(p) => p,
+        Err(_) => {
+            return json!({"status": 404, "message": "Error: No Project with this project_id found"})
+        }
+    };
+
+    let members = project.members.clone();
+    let mut allowed = false;
+
+    if current_user.role != Role::ROOT {
+        for member in members {
+            if member.to_lowercase() == uid.to_string() {
+                allowed = true;
+                break;
+            }
+        }
+    } else {
+        allowed = true;
+    }
+
+    if !allowed {
+        return json!({"status": 403, "message": "Error: Not authorized to test Routes for this Project"});
+    }
+
+    let all_routes = match auto_fetch_all_routes(project_id) {
+        Ok(d) => d,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching routes"});
+        }
+    };
+
+    let routes_to_test = match route_id {
+        Some(route_id) if route_id.trim().len() > 0 => {
+            match RouteComponent::get(&all_routes, project_id, route_id) {
+                Ok(r) => vec![r],
+                Err(_) => {
+                    return json!({"status": 404, "message": "Error: No Route with this route_id found"})
+                }
+            }
+        }
+        _ => all_routes,
+    };
+
+    let mut all_results = Vec::<TestResult>::new();
+    for current_route in routes_to_test.iter() {
+        all_results.append(&mut run_route_tests(current_route, project_id).await);
+    }
+
+    let passed = all_results.iter().filter(|result| result.passed).count();
+    let failed = all_results.len() - passed;
+
+    return json!({"status": 200, "message": "Route tests successfully executed!", "results": all_results, "passed": passed, "failed": failed, "all_passed": failed == 0});
+}
@@ -0,0 +1,156 @@
+use rocket::post;
+use rocket::serde::json::{json, Json, Value};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::components::project::Project;
+use crate::components::routing::mod_route::RouteComponent;
+use crate::components::user::{Role, User};
+use crate::middlewares::token::{verify_jwt, Token};
+use crate::routes::x::x::{execute_route, match_route_path, LocalParamData};
+use crate::utils::x::flow_processor::TraceStep;
+use crate::utils::x::route_validator::validate_route;
+use crate::utils::{
+    collection::auto_fetch_all_collections, mapping::auto_fetch_all_mappings,
+    project::auto_fetch_all_projects, route::auto_fetch_all_routes, user::auto_fetch_all_users,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct TraceRouteInput {
+    uid: String,
+    project_id: String,
+    route_id: String,
+    route: Option<RouteComponent>,
+    path: String,
+    params: String,
+    body: Value,
+    token: String,
+    #[serde(default)]
+    api_key: String,
+}
+
+#[post("/trace", format = "json", data = "<data>")]
+pub async fn main(data: Json<TraceRouteInput>, token: Token) -> Value {
+    let uid = &data.uid;
+    let project_id = &data.project_id;
+    let route_id = &data.route_id;
+
+    match verify_jwt(uid.clone(), token.0).await {
+        Err(info) => return json!({"status": info.0, "message": info.1}),
+        _ => {}
+    };
+
+    let mappings = auto_fetch_all_mappings();
+    let users = match auto_fetch_all_users(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching users"});
+        }
+    };
+
+    let current_user = User::get(&users, uid).unwrap();
+
+    let all_projects = match auto_fetch_all_projects(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching projects"});
+        }
+    };
+
+    let project = match Project::get(&all_projects, project_id) {
+        Ok(p) => p,
+        Err(_) => {
+            return json!({"status": 404, "message": "Error: No Project with this project_id found"})
+        }
+    };
+
+    let members = project.members.clone();
+    let mut allowed = false;
+
+    if current_user.role != Role::ROOT {
+        if current_user.role == Role::ADMIN {
+            for member in members {
+                if member.to_lowercase() == uid.to_string() {
+                    allowed = true;
+                    break;
+                }
+            }
+        }
+    } else {
+        allowed = true;
+    }
+
+    if !allowed {
+        return json!({"status": 403, "message": "Error: Not authorized to trace Routes for this Project"});
+    }
+
+    let all_routes = match auto_fetch_all_routes(project_id) {
+        Ok(d) => d,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching routes"});
+        }
+    };
+
+    let current_route = match &data.route {
+        Some(tmp_route) => {
+            let mut tmp_routes = Vec::<RouteComponent>::new();
+            let stringified_route = RouteComponent::to_string(tmp_route.clone());
+            if let Err(e) = RouteComponent::from_string(&mut tmp_routes, &stringified_route) {
+                return json!({"status": e.0, "message": e.1});
+            }
+
+            let all_collections = match auto_fetch_all_collections(&mappings) {
+                Ok(c) => c,
+                _ => {
+                    return json!({"status": 500, "message": "Error: Failed fetching collections"});
+                }
+            };
+
+            let diagnostics =
+                validate_route(&tmp_routes[0], project_id, &all_collections, &all_routes);
+            if diagnostics.len() > 0 {
+                return json!({"status": 400, "message": "Error: Route flow failed validation", "diagnostics": diagnostics});
+            }
+
+            tmp_routes[0].clone()
+        }
+        None => match RouteComponent::get(&all_routes, project_id, route_id) {
+            Ok(r) => r,
+            Err(_) => {
+                return json!({"status": 404, "message": "Error: No Route with this route_id found"})
+            }
+        },
+    };
+
+    let path = if data.path.trim().len() > 0 {
+        data.path.clone()
+    } else {
+        current_route.route_path.clone()
+    };
+
+    let all_path_params: Vec<LocalParamData> = match match_route_path(
+        &current_route.route_path,
+        &path,
+    ) {
+        Some((_, path_params)) => path_params,
+        None => {
+            return json!({"status": 400, "message": "Error: path does not match the route_path of this Route"})
+        }
+    };
+
+    let mut all_steps = Vec::<TraceStep>::new();
+    let result = execute_route(
+        &current_route,
+        project_id,
+        data.body.to_string(),
+        data.params.clone(),
+        all_path_params,
+        data.token.clone(),
+        data.api_key.clone(),
+        Some(&mut all_steps),
+        true,
+    )
+    .await;
+
+    return json!({"status": 200, "message": "Route successfully traced!", "route_id": current_route.route_id, "result": result.body, "result_status": result.status, "result_headers": result.headers, "steps": all_steps});
+}
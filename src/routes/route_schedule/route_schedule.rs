@@ -0,0 +1,8 @@
+#[path = "schedule_fetch_all.rs"]
+pub mod fetch_all;
+
+#[path = "schedule_create.rs"]
+pub mod create;
+
+#[path = "schedule_delete.rs"]
+pub mod delete;
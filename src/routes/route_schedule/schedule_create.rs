@@ -0,0 +1,138 @@
+use rocket::post;
+use rocket::serde::json::{json, Json, Value};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::components::project::Project;
+use crate::components::routing::mod_route::RouteComponent;
+use crate::components::schedule::{CatchUpPolicy, Schedule};
+use crate::components::user::{Role, User};
+use crate::middlewares::token::{verify_jwt, Token};
+use crate::utils::{
+    event::auto_create_event, mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
+    route::auto_fetch_all_routes, schedule::auto_fetch_all_schedules,
+    schedule::auto_save_all_schedules, scheduler::requires_auth, user::auto_fetch_all_users,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CreateScheduleInput {
+    uid: String,
+    project_id: String,
+    route_id: String,
+    cron: String,
+    catch_up: String,
+    body: Value,
+}
+
+#[post("/create", format = "json", data = "<data>")]
+pub async fn main(data: Json<CreateScheduleInput>, token: Token) -> Value {
+    let uid = &data.uid;
+    let project_id = &data.project_id;
+    let route_id = &data.route_id;
+
+    match verify_jwt(uid.clone(), token.0).await {
+        Err(info) => return json!({"status": info.0, "message": info.1}),
+        _ => {}
+    };
+
+    let mappings = auto_fetch_all_mappings();
+    let all_routes = match auto_fetch_all_routes(&project_id) {
+        Ok(r) => r,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching routes"});
+        }
+    };
+
+    let mut all_schedules = match auto_fetch_all_schedules(&project_id) {
+        Ok(s) => s,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching schedules"});
+        }
+    };
+
+    let users = match auto_fetch_all_users(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching users"});
+        }
+    };
+
+    let current_user = User::get(&users, uid).unwrap();
+
+    let all_projects = match auto_fetch_all_projects(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return json!({"status": 500, "message": "Error: Failed fetching projects"});
+        }
+    };
+
+    let project = match Project::get(&all_projects, project_id) {
+        Ok(p) => p,
+        Err(_) => {
+            return json!({"status": 404, "message": "Error: No Project with this project_id found"})
+        }
+    };
+
+    let members = project.members.clone();
+    let mut allowed = false;
+
+    if current_user.role != Role::ROOT {
+        if current_user.role == Role::ADMIN {
+            for member in members {
+                if member.to_lowercase() == uid.to_string() {
+                    allowed = true;
+                    break;
+                }
+            }
+        }
+    } else {
+        allowed = true;
+    }
+
+    if !allowed {
+        return json!({"status": 403, "message": "Error: Not authorized to create Schedules for this Project"});
+    }
+
+    let route = match RouteComponent::get(&all_routes, project_id, route_id) {
+        Ok(r) => r,
+        Err(_) => {
+            return json!({"status": 404, "message": "Error: No Route with this route_id found"})
+        }
+    };
+
+    if requires_auth(&route) {
+        return json!({"status": 400, "message": "Error: Routes requiring authentication cannot be scheduled"});
+    }
+
+    let schedule_id = match Schedule::create(
+        &mut all_schedules,
+        route_id,
+        &data.cron,
+        CatchUpPolicy::from(&data.catch_up),
+        &data.body.to_string(),
+    ) {
+        Ok(id) => id,
+        Err(e) => return json!({"status": e.0, "message": e.1}),
+    };
+
+    if let Err(e) = auto_create_event(
+        &mappings,
+        "schedule_create",
+        format!(
+            "A schedule with id <{}> was created for route <{}> under pro[{}] by usr[{}]",
+            schedule_id, route_id, project_id, uid
+        ),
+        format!("/routes/p/{}/r/v/{}", project_id, route_id),
+    ) {
+        return json!({"status": e.0, "message": e.1});
+    }
+
+    match auto_save_all_schedules(&project_id, &all_schedules) {
+        Ok(_) => {
+            return json!({"status": 200, "message": "Schedule successfully created!", "schedule_id": schedule_id})
+        }
+        Err(e) => {
+            json!({"status": 500, "message": e})
+        }
+    }
+}
@@ -1,27 +1,39 @@
 use rocket::data::ToByteUnit;
-use rocket::post;
 use rocket::serde::json::json;
 use rocket::serde::{Deserialize, Serialize};
+use rocket::{delete, get, patch, post, put};
 
+use crate::components::datapair::DataPair;
+use crate::components::mapping::Mapping;
+use crate::components::routing::core::core_body_data::BodyData;
 use crate::components::routing::mod_route::RouteComponent;
 use crate::components::routing::submodules::sub_body_data_type::BodyDataType;
+use crate::components::routing::submodules::sub_route_method::RouteMethod;
 
-use crate::middlewares::token::{verify_jwt_x, Token};
+use crate::components::routing::submodules::sub_auth_rule::AuthRule;
+use crate::middlewares::token::{verify_api_key, verify_jwt_x, ApiKeyHeader, Token};
 use crate::utils::x::complete_route::CompleteRoute;
 use crate::utils::{
-    mapping::auto_fetch_all_mappings, project::auto_fetch_all_projects,
+    mapping::auto_fetch_all_mappings,
+    project::auto_fetch_all_projects,
+    rate_limit::{check_rate_limit, get_rate_limit_identity},
     route::auto_fetch_all_routes,
+    route_cache::{cache_response, fetch_cached_response, get_route_cache_key},
 };
 
 use rocket::http::uri::Origin;
+use rocket::http::{RawStr, Status};
+use rocket::request::Request;
+use rocket::response::{self, Responder, Response};
+use rocket::serde::json::Json;
 use rocket::Data;
+use std::net::IpAddr;
 
 use serde_json::Value;
 
-use crate::utils::x::definition_store::{DefinitionData, DefinitionStore};
-use crate::utils::x::global_block_order::GlobalBlockOrder;
-use crate::utils::x::loop_processor::LoopObject;
-use crate::utils::x::signal_processor::{obtain_signal, Signal};
+use crate::utils::x::flow_processor::{execute_flow, TraceStep};
+use crate::utils::x::signal_processor::{ResponseHeader, Signal};
+use crate::utils::x::transaction::Transaction;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct LocalParamData {
@@ -35,6 +47,50 @@ pub struct LocalBlockOrder {
     names: Vec<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct XResponse {
+    pub status: usize,
+    pub body: Value,
+    pub headers: Vec<ResponseHeader>,
+}
+
+impl XResponse {
+    pub fn new(status: usize, body: Value, headers: Vec<ResponseHeader>) -> XResponse {
+        XResponse {
+            status: status,
+            body: body,
+            headers: headers,
+        }
+    }
+
+    pub fn from_json(body: Value) -> XResponse {
+        let status = match body["status"].as_u64() {
+            Some(s) => s as usize,
+            None => 200,
+        };
+
+        XResponse::new(status, body, vec![])
+    }
+}
+
+impl<'r> Responder<'r, 'static> for XResponse {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        let status = match Status::from_code(self.status as u16) {
+            Some(s) => s,
+            None => Status::InternalServerError,
+        };
+
+        let mut response = Response::build_from(Json(self.body).respond_to(request)?);
+        response.status(status);
+
+        for header in self.headers {
+            response.raw_header(header.key, header.value);
+        }
+
+        response.ok()
+    }
+}
+
 pub fn validate_body_data(
     id: &str,
     data: Value,
@@ -85,7 +141,7 @@ pub fn validate_body_data(
         if let Value::Array(_) = data {
             return Ok(data);
         } else {
-            return Err((400, format!("Error: {} should be a number", id)));
+            return Err((400, format!("Error: {} should be an array", id)));
         }
     } else if bdtype == BodyDataType::BOOLEAN {
         if let Value::Bool(_) = data {
@@ -110,68 +166,240 @@ pub fn validate_body_data(
     Ok(data)
 }
 
-pub fn process_block(
-    current_route: &RouteComponent,
-    all_definitions: &mut Vec<DefinitionStore>,
-    global_blocks: &Vec<GlobalBlockOrder>,
-    block: &GlobalBlockOrder,
-    project_id: &str,
-    current_index: usize,
-    actual_body: &Value,
-    all_params: &Vec<LocalParamData>,
-) -> Result<Signal, (usize, String)> {
-    if let Err(e) = DefinitionStore::add_definition(
-        current_route,
-        all_definitions,
-        global_blocks,
-        project_id,
-        &block.name,
-        block.index,
-        current_index,
-        actual_body,
-        all_params,
-    ) {
-        return Err(e);
+pub fn validate_body_schema(
+    bdata: &BodyData,
+    data: Value,
+    pointer: &str,
+) -> Result<Value, (usize, String)> {
+    if !bdata.required && Value::Null == data {
+        return Ok(data);
     }
 
-    match obtain_signal(
-        current_route,
-        all_definitions,
-        global_blocks,
-        &block.name,
-        block.index,
-        current_index,
-    ) {
-        Ok(signal) => match signal {
-            Signal::FAIL(status, message) => {
-                return Err((status, message));
+    let data = match validate_body_data(pointer, data, bdata.bdtype.clone(), bdata.required) {
+        Ok(d) => d,
+        Err(e) => return Err(e),
+    };
+
+    let length = match &data {
+        Value::String(s) => Some(s.chars().count()),
+        Value::Array(a) => Some(a.len()),
+        _ => None,
+    };
+
+    if let Some(length) = length {
+        if let Some(min) = bdata.min {
+            if length < min {
+                return Err((
+                    400,
+                    format!(
+                        "Error: {} should have a length of at least {}",
+                        pointer, min
+                    ),
+                ));
             }
-            Signal::BREAK => Ok(Signal::BREAK),
-            Signal::CONTINUE => Ok(Signal::CONTINUE),
-            Signal::NONE => Ok(Signal::NONE),
-            Signal::RETURN(data) => Ok(Signal::RETURN(data)),
-        },
-        Err(e) => {
-            return Err(e);
+        }
+
+        if let Some(max) = bdata.max {
+            if length > max {
+                return Err((
+                    400,
+                    format!("Error: {} should have a length of at most {}", pointer, max),
+                ));
+            }
+        }
+    }
+
+    if let Value::Array(all_elements) = &data {
+        if let Some(element_type) = &bdata.element_type {
+            let element_schema = BodyData {
+                id: String::new(),
+                bdtype: element_type.clone(),
+                element_type: None,
+                required: true,
+                min: None,
+                max: None,
+                fields: bdata.fields.clone(),
+            };
+
+            for (i, element) in all_elements.iter().enumerate() {
+                if let Err(e) = validate_body_schema(
+                    &element_schema,
+                    element.clone(),
+                    &format!("{}/{}", pointer, i),
+                ) {
+                    return Err(e);
+                }
+            }
+        } else if bdata.fields.len() > 0 {
+            return Err((400, format!("Error: {} should be an object", pointer)));
+        }
+    } else if let Value::Object(_) = &data {
+        for field in bdata.fields.iter() {
+            if let Err(e) = validate_body_schema(
+                field,
+                data[field.id.clone()].clone(),
+                &format!("{}/{}", pointer, field.id),
+            ) {
+                return Err(e);
+            }
+        }
+    } else if bdata.fields.len() > 0 {
+        return Err((400, format!("Error: {} should be an object", pointer)));
+    }
+
+    Ok(data)
+}
+
+pub fn match_route_path(route_path: &str, route: &str) -> Option<(Vec<bool>, Vec<LocalParamData>)> {
+    let pattern_segments = route_path.split("/").collect::<Vec<&str>>();
+    let route_segments = route.split("/").collect::<Vec<&str>>();
+
+    if pattern_segments.len() != route_segments.len() {
+        return None;
+    }
+
+    let mut static_segments = Vec::<bool>::new();
+    let mut all_path_params = Vec::<LocalParamData>::new();
+
+    for (pattern_segment, route_segment) in pattern_segments.iter().zip(route_segments.iter()) {
+        if pattern_segment.starts_with(":") && pattern_segment.len() > 1 {
+            if route_segment.trim().len() == 0 {
+                return None;
+            }
+
+            static_segments.push(false);
+            all_path_params.push(LocalParamData {
+                key: pattern_segment[1..].to_string(),
+                value: RawStr::new(route_segment)
+                    .percent_decode_lossy()
+                    .to_string(),
+            });
+        } else if pattern_segment == route_segment {
+            static_segments.push(true);
+        } else {
+            return None;
         }
     }
+
+    Some((static_segments, all_path_params))
+}
+
+#[get("/<_path..>")]
+pub async fn main_get<'r>(
+    _path: CompleteRoute,
+    token: Option<Token>,
+    api_key: ApiKeyHeader,
+    uri: &Origin<'r>,
+    client_ip: Option<IpAddr>,
+) -> XResponse {
+    process_route(
+        RouteMethod::GET,
+        String::new(),
+        token,
+        api_key,
+        uri,
+        client_ip,
+    )
+    .await
 }
 
 #[post("/<_path..>", format = "json", data = "<data>")]
 pub async fn main<'r>(
     data: Data<'r>,
     _path: CompleteRoute,
-    token: Token,
+    token: Option<Token>,
+    api_key: ApiKeyHeader,
     uri: &Origin<'r>,
-) -> rocket::serde::json::Value {
-    let mut project_id = String::new();
-    let mut api_path = String::new();
-    let mut route = String::new();
+    client_ip: Option<IpAddr>,
+) -> XResponse {
+    let stream = match data.open(10.megabytes()).into_string().await {
+        Ok(s) => s.into_inner(),
+        Err(_) => {
+            return XResponse::from_json(
+                json!({"status": 400, "message": "Error: Invalid body data"}),
+            )
+        }
+    };
+
+    process_route(RouteMethod::POST, stream, token, api_key, uri, client_ip).await
+}
+
+#[put("/<_path..>", format = "json", data = "<data>")]
+pub async fn main_put<'r>(
+    data: Data<'r>,
+    _path: CompleteRoute,
+    token: Option<Token>,
+    api_key: ApiKeyHeader,
+    uri: &Origin<'r>,
+    client_ip: Option<IpAddr>,
+) -> XResponse {
     let stream = match data.open(10.megabytes()).into_string().await {
         Ok(s) => s.into_inner(),
-        Err(_) => return json!({"status": 400, "message": "Error: Invalid body data"}),
+        Err(_) => {
+            return XResponse::from_json(
+                json!({"status": 400, "message": "Error: Invalid body data"}),
+            )
+        }
     };
 
+    process_route(RouteMethod::PUT, stream, token, api_key, uri, client_ip).await
+}
+
+#[patch("/<_path..>", format = "json", data = "<data>")]
+pub async fn main_patch<'r>(
+    data: Data<'r>,
+    _path: CompleteRoute,
+    token: Option<Token>,
+    api_key: ApiKeyHeader,
+    uri: &Origin<'r>,
+    client_ip: Option<IpAddr>,
+) -> XResponse {
+    let stream = match data.open(10.megabytes()).into_string().await {
+        Ok(s) => s.into_inner(),
+        Err(_) => {
+            return XResponse::from_json(
+                json!({"status": 400, "message": "Error: Invalid body data"}),
+            )
+        }
+    };
+
+    process_route(RouteMethod::PATCH, stream, token, api_key, uri, client_ip).await
+}
+
+#[delete("/<_path..>", data = "<data>")]
+pub async fn main_delete<'r>(
+    data: Data<'r>,
+    _path: CompleteRoute,
+    token: Option<Token>,
+    api_key: ApiKeyHeader,
+    uri: &Origin<'r>,
+    client_ip: Option<IpAddr>,
+) -> XResponse {
+    let stream = match data.open(10.megabytes()).into_string().await {
+        Ok(s) => s.into_inner(),
+        Err(_) => String::new(),
+    };
+
+    process_route(RouteMethod::DELETE, stream, token, api_key, uri, client_ip).await
+}
+
+pub async fn process_route<'r>(
+    method: RouteMethod,
+    stream: String,
+    token: Option<Token>,
+    api_key: ApiKeyHeader,
+    uri: &Origin<'r>,
+    client_ip: Option<IpAddr>,
+) -> XResponse {
+    let token = match token {
+        Some(t) => t.0,
+        None => String::new(),
+    };
+
+    let mut project_id = String::new();
+    let mut api_path = String::new();
+    let mut route = String::new();
+
     let mut full_path = uri.clone().into_normalized().path().to_string();
     let full_query = match uri.clone().into_normalized().query() {
         Some(query) => query.to_string(),
@@ -183,16 +411,18 @@ pub async fn main<'r>(
     let mappings = auto_fetch_all_mappings();
 
     if full_path.len() <= 1 {
-        return json!({
+        return XResponse::from_json(json!({
             "status": 400,
             "message": "Error: Invalid api_path & route"
-        });
+        }));
     }
 
     let all_projects = match auto_fetch_all_projects(&mappings) {
         Ok(u) => u,
         _ => {
-            return json!({"status": 500, "message": "Error: Failed fetching projects"});
+            return XResponse::from_json(
+                json!({"status": 500, "message": "Error: Failed fetching projects"}),
+            );
         }
     };
 
@@ -206,43 +436,186 @@ pub async fn main<'r>(
     }
 
     if api_path.len() == 0 {
-        return json!({
+        return XResponse::from_json(json!({
             "status": 400,
             "message": "Error: Invalid api_path"
-        });
+        }));
     }
 
     if route.len() == 0 {
-        return json!({
+        return XResponse::from_json(json!({
             "status": 400,
             "message": "Error: Invalid route"
-        });
+        }));
     }
 
     let all_routes = match auto_fetch_all_routes(&project_id) {
         Ok(d) => d,
         _ => {
-            return json!({"status": 500, "message": "Error: Failed fetching routes"});
+            return XResponse::from_json(
+                json!({"status": 500, "message": "Error: Failed fetching routes"}),
+            );
         }
     };
 
     let mut route_index = -1;
+    let mut path_found = false;
+    let mut best_static_segments = Vec::<bool>::new();
+    let mut all_path_params = Vec::<LocalParamData>::new();
     for (i, c_route) in all_routes.iter().enumerate() {
-        if c_route.route_path == route {
-            route_index = i as isize;
-            break;
+        if let Some((static_segments, path_params)) = match_route_path(&c_route.route_path, &route)
+        {
+            path_found = true;
+            if c_route.method == method
+                && (route_index < 0 || static_segments > best_static_segments)
+            {
+                route_index = i as isize;
+                best_static_segments = static_segments;
+                all_path_params = path_params;
+            }
         }
     }
 
     if route_index < 0 {
-        return json!({
+        if path_found {
+            return XResponse::from_json(json!({
+                "status": 405,
+                "message": format!("Error: Method {} not allowed on this route", method)
+            }));
+        }
+
+        return XResponse::from_json(json!({
             "status": 404,
             "message": "Error: Route not found"
-        });
+        }));
     }
 
     let current_route = all_routes[route_index as usize].clone();
 
+    if let Some(rate_limit) = &current_route.rate_limit {
+        let identity = get_rate_limit_identity(
+            &current_route,
+            rate_limit,
+            &project_id,
+            &token,
+            &api_key.0,
+            &client_ip,
+        );
+
+        if let Err(retry_after) =
+            check_rate_limit(&project_id, &current_route.route_id, rate_limit, &identity)
+        {
+            return XResponse::new(
+                429,
+                json!({
+                    "status": 429,
+                    "message": "Error: Too many requests"
+                }),
+                vec![ResponseHeader {
+                    key: String::from("Retry-After"),
+                    value: retry_after.to_string(),
+                }],
+            );
+        }
+    }
+
+    let mut cache_key: Option<String> = None;
+    if current_route.cache_ttl > 0 && RouteComponent::is_read_only(&current_route) {
+        let has_auth = current_route.auth_api_key
+            || match &current_route.auth_jwt {
+                Some(aj) => aj.active,
+                None => false,
+            };
+
+        // Cached responses would skip the auth checks, so authenticated routes always run
+        if !has_auth {
+            cache_key = Some(get_route_cache_key(
+                &current_route,
+                &full_query,
+                &all_path_params,
+                &stream,
+            ));
+        }
+    }
+
+    if let Some(key) = &cache_key {
+        if let Some(cached) = fetch_cached_response(key) {
+            if let Ok(mut response) = serde_json::from_str::<XResponse>(&cached) {
+                response.headers.push(ResponseHeader {
+                    key: String::from("X-Cache"),
+                    value: String::from("HIT"),
+                });
+                return response;
+            }
+        }
+    }
+
+    let mut response = execute_route(
+        &current_route,
+        &project_id,
+        stream,
+        full_query,
+        all_path_params,
+        token,
+        api_key.0,
+        None,
+        false,
+    )
+    .await;
+
+    if let Some(key) = &cache_key {
+        if response.status >= 200 && response.status < 300 {
+            if let Ok(stringified_response) = serde_json::to_string(&response) {
+                cache_response(
+                    key,
+                    &project_id,
+                    RouteComponent::fetched_collections(&current_route),
+                    stringified_response,
+                    current_route.cache_ttl,
+                );
+            }
+        }
+
+        response.headers.push(ResponseHeader {
+            key: String::from("X-Cache"),
+            value: String::from("MISS"),
+        });
+    }
+
+    response
+}
+
+pub async fn execute_route(
+    current_route: &RouteComponent,
+    project_id: &str,
+    stream: String,
+    full_query: String,
+    all_path_params: Vec<LocalParamData>,
+    token: String,
+    api_key: String,
+    mut trace: Option<&mut Vec<TraceStep>>,
+    dry_run: bool,
+) -> XResponse {
+    for pair in &current_route.path_params {
+        for current_path_param in all_path_params.iter() {
+            if current_path_param.key == pair.id {
+                if let Err(e) = validate_body_data(
+                    &pair.id.clone(),
+                    Value::String(current_path_param.value.clone()),
+                    pair.bdtype.clone(),
+                    true,
+                ) {
+                    return XResponse::from_json(json!({
+                        "status": e.0,
+                        "message": e.1
+                    }));
+                }
+
+                break;
+            }
+        }
+    }
+
     let mut delimiter = "&".to_string();
     if let Some(params) = &current_route.params {
         delimiter = params.delimiter.clone();
@@ -269,10 +642,10 @@ pub async fn main<'r>(
                         pair.bdtype.clone(),
                         false,
                     ) {
-                        return json!({
+                        return XResponse::from_json(json!({
                             "status": e.0,
                             "message": e.1
-                        });
+                        }));
                     }
 
                     break;
@@ -284,246 +657,123 @@ pub async fn main<'r>(
     let mut body_data = Value::Null;
     if let Ok(bd) = serde_json::from_str::<Value>(&stream) {
         body_data = bd;
+    } else if stream.trim().len() == 0 {
+        body_data = Value::Object(serde_json::Map::new());
     }
 
+    if current_route.auth_api_key {
+        if let Err(e) = verify_api_key(project_id, &api_key) {
+            return XResponse::from_json(json!({
+                "status": e.0,
+                "message": e.1
+            }));
+        }
+    }
+
+    let mut auth_claims = Vec::<DataPair>::new();
     if let Some(aj) = &current_route.auth_jwt {
         if aj.active {
             let payload = match body_data[aj.field.clone()].as_str() {
-                Some(p) => p,
-                None => {
-                    return json!({
-                        "status": 400,
-                        "message": format!("Error: Lack of a value for {}", &aj.field)
-                    });
-                }
+                Some(p) => Some(String::from(p)),
+                None => None,
             };
 
-            if let Err(e) = verify_jwt_x(
-                String::from(payload),
-                token.0,
-                &project_id,
-                &aj.ref_col,
-                &aj.field,
-            )
-            .await
-            {
-                return json!({
+            let record =
+                match verify_jwt_x(payload, token, project_id, &aj.ref_col, &aj.field).await {
+                    Ok(r) => r,
+                    Err(e) => {
+                        return XResponse::from_json(json!({
+                            "status": e.0,
+                            "message": e.1
+                        }));
+                    }
+                };
+
+            if let Err(e) = AuthRule::check(&aj.rules, &record.pairs) {
+                return XResponse::from_json(json!({
                     "status": e.0,
                     "message": e.1
-                });
+                }));
             }
+
+            auth_claims = record.pairs;
         }
     }
 
     if current_route.body.len() > 0 {
         for bdata in &current_route.body {
             if !body_data.is_object() {
-                return json!({
+                return XResponse::from_json(json!({
                     "status": 400,
                     "message": "Error: Invalid body"
-                });
+                }));
             }
 
-            if let Err(e) = validate_body_data(
-                &bdata.id.clone(),
+            if let Err(e) = validate_body_schema(
+                bdata,
                 body_data[bdata.id.clone()].clone(),
-                bdata.bdtype.clone(),
-                true,
+                &format!("/{}", bdata.id),
             ) {
-                return json!({
+                return XResponse::from_json(json!({
                     "status": e.0,
                     "message": e.1
-                });
+                }));
             }
         }
     }
 
-    let mut global_blocks = Vec::<GlobalBlockOrder>::new();
-    GlobalBlockOrder::process_blocks(&current_route, &mut global_blocks);
-
-    let mut all_definitions = Vec::<DefinitionStore>::new();
-    let mut current_index = 0;
-
-    loop {
-        if current_index >= global_blocks.len() {
-            break;
-        }
-
-        let mut current_block = global_blocks[current_index].clone();
-
-        let current_loops = match LoopObject::detect_loops(&global_blocks, &current_block) {
-            Ok(l) => l,
-            Err(e) => {
-                return json!({
-                    "status": e.0,
-                    "message": e.1
-                });
-            }
-        };
-
-        for cur_loop in current_loops {
-            if current_index < cur_loop.start_index {
-                break;
-            } else if current_index >= cur_loop.end_index {
-                continue;
-            }
-
-            let mut completed = false;
-            let mut iterations = 0;
-
-            while !completed {
-                if iterations == 0 {
-                    match process_block(
-                        &current_route,
-                        &mut all_definitions,
-                        &global_blocks,
-                        &global_blocks[cur_loop.start_index].clone(),
-                        &project_id,
-                        cur_loop.start_index,
-                        &body_data,
-                        &all_params,
-                    ) {
-                        Ok(s) => match s {
-                            Signal::RETURN(r) => {
-                                return r;
-                            }
-                            _ => {}
-                        },
-                        Err(e) => {
-                            return json!({
-                                "status": e.0,
-                                "message": e.1
-                            });
-                        }
-                    }
-                } else {
-                    let current_loop_value: Option<DefinitionStore>;
-
-                    if let Some(val) = DefinitionStore::get_raw_definition(
-                        &all_definitions,
-                        &cur_loop.ref_var,
-                        current_block.index,
-                    ) {
-                        current_loop_value = Some(val);
-                    } else {
-                        break;
-                    }
+    let mut transaction =
+        Transaction::new(dry_run, current_route.auth_api_key || auth_claims.len() > 0);
 
-                    let updated_loop_value = match current_loop_value.unwrap().data {
-                        DefinitionData::INTEGER(i) => DefinitionData::INTEGER(i + 1),
-                        DefinitionData::FLOAT(f) => DefinitionData::FLOAT(f + 1.0),
-                        _ => {
-                            break;
-                        }
-                    };
-
-                    match DefinitionStore::update_definition_value(
-                        &mut all_definitions,
-                        cur_loop.start_index,
-                        updated_loop_value,
-                    ) {
-                        Ok(_) => {}
-                        Err(e) => {
-                            return json!({
-                                "status": e.0,
-                                "message": e.1
-                            });
-                        }
-                    }
-                }
-
-                completed = match LoopObject::check_completed(
-                    &global_blocks,
-                    &all_definitions,
-                    &current_route,
-                    &cur_loop,
-                ) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        return json!({
-                            "status": e.0,
-                            "message": e.1
-                        });
-                    }
-                };
-
-                if completed {
-                    current_index = cur_loop.end_index;
-                    break;
-                } else {
-                    iterations += 1;
-                    if iterations > 12 {
-                        panic!();
-                    }
-                    for n in (cur_loop.start_index + 1)..cur_loop.end_index {
-                        match process_block(
-                            &current_route,
-                            &mut all_definitions,
-                            &global_blocks,
-                            &global_blocks[n].clone(),
-                            &project_id,
-                            n,
-                            &body_data,
-                            &all_params,
-                        ) {
-                            Ok(s) => match s {
-                                Signal::CONTINUE => break,
-                                Signal::BREAK => {
-                                    current_index = cur_loop.end_index;
-                                    completed = true;
-                                    break;
-                                }
-                                Signal::RETURN(r) => {
-                                    return r;
-                                }
-                                _ => {}
-                            },
-                            Err(e) => {
-                                return json!({
-                                    "status": e.0,
-                                    "message": e.1
-                                });
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    let result = execute_flow(
+        current_route,
+        project_id,
+        &body_data,
+        &all_params,
+        &all_path_params,
+        &auth_claims,
+        &mut transaction,
+        &mut trace,
+        0,
+    );
+
+    finish_flow(result, &transaction, &auto_fetch_all_mappings())
+}
 
-        if current_index >= global_blocks.len() {
-            break;
+pub fn finish_flow(
+    result: Result<Signal, (usize, String)>,
+    transaction: &Transaction,
+    mappings: &Vec<Mapping>,
+) -> XResponse {
+    let response = match result {
+        Ok(Signal::RETURN(r, status, headers)) => XResponse::new(status, r, headers),
+        Ok(Signal::FAIL(status, message, headers)) => {
+            return XResponse::new(
+                status,
+                json!({
+                    "status": status,
+                    "message": message
+                }),
+                headers,
+            );
         }
-
-        current_block = global_blocks[current_index].clone();
-
-        match process_block(
-            &current_route,
-            &mut all_definitions,
-            &global_blocks,
-            &current_block,
-            &project_id,
-            current_index,
-            &body_data,
-            &all_params,
-        ) {
-            Ok(s) => match s {
-                Signal::RETURN(r) => {
-                    return r;
-                }
-                _ => {}
-            },
-            Err(e) => {
-                return json!({
-                    "status": e.0,
-                    "message": e.1
-                });
-            }
+        Ok(_) => XResponse::from_json(json!({
+            "status": 200
+        })),
+        Err(e) => {
+            return XResponse::from_json(json!({
+                "status": e.0,
+                "message": e.1
+            }));
         }
+    };
 
-        current_index += 1;
+    if let Err(e) = transaction.commit(mappings) {
+        return XResponse::from_json(json!({
+            "status": e.0,
+            "message": e.1
+        }));
     }
 
-    return json!({
-        "status": 200
-    });
+    response
 }
@@ -34,6 +34,12 @@ pub mod event;
 #[path = "route_routing/route_routing.rs"]
 pub mod routing;
 
+#[path = "route_schedule/route_schedule.rs"]
+pub mod schedule;
+
+#[path = "route_api_key/route_api_key.rs"]
+pub mod api_key;
+
 #[path = "route_constraint/route_constraint.rs"]
 pub mod constraint;
 
@@ -0,0 +1,52 @@
+#[cfg(test)]
+use crate::components::{
+    api_key::{fetch_all_api_keys, save_all_api_keys, ApiKey},
+    io::remove_file,
+};
+
+#[test]
+fn main() {
+    let file_name: &str = "data/api_keys_test.txt";
+    remove_file(file_name.to_string());
+
+    let mut all_keys = fetch_all_api_keys(file_name.to_string(), &String::new());
+
+    let static_key = ApiKey::create(&mut all_keys, "static", "static-key-0123456789", false);
+    assert!(static_key.is_ok());
+
+    let hashed_key = ApiKey::create(&mut all_keys, "hashed", "hashed-key-0123456789", true);
+    assert!(hashed_key.is_ok());
+
+    let short_key = ApiKey::create(&mut all_keys, "short", "abc", false);
+    assert_eq!(
+        short_key,
+        Err((
+            400,
+            String::from("Error: key should be at least 16 characters long")
+        ))
+    );
+
+    let duplicate_key = ApiKey::create(&mut all_keys, "again", "hashed-key-0123456789", false);
+    assert_eq!(
+        duplicate_key,
+        Err((400, String::from("Error: key already in use")))
+    );
+    assert_eq!(all_keys.len(), 2);
+
+    assert_eq!(all_keys[0].key, "static-key-0123456789");
+    assert_ne!(all_keys[1].key, "hashed-key-0123456789");
+    assert_eq!(all_keys[1].key, ApiKey::hash("hashed-key-0123456789"));
+
+    let verified = ApiKey::verify(&all_keys, "hashed-key-0123456789").unwrap();
+    assert_eq!(verified.id, hashed_key.clone().unwrap());
+    assert!(ApiKey::verify(&all_keys, "static-key-0123456789").is_some());
+    assert!(ApiKey::verify(&all_keys, "unknown-key-0123456789").is_none());
+    assert!(ApiKey::verify(&all_keys, "").is_none());
+
+    save_all_api_keys(&all_keys, String::from(file_name), &String::new());
+    let fetched_keys = fetch_all_api_keys(file_name.to_string(), &String::new());
+    assert_eq!(fetched_keys, all_keys);
+
+    assert_eq!(ApiKey::delete(&mut all_keys, &static_key.unwrap()), Ok(()));
+    assert!(ApiKey::verify(&all_keys, "static-key-0123456789").is_none());
+}
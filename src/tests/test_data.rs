@@ -1,6 +1,7 @@
 #[cfg(test)]
 use crate::components::{
     data::{fetch_all_data, save_all_data, Data},
+    data_log::get_data_log_path,
     io::remove_file,
 };
 
@@ -8,6 +9,7 @@ use crate::components::{
 fn main() {
     let file_name: &str = "data/data_test.txt";
     remove_file(file_name.to_string());
+    remove_file(get_data_log_path(file_name));
 
     let mut all_data = fetch_all_data(file_name.to_string(), &String::new());
     println!("{:#?}", all_data);
@@ -0,0 +1,84 @@
+#[cfg(test)]
+use crate::components::{
+    data::{
+        commit_all_data, fetch_all_data, get_commit_journal_dir, get_commit_staged_path,
+        recover_data_commits, save_all_data, save_data_entries, stringify_data, Data,
+    },
+    data_log::{count_data_log, get_data_log_path, LogEntry, LogOperation},
+    io::{ensure_directory_exists, remove_file, save_file},
+};
+
+fn get_ids(all_data: &Vec<Data>) -> Vec<String> {
+    all_data.iter().map(|d| d.id.clone()).collect()
+}
+
+#[test]
+fn main() {
+    let first_file: &str = "data/data_commit_test.txt";
+    let second_file: &str = "data/data_commit_test2.txt";
+    let encryption_key = String::new();
+
+    for file_name in [first_file, second_file].iter() {
+        remove_file(file_name.to_string());
+        remove_file(get_data_log_path(file_name));
+    }
+
+    let mut all_data = Vec::<Data>::new();
+    Data::create(&mut all_data, "first", "test", "test", false).unwrap();
+    save_all_data(&all_data, first_file.to_string(), &encryption_key);
+
+    let mut logged_data = all_data.clone();
+    Data::create(&mut logged_data, "second", "test", "test", false).unwrap();
+    save_data_entries(
+        &logged_data,
+        &vec![LogEntry::new(
+            LogOperation::CREATE,
+            "second",
+            Some(logged_data[1].clone()),
+        )],
+        first_file.to_string(),
+        &encryption_key,
+    );
+    assert!(count_data_log(first_file) > 0);
+
+    let mut other_data = Vec::<Data>::new();
+    Data::create(&mut other_data, "other", "test", "test2", false).unwrap();
+
+    let all_staged = vec![
+        (first_file.to_string(), all_data.clone()),
+        (second_file.to_string(), other_data.clone()),
+    ];
+    assert_eq!(commit_all_data(&all_staged, &encryption_key), Ok(()));
+
+    assert_eq!(
+        get_ids(&fetch_all_data(first_file.to_string(), &encryption_key)),
+        get_ids(&all_data)
+    );
+    assert_eq!(
+        get_ids(&fetch_all_data(second_file.to_string(), &encryption_key)),
+        get_ids(&other_data)
+    );
+    assert_eq!(count_data_log(first_file), 0);
+    assert!(!std::path::Path::new(&get_commit_staged_path(first_file)).exists());
+
+    save_file(
+        get_commit_staged_path(second_file),
+        stringify_data(&logged_data),
+        &encryption_key,
+    );
+    let journal_dir = get_commit_journal_dir();
+    ensure_directory_exists(&journal_dir);
+    let journal_path = format!("{}/data_commit_test.txt", journal_dir);
+    std::fs::write(&journal_path, format!("{}\n{}", first_file, second_file)).unwrap();
+
+    assert!(recover_data_commits() >= 1);
+    assert!(!std::path::Path::new(&journal_path).exists());
+    assert_eq!(
+        get_ids(&fetch_all_data(second_file.to_string(), &encryption_key)),
+        get_ids(&logged_data)
+    );
+    assert_eq!(
+        get_ids(&fetch_all_data(first_file.to_string(), &encryption_key)),
+        get_ids(&all_data)
+    );
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+use crate::components::{
+    data::{compact_data, fetch_all_data, save_all_data, save_data_entries, Data},
+    data_log::{
+        count_data_log, diff_data, fetch_data_log, get_data_log_path, LogEntry, LogOperation,
+    },
+    io::{fetch_file, remove_file},
+};
+
+#[test]
+fn main() {
+    assert_eq!(
+        get_data_log_path("data/data_log_test.txt"),
+        "data/data_log_test.log"
+    );
+
+    let file_name: &str = "data/data_log_test.txt";
+    let encryption_key = String::new();
+    remove_file(file_name.to_string());
+    remove_file(get_data_log_path(file_name));
+
+    let mut all_data = fetch_all_data(file_name.to_string(), &encryption_key);
+    assert_eq!(all_data.len(), 0);
+
+    assert_eq!(
+        Data::create(&mut all_data, "one", "test", "test", false),
+        Ok(())
+    );
+    assert_eq!(
+        Data::create(&mut all_data, "two", "test", "test", false),
+        Ok(())
+    );
+    save_all_data(&all_data, file_name.to_string(), &encryption_key);
+
+    assert_eq!(fetch_file(file_name.to_string(), &encryption_key), "");
+    assert_eq!(fetch_data_log(file_name, &encryption_key).len(), 2);
+
+    let previous_data = all_data.clone();
+    assert_eq!(
+        Data::update_published(&mut all_data, &String::from("one"), true),
+        Ok(())
+    );
+    assert_eq!(Data::delete(&mut all_data, &String::from("two")), Ok(()));
+
+    let entries = diff_data(&previous_data, &all_data);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].operation, LogOperation::UPDATE);
+    assert_eq!(entries[1].operation, LogOperation::DELETE);
+    assert_eq!(entries[1].id, "two");
+
+    save_all_data(&all_data, file_name.to_string(), &encryption_key);
+    assert_eq!(fetch_data_log(file_name, &encryption_key).len(), 4);
+
+    let fetched_data = fetch_all_data(file_name.to_string(), &encryption_key);
+    assert_eq!(fetched_data.len(), 1);
+    assert_eq!(fetched_data[0].id, "one");
+    assert!(fetched_data[0].published);
+
+    compact_data(file_name.to_string(), &encryption_key);
+    assert_eq!(fetch_data_log(file_name, &encryption_key).len(), 0);
+
+    let compacted_data = fetch_all_data(file_name.to_string(), &encryption_key);
+    assert_eq!(compacted_data.len(), 1);
+    assert_eq!(
+        Data::to_string(compacted_data[0].clone()),
+        Data::to_string(fetched_data[0].clone())
+    );
+
+    let encrypted_file_name: &str = "data/data_log_encrypted_test.txt";
+    let encrypted_key = String::from("data-log-test-key");
+    remove_file(encrypted_file_name.to_string());
+    remove_file(get_data_log_path(encrypted_file_name));
+
+    save_all_data(&all_data, encrypted_file_name.to_string(), &encrypted_key);
+
+    let raw_log = fetch_file(get_data_log_path(encrypted_file_name), &String::new());
+    assert!(raw_log.starts_with(";|encrypted|;\n"));
+    assert!(!raw_log.contains("CREATE;one"));
+
+    let encrypted_data = fetch_all_data(encrypted_file_name.to_string(), &encrypted_key);
+    assert_eq!(encrypted_data.len(), 1);
+    assert_eq!(encrypted_data[0].id, "one");
+
+    let entries_file_name: &str = "data/data_log_entries_test.txt";
+    remove_file(entries_file_name.to_string());
+    remove_file(get_data_log_path(entries_file_name));
+
+    let mut entries_data = Vec::<Data>::new();
+    assert_eq!(
+        Data::create(&mut entries_data, "three", "test", "test", false),
+        Ok(())
+    );
+    save_data_entries(
+        &entries_data,
+        &vec![LogEntry::new(
+            LogOperation::CREATE,
+            "three",
+            Some(entries_data[0].clone()),
+        )],
+        entries_file_name.to_string(),
+        &encrypted_key,
+    );
+    assert_eq!(count_data_log(entries_file_name), 1);
+
+    assert_eq!(
+        Data::delete(&mut entries_data, &String::from("three")),
+        Ok(())
+    );
+    save_data_entries(
+        &entries_data,
+        &vec![LogEntry::new(LogOperation::DELETE, "three", None)],
+        entries_file_name.to_string(),
+        &encrypted_key,
+    );
+    assert_eq!(count_data_log(entries_file_name), 2);
+    assert_eq!(
+        fetch_all_data(entries_file_name.to_string(), &encrypted_key).len(),
+        0
+    );
+}
@@ -0,0 +1,204 @@
+#[cfg(test)]
+use crate::components::{
+    collection::Collection,
+    data::Data,
+    datapair::DataPair,
+    encryption::EncryptionKey,
+    raw_pair::{RawPair, StructurePair},
+    structure::Structure,
+};
+#[allow(unused_imports)]
+use crate::utils::field_encryption::{
+    check_encrypted_comparison, decrypt_field, encrypt_field, has_encrypted_data,
+    has_encrypted_structures, is_deterministic_field, is_encrypted_field, is_encrypted_property,
+    mask_data, restore_encrypted_data, reveal_data, reveal_rawpair, unmask_rawpair,
+    FieldEncryptionMode, FIELD_MASK,
+};
+#[allow(unused_imports)]
+use crate::utils::x::transaction::{StagedCollection, Transaction};
+
+#[test]
+fn main() {
+    assert_eq!(
+        FieldEncryptionMode::from("deterministic"),
+        FieldEncryptionMode::DETERMINISTIC
+    );
+    assert_eq!(
+        FieldEncryptionMode::from("_empty"),
+        FieldEncryptionMode::RANDOM
+    );
+
+    let data_key = EncryptionKey::generate(32).0;
+    let other_key = EncryptionKey::generate(32).0;
+
+    let random_first = encrypt_field("secret", &data_key, &FieldEncryptionMode::RANDOM);
+    let random_second = encrypt_field("secret", &data_key, &FieldEncryptionMode::RANDOM);
+    assert!(is_encrypted_field(&random_first));
+    assert!(!is_deterministic_field(&random_first));
+    assert_ne!(random_first, random_second);
+    assert_eq!(
+        decrypt_field(&random_first, &data_key),
+        Some(String::from("secret"))
+    );
+    assert_eq!(
+        decrypt_field(&random_second, &data_key),
+        Some(String::from("secret"))
+    );
+
+    let det_first = encrypt_field("secret", &data_key, &FieldEncryptionMode::DETERMINISTIC);
+    let det_second = encrypt_field("secret", &data_key, &FieldEncryptionMode::DETERMINISTIC);
+    assert!(is_deterministic_field(&det_first));
+    assert_eq!(det_first, det_second);
+    assert_eq!(
+        decrypt_field(&det_first, &data_key),
+        Some(String::from("secret"))
+    );
+
+    assert_eq!(decrypt_field("secret", &data_key), None);
+    assert_ne!(
+        decrypt_field(&det_first, &other_key),
+        Some(String::from("secret"))
+    );
+
+    let mut collection = Collection {
+        id: String::from("people"),
+        project_id: String::from("test"),
+        structures: vec![
+            Structure {
+                id: String::from("name"),
+                ..Default::default()
+            },
+            Structure {
+                id: String::from("ssn"),
+                encrypted: true,
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    assert!(has_encrypted_structures(&collection));
+
+    let data = Data {
+        id: String::from("person"),
+        project_id: String::from("test"),
+        collection_id: String::from("people"),
+        pairs: vec![
+            DataPair {
+                structure_id: String::from("name"),
+                value: String::from("Alice"),
+                ..Default::default()
+            },
+            DataPair {
+                structure_id: String::from("ssn"),
+                value: random_first.clone(),
+                ..Default::default()
+            },
+        ],
+        published: true,
+    };
+
+    let raw_pair = RawPair {
+        data_id: String::from("person"),
+        structures: vec![
+            StructurePair {
+                id: String::from("name"),
+                value: String::from("Alice"),
+                rtype: String::from("STRING"),
+            },
+            StructurePair {
+                id: String::from("ssn"),
+                value: random_first.clone(),
+                rtype: String::from("STRING"),
+            },
+        ],
+        custom_structures: vec![],
+        published: true,
+    };
+
+    let mut revealed = raw_pair.clone();
+    reveal_rawpair(&mut revealed, &collection, Some(&data_key));
+    assert_eq!(revealed.structures[0].value, "Alice");
+    assert_eq!(revealed.structures[1].value, "secret");
+
+    let mut masked = raw_pair.clone();
+    reveal_rawpair(&mut masked, &collection, None);
+    assert_eq!(masked.structures[0].value, "Alice");
+    assert_eq!(masked.structures[1].value, FIELD_MASK);
+
+    let mut wrong_key = raw_pair.clone();
+    reveal_rawpair(&mut wrong_key, &collection, Some(&other_key));
+    assert_ne!(wrong_key.structures[1].value, "secret");
+
+    unmask_rawpair(&mut masked, &collection, &data);
+    assert_eq!(masked.structures[1].value, random_first);
+
+    assert!(is_encrypted_property(&collection, "ssn"));
+    assert!(!is_encrypted_property(&collection, "name"));
+    assert!(!is_encrypted_property(&collection, "person.ssn"));
+
+    assert!(check_encrypted_comparison("ssn", false, true).is_err());
+    assert!(check_encrypted_comparison("ssn", true, false).is_err());
+    assert_eq!(check_encrypted_comparison("ssn", true, true), Ok(()));
+
+    let original_data = vec![data.clone()];
+    let mut revealed_data = original_data.clone();
+    assert!(has_encrypted_data(&revealed_data));
+    reveal_data(&mut revealed_data, &data_key);
+    assert!(!has_encrypted_data(&revealed_data));
+    assert_eq!(revealed_data[0].pairs[1].value, "secret");
+
+    restore_encrypted_data(&mut revealed_data, &original_data, &data_key);
+    assert_eq!(revealed_data[0].pairs[1].value, random_first);
+
+    let mut masked_data = original_data.clone();
+    reveal_data(&mut masked_data, &other_key);
+    assert_eq!(masked_data[0].pairs[1].value, FIELD_MASK);
+    restore_encrypted_data(&mut masked_data, &original_data, &data_key);
+    assert_eq!(masked_data[0].pairs[1].value, random_first);
+
+    let mut changed_data = original_data.clone();
+    reveal_data(&mut changed_data, &data_key);
+    changed_data[0].pairs[1].value = String::from("changed");
+    restore_encrypted_data(&mut changed_data, &original_data, &data_key);
+    assert!(is_encrypted_field(&changed_data[0].pairs[1].value));
+    assert!(!is_deterministic_field(&changed_data[0].pairs[1].value));
+    assert_eq!(
+        decrypt_field(&changed_data[0].pairs[1].value, &data_key),
+        Some(String::from("changed"))
+    );
+
+    let mut hidden_data = original_data.clone();
+    mask_data(&mut hidden_data);
+    assert_eq!(hidden_data[0].pairs[1].value, FIELD_MASK);
+    restore_encrypted_data(&mut hidden_data, &original_data, &data_key);
+    assert_eq!(hidden_data[0].pairs[1].value, random_first);
+
+    let mut transaction = Transaction::new(false, false);
+    transaction.collections.push(StagedCollection {
+        project_id: data.project_id.clone(),
+        collection_id: data.collection_id.clone(),
+        checksum: 0,
+        original: original_data.clone(),
+        data: None,
+    });
+    assert_eq!(
+        transaction.check_comparison(&data.id, "ssn", true),
+        Err((
+            403,
+            String::from(
+                "Error: Encrypted property 'ssn' can only be compared in authenticated routes"
+            )
+        ))
+    );
+    assert_eq!(transaction.check_comparison(&data.id, "name", true), Ok(()));
+
+    transaction.reveal = true;
+    assert!(transaction.check_comparison(&data.id, "ssn", true).is_err());
+
+    collection.structures[1].encrypted = false;
+    assert!(!has_encrypted_structures(&collection));
+
+    let mut plain = raw_pair.clone();
+    reveal_rawpair(&mut plain, &collection, None);
+    assert_eq!(plain.structures[1].value, random_first);
+}
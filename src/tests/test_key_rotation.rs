@@ -0,0 +1,81 @@
+#[cfg(test)]
+use crate::components::{
+    encryption::EncryptionKey,
+    io::{fetch_file, remove_file, save_file},
+};
+#[allow(unused_imports)]
+use crate::utils::key_rotation::{
+    acquire_rotation_gate, commit_file, get_staged_path, persist_password, stage_file,
+    RotationPhase, RotationState,
+};
+
+#[test]
+fn main() {
+    let state = RotationState {
+        phase: RotationPhase::COMMIT,
+        old_key: EncryptionKey::generate(32).0,
+        new_key: EncryptionKey::generate(32).0,
+    };
+    let state_str = RotationState::to_string(state.clone());
+    assert_eq!(RotationState::from_string(&state_str), Some(state.clone()));
+    assert_eq!(
+        RotationState::from_string("phase=DONE\nold_key=a\nnew_key=b"),
+        None
+    );
+    assert_eq!(RotationState::from_string("phase=STAGE"), None);
+
+    let file_name: &str = "data/key_rotation_test.txt";
+    let staged_name = get_staged_path(file_name);
+    remove_file(file_name.to_string());
+    remove_file(staged_name.clone());
+
+    let content = String::from("first line\nsecond line\nthird line");
+    save_file(file_name.to_string(), content.clone(), &state.old_key);
+
+    assert!(stage_file(file_name, &state.new_key, &state.old_key).is_err());
+
+    assert_eq!(
+        stage_file(file_name, &state.old_key, &state.new_key),
+        Ok(true)
+    );
+    assert_eq!(fetch_file(file_name.to_string(), &state.old_key), content);
+    assert_eq!(fetch_file(staged_name.clone(), &state.new_key), content);
+
+    assert_eq!(commit_file(file_name), Ok(true));
+    assert!(!std::path::Path::new(&staged_name).exists());
+    assert_eq!(fetch_file(file_name.to_string(), &state.new_key), content);
+    assert_eq!(commit_file(file_name), Ok(false));
+
+    save_file(file_name.to_string(), content.clone(), &String::new());
+    assert_eq!(
+        stage_file(file_name, &state.old_key, &state.new_key),
+        Ok(false)
+    );
+    assert!(!std::path::Path::new(&staged_name).exists());
+
+    remove_file(file_name.to_string());
+
+    {
+        let _first = acquire_rotation_gate();
+        let _second = acquire_rotation_gate();
+        save_file(file_name.to_string(), content.clone(), &String::new());
+    }
+    remove_file(file_name.to_string());
+
+    let env_name: &str = "data/key_rotation_test.env";
+    std::fs::write(env_name, "CURRENT_PATH=/tmp\nTMP_PASSWORD=Test123*\n").unwrap();
+
+    assert_eq!(persist_password(env_name, "NewPass123*"), Ok(()));
+    assert_eq!(
+        std::fs::read_to_string(env_name).unwrap(),
+        "CURRENT_PATH=/tmp\nTMP_PASSWORD='NewPass123*'\n"
+    );
+
+    assert_eq!(persist_password(env_name, "it's$\"pass"), Ok(()));
+    assert_eq!(
+        std::fs::read_to_string(env_name).unwrap(),
+        "CURRENT_PATH=/tmp\nTMP_PASSWORD=\"it's\\$\\\"pass\"\n"
+    );
+
+    remove_file(env_name.to_string());
+}
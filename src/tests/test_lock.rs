@@ -0,0 +1,90 @@
+#[allow(unused_imports)]
+use crate::utils::lock::{
+    acquire_read_lock, acquire_write_lock, fetch_all_locks, get_instance_id, get_lock_directory,
+    is_stale, release_stale_locks, LockInfo, LockMode,
+};
+#[cfg(test)]
+use chrono::prelude::*;
+
+#[test]
+fn main() {
+    let info = LockInfo {
+        name: String::from("lock_test_info"),
+        mode: LockMode::READ,
+        pid: std::process::id(),
+        instance: get_instance_id(),
+        acquired_at: Utc::now().timestamp(),
+        stale: false,
+    };
+
+    let info_str = LockInfo::to_string(info.clone());
+    assert_eq!(LockInfo::from_string(&info_str), Some(info.clone()));
+    assert_eq!(LockInfo::from_string("SHARED;1;2;lock_test_info"), None);
+
+    let mut old_info = info.clone();
+    old_info.acquired_at = Utc::now().timestamp() - 3600;
+    assert!(is_stale(&old_info));
+    assert!(!is_stale(&info));
+
+    let mut previous_instance = info.clone();
+    previous_instance.instance = String::from("previous_instance");
+    assert!(is_stale(&previous_instance));
+
+    let legacy_info = LockInfo::from_string(&format!(
+        "WRITE;{};{};lock_test_legacy",
+        std::process::id(),
+        Utc::now().timestamp()
+    ))
+    .unwrap();
+    assert_eq!(legacy_info.instance, "");
+    assert_eq!(legacy_info.name, "lock_test_legacy");
+    assert!(legacy_info.stale);
+
+    let write_path = format!("{}/lock_test_write.write.lock", get_lock_directory());
+    {
+        let _lock = acquire_write_lock("lock_test_write");
+        assert!(std::path::Path::new(&write_path).exists());
+
+        let held = fetch_all_locks()
+            .into_iter()
+            .filter(|l| l.name == "lock_test_write")
+            .collect::<Vec<LockInfo>>();
+        assert_eq!(held.len(), 1);
+        assert_eq!(held[0].mode, LockMode::WRITE);
+        assert_eq!(held[0].pid, std::process::id());
+        assert_eq!(held[0].instance, get_instance_id());
+    }
+    assert!(!std::path::Path::new(&write_path).exists());
+
+    let read_path = format!(
+        "{}/lock_test_read.read.{}.lock",
+        get_lock_directory(),
+        std::process::id()
+    );
+    {
+        let _first = acquire_read_lock("lock_test_read");
+        let second = acquire_read_lock("lock_test_read");
+        assert!(std::path::Path::new(&read_path).exists());
+
+        drop(second);
+        assert!(std::path::Path::new(&read_path).exists());
+    }
+    assert!(!std::path::Path::new(&read_path).exists());
+
+    let stale_path = format!("{}/lock_test_stale.write.lock", get_lock_directory());
+    std::fs::write(&stale_path, LockInfo::to_string(old_info.clone())).unwrap();
+    assert!(release_stale_locks() >= 1);
+    assert!(!std::path::Path::new(&stale_path).exists());
+
+    std::fs::write(&stale_path, LockInfo::to_string(old_info)).unwrap();
+    {
+        let _lock = acquire_write_lock("lock_test_stale");
+        let held = fetch_all_locks()
+            .into_iter()
+            .filter(|l| l.name == "lock_test_stale")
+            .collect::<Vec<LockInfo>>();
+        assert_eq!(held.len(), 1);
+        assert!(!held[0].stale);
+    }
+    assert!(!std::path::Path::new(&stale_path).exists());
+}
@@ -0,0 +1,170 @@
+#[allow(unused_imports)]
+use crate::components::routing::{core::core_auth_jwt::AuthJWT, mod_route::RouteComponent};
+#[cfg(test)]
+use crate::components::{
+    cron::CronExpression,
+    io::remove_file,
+    schedule::{fetch_all_schedules, save_all_schedules, CatchUpPolicy, Schedule},
+};
+#[allow(unused_imports)]
+use crate::utils::scheduler::{get_due_runs, requires_auth};
+
+// 2024-01-01 00:00:00 UTC, a Monday
+#[allow(dead_code)]
+const START: i64 = 1704067200;
+
+#[test]
+fn main() {
+    let file_name: &str = "data/schedules_test.txt";
+    remove_file(file_name.to_string());
+
+    let mut all_schedules = fetch_all_schedules(file_name.to_string(), &String::new());
+
+    let test_schedule = Schedule::create(
+        &mut all_schedules,
+        "daily_report",
+        "*/15 9-17 * * 1-5",
+        CatchUpPolicy::ONCE,
+        "{\"type\": \"summary\"}",
+    );
+    assert!(test_schedule.is_ok());
+
+    let test_schedule2 = Schedule::create(
+        &mut all_schedules,
+        "daily_report",
+        "*/15 9-17 * *",
+        CatchUpPolicy::SKIP,
+        "{}",
+    );
+    assert_eq!(
+        test_schedule2,
+        Err((
+            400,
+            String::from("Error: cron expression should contain exactly 5 fields")
+        ))
+    );
+
+    let test_schedule2 = Schedule::create(
+        &mut all_schedules,
+        "daily_report",
+        "0 0 * * *",
+        CatchUpPolicy::SKIP,
+        "[1, 2]",
+    );
+    assert_eq!(
+        test_schedule2,
+        Err((
+            400,
+            String::from("Error: body should be a valid JSON object")
+        ))
+    );
+    assert_eq!(all_schedules.len(), 1);
+
+    let schedule_id = test_schedule.unwrap();
+    let stringified = Schedule::to_string(all_schedules[0].clone());
+    assert_eq!(Schedule::from_string(&stringified), all_schedules[0]);
+    assert_eq!(all_schedules[0].body, "{\"type\":\"summary\"}");
+
+    save_all_schedules(&all_schedules, String::from(file_name), &String::new());
+    let fetched_schedules = fetch_all_schedules(file_name.to_string(), &String::new());
+    assert_eq!(fetched_schedules, all_schedules);
+
+    assert_eq!(Schedule::delete(&mut all_schedules, &schedule_id), Ok(()));
+    assert_eq!(all_schedules.len(), 0);
+}
+
+#[test]
+fn cron() {
+    let cron = CronExpression::parse("*/20 1,3 * * *").unwrap();
+    assert_eq!(cron.minutes, vec![0, 20, 40]);
+    assert_eq!(cron.hours, vec![1, 3]);
+
+    let weekly = CronExpression::parse("@weekly").unwrap();
+    assert_eq!(weekly.days_of_week, vec![0]);
+    assert_eq!(
+        CronExpression::parse("0 0 * * 7").unwrap().days_of_week,
+        vec![0]
+    );
+
+    assert!(CronExpression::parse("60 * * * *").is_err());
+    assert!(CronExpression::parse("5-1 * * * *").is_err());
+    assert!(CronExpression::parse("*/0 * * * *").is_err());
+
+    let occurrences = cron.occurrences(START, START + 86400, 100);
+    assert_eq!(
+        occurrences,
+        vec![
+            START + 3600,
+            START + 3600 + 1200,
+            START + 3600 + 2400,
+            START + 3 * 3600,
+            START + 3 * 3600 + 1200,
+            START + 3 * 3600 + 2400,
+        ]
+    );
+
+    // day of month OR day of week when both are restricted
+    let cron = CronExpression::parse("0 0 15 * 0").unwrap();
+    let occurrences = cron.occurrences(START, START + 31 * 86400, 100);
+    assert_eq!(
+        occurrences,
+        vec![
+            START + 6 * 86400,
+            START + 13 * 86400,
+            START + 14 * 86400,
+            START + 20 * 86400,
+            START + 27 * 86400,
+        ]
+    );
+}
+
+#[test]
+fn catch_up() {
+    let mut schedule = Schedule::default();
+    schedule.cron = String::from("0 * * * *");
+    schedule.last_run = START;
+
+    let now = START + 5 * 3600 + 30;
+
+    schedule.catch_up = CatchUpPolicy::SKIP;
+    assert_eq!(get_due_runs(&schedule, now, 10), vec![START + 5 * 3600]);
+    assert_eq!(get_due_runs(&schedule, now + 600, 10), Vec::<i64>::new());
+
+    schedule.catch_up = CatchUpPolicy::ONCE;
+    assert_eq!(get_due_runs(&schedule, now + 600, 10), vec![now + 600]);
+
+    schedule.catch_up = CatchUpPolicy::ALL;
+    assert_eq!(get_due_runs(&schedule, now, 10).len(), 5);
+    assert_eq!(
+        get_due_runs(&schedule, now, 3),
+        vec![START + 3600, START + 7200, START + 10800]
+    );
+}
+
+#[test]
+fn auth() {
+    let mut route = RouteComponent::default();
+    assert!(!requires_auth(&route));
+
+    route.auth_api_key = true;
+    assert!(requires_auth(&route));
+
+    route.auth_api_key = false;
+    route.auth_jwt = Some(AuthJWT {
+        active: false,
+        field: String::from("uid"),
+        ref_col: String::from("users"),
+        claims: vec![],
+        rules: vec![],
+    });
+    assert!(!requires_auth(&route));
+
+    route.auth_jwt = Some(AuthJWT {
+        active: true,
+        field: String::from("uid"),
+        ref_col: String::from("users"),
+        claims: vec![],
+        rules: vec![],
+    });
+    assert!(requires_auth(&route));
+}
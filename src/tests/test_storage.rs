@@ -0,0 +1,63 @@
+#[cfg(test)]
+use crate::components::{
+    api_key::{stringify_api_keys, unwrap_api_keys, ApiKey},
+    encryption::EncryptionKey,
+    io::remove_file,
+};
+#[allow(unused_imports)]
+use crate::utils::storage::{
+    open_redis_value, seal_redis_value, FileStorage, MemoryStorage, StorageBackend,
+    StorageLocation, StorageType,
+};
+
+#[test]
+fn main() {
+    assert_eq!(StorageType::from("memory"), StorageType::MEMORY);
+    assert_eq!(StorageType::from(" Redis "), StorageType::REDIS);
+    assert_eq!(StorageType::from("_empty"), StorageType::FILE);
+    assert_eq!(StorageType::to(StorageType::MEMORY), "MEMORY");
+
+    let mut all_keys = Vec::<ApiKey>::new();
+    assert!(ApiKey::create(&mut all_keys, "storage", "storage-key-0123456789", true).is_ok());
+
+    let file_name: &str = "data/storage_test.txt";
+    remove_file(file_name.to_string());
+
+    let file_location = StorageLocation::new("", file_name, "");
+    assert_eq!(FileStorage.fetch(&file_location), "");
+
+    FileStorage.save(&file_location, stringify_api_keys(&all_keys));
+    assert_eq!(unwrap_api_keys(FileStorage.fetch(&file_location)), all_keys);
+
+    let memory_location = StorageLocation::new("", "data/storage_memory_test.txt", "");
+    assert_eq!(MemoryStorage.fetch(&memory_location), "");
+
+    MemoryStorage.save(&memory_location, stringify_api_keys(&all_keys));
+    assert_eq!(
+        unwrap_api_keys(MemoryStorage.fetch(&memory_location)),
+        all_keys
+    );
+    assert!(!std::path::Path::new("data/storage_memory_test.txt").exists());
+
+    MemoryStorage.save(&memory_location, String::new());
+    assert_eq!(MemoryStorage.fetch(&memory_location), "");
+
+    let seeded_location = StorageLocation::new("", file_name, "");
+    assert_eq!(
+        unwrap_api_keys(MemoryStorage.fetch(&seeded_location)),
+        all_keys
+    );
+
+    let data_key = EncryptionKey::generate(32).0;
+    let other_key = EncryptionKey::generate(32).0;
+    let raw_keys = stringify_api_keys(&all_keys);
+
+    let sealed = seal_redis_value(&raw_keys, &data_key);
+    assert_ne!(sealed, raw_keys);
+    assert!(!sealed.contains("storage-key-0123456789"));
+    assert_eq!(open_redis_value(&sealed, &data_key), Some(raw_keys.clone()));
+    assert_eq!(open_redis_value(&sealed, &other_key), None);
+
+    assert_eq!(seal_redis_value(&raw_keys, ""), raw_keys);
+    assert_eq!(open_redis_value(&raw_keys, ""), Some(raw_keys.clone()));
+}
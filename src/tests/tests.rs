@@ -27,11 +27,35 @@ mod test_data;
 #[path = "test_event.rs"]
 mod test_event;
 
+#[path = "test_schedule.rs"]
+mod test_schedule;
+
+#[path = "test_api_key.rs"]
+mod test_api_key;
+
+#[path = "test_storage.rs"]
+mod test_storage;
+
+#[path = "test_data_log.rs"]
+mod test_data_log;
+
+#[path = "test_lock.rs"]
+mod test_lock;
+
+#[path = "test_key_rotation.rs"]
+mod test_key_rotation;
+
+#[path = "test_field_encryption.rs"]
+mod test_field_encryption;
+
 #[path = "test_datapair.rs"]
 mod test_datapair;
 
 #[path = "test_constraint.rs"]
 mod test_constraint;
 
+#[path = "test_data_commit.rs"]
+mod test_data_commit;
+
 #[path = "../components/routing/tests/tests.rs"]
 pub mod test_routing;
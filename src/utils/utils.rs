@@ -4,6 +4,9 @@ pub mod encryption_key;
 #[path = "utils_redis.rs"]
 pub mod redis;
 
+#[path = "utils_rate_limit.rs"]
+pub mod rate_limit;
+
 #[path = "utils_mapping.rs"]
 pub mod mapping;
 
@@ -31,6 +34,18 @@ pub mod event;
 #[path = "utils_route.rs"]
 pub mod route;
 
+#[path = "utils_api_key.rs"]
+pub mod api_key;
+
+#[path = "utils_route_cache.rs"]
+pub mod route_cache;
+
+#[path = "utils_schedule.rs"]
+pub mod schedule;
+
+#[path = "utils_scheduler.rs"]
+pub mod scheduler;
+
 #[path = "utils_io.rs"]
 pub mod io;
 
@@ -48,3 +63,15 @@ pub mod repl;
 
 #[path = "utils_x/utils_x.rs"]
 pub mod x;
+
+#[path = "utils_storage.rs"]
+pub mod storage;
+
+#[path = "utils_lock.rs"]
+pub mod lock;
+
+#[path = "utils_key_rotation.rs"]
+pub mod key_rotation;
+
+#[path = "utils_field_encryption.rs"]
+pub mod field_encryption;
@@ -0,0 +1,31 @@
+use crate::components::{
+    api_key::{stringify_api_keys, unwrap_api_keys, ApiKey},
+    mapping::Mapping,
+};
+
+use super::storage::{get_storage_backend, get_tmp_encryption_key, StorageLocation};
+
+pub fn auto_fetch_all_api_keys(
+    mappings: &Vec<Mapping>,
+    project_id: &str,
+) -> Result<Vec<ApiKey>, String> {
+    let location =
+        StorageLocation::project(project_id, "api_keys", &get_tmp_encryption_key(mappings));
+
+    Ok(unwrap_api_keys(
+        get_storage_backend(mappings).fetch(&location),
+    ))
+}
+
+pub fn auto_save_all_api_keys(
+    mappings: &Vec<Mapping>,
+    project_id: &str,
+    api_keys: &Vec<ApiKey>,
+) -> Result<(), String> {
+    let location =
+        StorageLocation::project(project_id, "api_keys", &get_tmp_encryption_key(mappings));
+
+    get_storage_backend(mappings).save(&location, stringify_api_keys(api_keys));
+
+    Ok(())
+}
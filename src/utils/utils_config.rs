@@ -1,43 +1,28 @@
 use crate::components::{
-    config::{fetch_all_configs, save_all_configs, Config},
-    mapping::{get_file_name, Mapping},
+    config::{stringify_configs, unwrap_configs, Config},
+    mapping::Mapping,
 };
 
-use super::encryption_key::get_encryption_key;
+use super::storage::{FileStorage, StorageBackend, StorageLocation};
 
 pub fn auto_fetch_all_configs(mappings: &Vec<Mapping>) -> Result<Vec<Config>, String> {
-    let all_configs_path = get_file_name("configs", mappings);
-
-    let tmp_password = match std::env::var("TMP_PASSWORD") {
-        Ok(pass) => pass,
-        _ => "password".to_string(),
+    let mut location = match StorageLocation::component(mappings, "configs") {
+        Ok(l) => l,
+        Err(e) => return Err(e),
     };
+    location.key = String::new();
 
-    if let Err(e) = all_configs_path {
-        return Err(e);
-    }
-
-    let all_configs = fetch_all_configs(
-        all_configs_path.clone().unwrap(),
-        &&get_encryption_key(&mappings, &tmp_password),
-    );
-
-    Ok(all_configs)
+    Ok(unwrap_configs(FileStorage.fetch(&location)))
 }
 
 pub fn auto_save_all_configs(mappings: &Vec<Mapping>, configs: &Vec<Config>) -> Result<(), String> {
-    let all_configs_path = match get_file_name("configs", mappings) {
-        Ok(path) => path,
+    let mut location = match StorageLocation::component(mappings, "configs") {
+        Ok(l) => l,
         Err(e) => return Err(e),
     };
+    location.key = String::new();
 
-    let tmp_password = match std::env::var("TMP_PASSWORD") {
-        Ok(pass) => pass,
-        _ => "password".to_string(),
-    };
-
-    let encryption_key = get_encryption_key(mappings, &tmp_password);
-    save_all_configs(configs, all_configs_path, &encryption_key);
+    FileStorage.save(&location, stringify_configs(configs));
 
     Ok(())
 }
@@ -1,10 +1,17 @@
+use rocket::tokio::time::{sleep, Duration};
+
 use crate::components::{
-    data::{fetch_all_data, save_all_data, stringify_data, unwrap_data, Data},
+    data::{compact_data, recover_data_commits, Data},
+    data_log::LogEntry,
     mapping::Mapping,
 };
 
 use super::{
-    encryption_key::get_encryption_key, io::get_root_data_dir, redis::get_redis_connection,
+    collection::auto_fetch_all_collections,
+    config::get_config_value,
+    mapping::auto_fetch_all_mappings,
+    route_cache::invalidate_route_cache,
+    storage::{get_storage_backend, StorageLocation},
 };
 
 pub fn auto_fetch_all_data(
@@ -12,72 +19,76 @@ pub fn auto_fetch_all_data(
     project_id: &str,
     collection_id: &str,
 ) -> Result<Vec<Data>, String> {
-    let connection = get_redis_connection();
-
-    if let Ok(mut con) = connection {
-        let stringified_data = match redis::pipe()
-            .cmd("GET")
-            .arg(format!("data_{}_{}", project_id, collection_id))
-            .query(&mut con)
-        {
-            Ok(d) => Some(d),
-            _ => None,
-        };
-
-        if let Some(sd) = stringified_data {
-            return Ok(unwrap_data(sd));
-        }
-    }
+    let location = StorageLocation::collection(mappings, project_id, collection_id);
 
-    let all_data_path = format!(
-        "{}/data/projects/{}/{}/data.txt",
-        get_root_data_dir(),
-        project_id,
-        collection_id
-    );
+    Ok(get_storage_backend(mappings).fetch_data(&location))
+}
 
-    let tmp_password = match std::env::var("TMP_PASSWORD") {
-        Ok(pass) => pass,
-        _ => "password".to_string(),
-    };
+pub fn auto_save_all_data(
+    mappings: &Vec<Mapping>,
+    project_id: &str,
+    collection_id: &str,
+    data: &Vec<Data>,
+) -> Result<(), String> {
+    let location = StorageLocation::collection(mappings, project_id, collection_id);
 
-    let all_data = fetch_all_data(
-        all_data_path.clone(),
-        &get_encryption_key(&mappings, &tmp_password),
-    );
+    get_storage_backend(mappings).save_data(&location, data);
+    invalidate_route_cache(project_id, collection_id);
 
-    Ok(all_data)
+    Ok(())
 }
 
-pub fn auto_save_all_data(
+pub fn auto_save_data_entries(
     mappings: &Vec<Mapping>,
     project_id: &str,
     collection_id: &str,
     data: &Vec<Data>,
+    entries: &Vec<LogEntry>,
 ) -> Result<(), String> {
-    let all_data_path = format!(
-        "{}/data/projects/{}/{}/data.txt",
-        get_root_data_dir(),
-        project_id,
-        collection_id
-    );
-
-    let tmp_password = match std::env::var("TMP_PASSWORD") {
-        Ok(pass) => pass,
-        _ => "password".to_string(),
-    };
+    let location = StorageLocation::collection(mappings, project_id, collection_id);
 
-    let encryption_key = get_encryption_key(mappings, &tmp_password);
+    get_storage_backend(mappings).save_data_entries(&location, data, entries);
+    invalidate_route_cache(project_id, collection_id);
 
-    let connection = get_redis_connection();
-    if let Ok(mut con) = connection {
-        redis::cmd("SET")
-            .arg(format!("data_{}_{}", project_id, collection_id))
-            .arg(stringify_data(data))
-            .execute(&mut con);
+    Ok(())
+}
+
+pub fn auto_recover_data_commits() -> bool {
+    let recovered = recover_data_commits();
+    if recovered > 0 {
+        println!("Recovered {} interrupted data commit(s)", recovered);
     }
 
-    save_all_data(data, all_data_path, &encryption_key);
+    true
+}
 
-    Ok(())
+pub fn auto_compact_all_data(mappings: &Vec<Mapping>) {
+    let all_collections = match auto_fetch_all_collections(mappings) {
+        Ok(c) => c,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    for collection in all_collections.iter() {
+        let location =
+            StorageLocation::collection(mappings, &collection.project_id, &collection.id);
+        compact_data(location.path, &location.encryption_key);
+    }
+}
+
+pub async fn start_data_compaction() {
+    println!("Data compaction started");
+
+    loop {
+        let mappings = auto_fetch_all_mappings();
+        let interval = get_config_value(&mappings, "DATA_LOG_COMPACT_INTERVAL", "3600")
+            .parse::<u64>()
+            .unwrap_or(3600);
+
+        sleep(Duration::from_secs(std::cmp::max(interval, 60))).await;
+
+        auto_compact_all_data(&auto_fetch_all_mappings());
+    }
 }
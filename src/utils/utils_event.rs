@@ -1,64 +1,28 @@
 use crate::components::{
-    event::{fetch_all_events, save_all_events, stringify_events, unwrap_events, Event},
-    mapping::{get_file_name, Mapping},
+    event::{stringify_events, unwrap_events, Event},
+    mapping::Mapping,
 };
 
-use super::{encryption_key::get_encryption_key, redis::get_redis_connection};
+use super::storage::{get_storage_backend, StorageLocation};
 
 pub fn auto_fetch_all_events(mappings: &Vec<Mapping>) -> Result<Vec<Event>, String> {
-    let connection = get_redis_connection();
-
-    if let Ok(mut con) = connection {
-        let stringified_events = match redis::pipe().cmd("GET").arg("events").query(&mut con) {
-            Ok(e) => Some(e),
-            _ => None,
-        };
-
-        if let Some(se) = stringified_events {
-            return Ok(unwrap_events(se));
-        }
-    }
-
-    let all_events_path = match get_file_name("events", mappings) {
-        Ok(path) => path,
+    let location = match StorageLocation::component(mappings, "events") {
+        Ok(l) => l,
         Err(e) => return Err(e),
     };
 
-    let tmp_password = match std::env::var("TMP_PASSWORD") {
-        Ok(pass) => pass,
-        _ => "password".to_string(),
-    };
-
-    let all_events = fetch_all_events(
-        all_events_path.clone(),
-        &get_encryption_key(&mappings, &tmp_password),
-    );
-
-    Ok(all_events)
+    Ok(unwrap_events(
+        get_storage_backend(mappings).fetch(&location),
+    ))
 }
 
 pub fn auto_save_all_events(mappings: &Vec<Mapping>, events: &Vec<Event>) -> Result<(), String> {
-    let all_events_path = match get_file_name("events", mappings) {
-        Ok(path) => path,
+    let location = match StorageLocation::component(mappings, "events") {
+        Ok(l) => l,
         Err(e) => return Err(e),
     };
 
-    let tmp_password = match std::env::var("TMP_PASSWORD") {
-        Ok(pass) => pass,
-        _ => "password".to_string(),
-    };
-
-    let encryption_key = get_encryption_key(mappings, &tmp_password);
-
-    let connection = get_redis_connection();
-    if let Ok(mut con) = connection {
-        redis::cmd("SET")
-            .arg("events")
-            .arg(stringify_events(events))
-            .execute(&mut con);
-    }
-
-    save_all_events(events, all_events_path, &encryption_key);
+    get_storage_backend(mappings).save(&location, stringify_events(events));
 
     Ok(())
 }
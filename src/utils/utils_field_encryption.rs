@@ -0,0 +1,353 @@
+use std::fmt;
+
+use crate::components::{
+    collection::Collection,
+    data::Data,
+    encryption::EncryptionKey,
+    mapping::Mapping,
+    raw_pair::{RawPair, StructurePair},
+    structure::Structure,
+};
+
+use super::{
+    config::get_config_value,
+    lock::acquire_write_lock,
+    storage::{get_storage_backend, get_tmp_encryption_key, StorageLocation},
+};
+
+pub const FIELD_MASK: &str = "********";
+const RANDOM_PREFIX: &str = "enc::";
+const DETERMINISTIC_PREFIX: &str = "det::";
+const NONCE_LENGTH: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldEncryptionMode {
+    RANDOM,
+    DETERMINISTIC,
+}
+
+impl Default for FieldEncryptionMode {
+    fn default() -> Self {
+        FieldEncryptionMode::RANDOM
+    }
+}
+
+impl fmt::Display for FieldEncryptionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode_txt = match self {
+            FieldEncryptionMode::RANDOM => "RANDOM".to_string(),
+            FieldEncryptionMode::DETERMINISTIC => "DETERMINISTIC".to_string(),
+        };
+
+        write!(f, "{}", mode_txt)
+    }
+}
+
+impl FieldEncryptionMode {
+    pub fn to(mode: FieldEncryptionMode) -> String {
+        return match mode.clone() {
+            FieldEncryptionMode::RANDOM => "RANDOM".to_string(),
+            FieldEncryptionMode::DETERMINISTIC => "DETERMINISTIC".to_string(),
+        };
+    }
+
+    pub fn from(mode_txt: &str) -> FieldEncryptionMode {
+        return match mode_txt.trim().to_uppercase().as_str() {
+            "RANDOM" => FieldEncryptionMode::RANDOM,
+            "DETERMINISTIC" => FieldEncryptionMode::DETERMINISTIC,
+            _ => FieldEncryptionMode::RANDOM,
+        };
+    }
+}
+
+pub fn get_field_encryption_mode(mappings: &Vec<Mapping>) -> FieldEncryptionMode {
+    FieldEncryptionMode::from(&get_config_value(
+        mappings,
+        "FIELD_ENCRYPTION_MODE",
+        "RANDOM",
+    ))
+}
+
+pub fn auto_fetch_data_key(mappings: &Vec<Mapping>, project_id: &str) -> String {
+    let _lock = acquire_write_lock(&format!("data_key_{}", project_id));
+
+    let location =
+        StorageLocation::project(project_id, "data_key", &get_tmp_encryption_key(mappings));
+    let backend = get_storage_backend(mappings);
+
+    let data_key = backend.fetch(&location).trim().to_string();
+    if data_key.len() > 0 {
+        return data_key;
+    }
+
+    let new_data_key = EncryptionKey::generate(32).0;
+    backend.save(&location, new_data_key.clone());
+
+    new_data_key
+}
+
+pub fn is_encrypted_field(value: &str) -> bool {
+    value.starts_with(RANDOM_PREFIX) || value.starts_with(DETERMINISTIC_PREFIX)
+}
+
+pub fn is_deterministic_field(value: &str) -> bool {
+    value.starts_with(DETERMINISTIC_PREFIX)
+}
+
+pub fn encrypt_field(value: &str, data_key: &str, mode: &FieldEncryptionMode) -> String {
+    match mode {
+        FieldEncryptionMode::RANDOM => format!(
+            "{}{}",
+            RANDOM_PREFIX,
+            EncryptionKey::encrypt(
+                format!("{}{}", EncryptionKey::generate_block(NONCE_LENGTH), value),
+                data_key
+            )
+        ),
+        FieldEncryptionMode::DETERMINISTIC => format!(
+            "{}{}",
+            DETERMINISTIC_PREFIX,
+            EncryptionKey::encrypt(value.to_string(), data_key)
+        ),
+    }
+}
+
+pub fn decrypt_field(value: &str, data_key: &str) -> Option<String> {
+    if let Some(ciphertext) = value.strip_prefix(RANDOM_PREFIX) {
+        return match EncryptionKey::decrypt(ciphertext.to_string(), data_key) {
+            Ok(d) if d.0.chars().count() >= NONCE_LENGTH => {
+                Some(d.0.chars().skip(NONCE_LENGTH).collect::<String>())
+            }
+            _ => None,
+        };
+    }
+
+    if let Some(ciphertext) = value.strip_prefix(DETERMINISTIC_PREFIX) {
+        return match EncryptionKey::decrypt(ciphertext.to_string(), data_key) {
+            Ok(d) => Some(d.0),
+            _ => None,
+        };
+    }
+
+    None
+}
+
+pub fn has_encrypted_structures(collection: &Collection) -> bool {
+    collection.structures.iter().any(|s| s.encrypted)
+        || collection
+            .custom_structures
+            .iter()
+            .any(|cs| cs.structures.iter().any(|s| s.encrypted))
+}
+
+pub fn get_reveal_key(
+    mappings: &Vec<Mapping>,
+    collection: &Collection,
+    can_reveal: bool,
+) -> Option<String> {
+    if !can_reveal || !has_encrypted_structures(collection) {
+        return None;
+    }
+
+    Some(auto_fetch_data_key(mappings, &collection.project_id))
+}
+
+fn is_encrypted_structure(structures: &Vec<Structure>, structure_id: &str) -> bool {
+    structures
+        .iter()
+        .any(|s| s.id == structure_id && s.encrypted)
+}
+
+fn reveal_structures(
+    structure_pairs: &mut Vec<StructurePair>,
+    structures: &Vec<Structure>,
+    data_key: Option<&str>,
+) {
+    for structure_pair in structure_pairs.iter_mut() {
+        if structure_pair.value.len() < 1 || !is_encrypted_structure(structures, &structure_pair.id)
+        {
+            continue;
+        }
+
+        structure_pair.value = match data_key {
+            Some(key) => match decrypt_field(&structure_pair.value, key) {
+                Some(v) => v,
+                None if is_encrypted_field(&structure_pair.value) => FIELD_MASK.to_string(),
+                None => structure_pair.value.clone(),
+            },
+            None => FIELD_MASK.to_string(),
+        };
+    }
+}
+
+pub fn reveal_rawpair(raw_pair: &mut RawPair, collection: &Collection, data_key: Option<&str>) {
+    reveal_structures(&mut raw_pair.structures, &collection.structures, data_key);
+
+    for custom_structure_pair in raw_pair.custom_structures.iter_mut() {
+        for custom_structure in collection.custom_structures.iter() {
+            if custom_structure.id == custom_structure_pair.id {
+                reveal_structures(
+                    &mut custom_structure_pair.structures,
+                    &custom_structure.structures,
+                    data_key,
+                );
+                break;
+            }
+        }
+    }
+}
+
+fn unmask_structures(
+    structure_pairs: &mut Vec<StructurePair>,
+    structures: &Vec<Structure>,
+    custom_structure_id: &str,
+    data: &Data,
+) {
+    for structure_pair in structure_pairs.iter_mut() {
+        if structure_pair.value != FIELD_MASK
+            || !is_encrypted_structure(structures, &structure_pair.id)
+        {
+            continue;
+        }
+
+        for pair in data.pairs.iter() {
+            if pair.structure_id == structure_pair.id
+                && pair.custom_structure_id == custom_structure_id
+            {
+                structure_pair.value = pair.value.clone();
+                break;
+            }
+        }
+    }
+}
+
+pub fn unmask_rawpair(raw_pair: &mut RawPair, collection: &Collection, data: &Data) {
+    unmask_structures(&mut raw_pair.structures, &collection.structures, "", data);
+
+    for custom_structure_pair in raw_pair.custom_structures.iter_mut() {
+        for custom_structure in collection.custom_structures.iter() {
+            if custom_structure.id == custom_structure_pair.id {
+                unmask_structures(
+                    &mut custom_structure_pair.structures,
+                    &custom_structure.structures,
+                    &custom_structure.id,
+                    data,
+                );
+                break;
+            }
+        }
+    }
+}
+
+pub fn has_encrypted_data(all_data: &Vec<Data>) -> bool {
+    all_data.iter().any(|data| {
+        data.pairs
+            .iter()
+            .any(|pair| is_encrypted_field(&pair.value))
+    })
+}
+
+pub fn reveal_data(all_data: &mut Vec<Data>, data_key: &str) {
+    for data in all_data.iter_mut() {
+        for pair in data.pairs.iter_mut() {
+            if !is_encrypted_field(&pair.value) {
+                continue;
+            }
+
+            pair.value = match decrypt_field(&pair.value, data_key) {
+                Some(v) => v,
+                None => FIELD_MASK.to_string(),
+            };
+        }
+    }
+}
+
+pub fn mask_data(all_data: &mut Vec<Data>) {
+    for data in all_data.iter_mut() {
+        for pair in data.pairs.iter_mut() {
+            if is_encrypted_field(&pair.value) {
+                pair.value = FIELD_MASK.to_string();
+            }
+        }
+    }
+}
+
+pub fn restore_encrypted_data(all_data: &mut Vec<Data>, original_data: &Vec<Data>, data_key: &str) {
+    for data in all_data.iter_mut() {
+        let original = match original_data.iter().find(|o| o.id == data.id) {
+            Some(o) => o,
+            None => continue,
+        };
+
+        for pair in data.pairs.iter_mut() {
+            let original_value = match original.pairs.iter().find(|o| {
+                o.structure_id == pair.structure_id
+                    && o.custom_structure_id == pair.custom_structure_id
+            }) {
+                Some(o) if is_encrypted_field(&o.value) => o.value.clone(),
+                _ => continue,
+            };
+
+            let value = if is_encrypted_field(&pair.value) {
+                decrypt_field(&pair.value, data_key)
+            } else {
+                Some(pair.value.clone())
+            };
+
+            if value.as_deref() == Some(FIELD_MASK)
+                || value == decrypt_field(&original_value, data_key)
+            {
+                pair.value = original_value;
+            } else if !is_encrypted_field(&pair.value) && pair.value.len() > 0 {
+                let mode = if is_deterministic_field(&original_value) {
+                    FieldEncryptionMode::DETERMINISTIC
+                } else {
+                    FieldEncryptionMode::RANDOM
+                };
+                pair.value = encrypt_field(&pair.value, data_key, &mode);
+            }
+        }
+    }
+}
+
+pub fn is_encrypted_property(collection: &Collection, property: &str) -> bool {
+    let broken_property = property.split(".").collect::<Vec<&str>>();
+
+    if broken_property.len() == 1 {
+        return is_encrypted_structure(&collection.structures, broken_property[0]);
+    }
+
+    collection.custom_structures.iter().any(|cs| {
+        broken_property.len() == 2
+            && cs.id == broken_property[0]
+            && is_encrypted_structure(&cs.structures, broken_property[1])
+    })
+}
+
+pub fn check_encrypted_comparison(
+    property: &str,
+    deterministic: bool,
+    equality_only: bool,
+) -> Result<(), (usize, String)> {
+    if !deterministic {
+        return Err((
+            400,
+            format!(
+                "Error: Filtering on encrypted property '{}' is not supported",
+                property
+            ),
+        ));
+    }
+
+    if !equality_only {
+        return Err((
+            400,
+            format!(
+                "Error: Only equality filters are supported on encrypted property '{}'",
+                property
+            ),
+        ));
+    }
+
+    Ok(())
+}
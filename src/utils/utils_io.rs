@@ -6,7 +6,7 @@ use crate::components::{
     mapping::Mapping,
 };
 
-use super::encryption_key::get_encryption_key;
+use super::{encryption_key::get_encryption_key, lock::release_stale_locks};
 
 pub fn auto_fetch_file(path: &str, mappings: &Vec<Mapping>) -> String {
     let tmp_password = match std::env::var("TMP_PASSWORD") {
@@ -76,72 +76,10 @@ pub fn obtain_lock_name(path: &str) -> String {
     lock_name
 }
 
-pub fn auto_check_lock(lock_name: &str) -> bool {
-    let lock_directory = format!("{}/.lock/", get_root_data_dir());
-    ensure_directory_exists(&lock_directory);
-
-    let mut found = false;
-    for entry in std::fs::read_dir(&lock_directory).unwrap() {
-        if let Ok(entry) = entry {
-            let file_name = entry.file_name();
-
-            if let Ok(ft) = entry.file_type() {
-                if !ft.is_dir() {
-                    if file_name.to_str().unwrap().contains(lock_name) {
-                        found = true;
-                        break;
-                    }
-                }
-            }
-        }
-    }
-    found
-}
-
-pub fn auto_create_lock(lock_name: &str) -> bool {
-    if lock_name.trim().len() < 1 {
-        return false;
-    }
-
-    let lock_file_path = format!("{}/.lock/{}.lock", get_root_data_dir(), lock_name);
-
-    if auto_check_lock(lock_name) {
-        return false;
-    }
-
-    auto_save_file_unencrypted(&lock_file_path, "");
-    true
-}
-
-pub fn auto_release_lock(lock_name: &str) -> bool {
-    if lock_name.trim().len() < 1 {
-        return false;
-    }
-
-    let lock_file_path = format!("{}/.lock/{}.lock", get_root_data_dir(), lock_name);
-
-    if !auto_check_lock(lock_name) {
-        return false;
-    }
-
-    remove_file(lock_file_path);
-    true
-}
-
 pub fn auto_reset_locks() -> bool {
-    let lock_directory = format!("{}/.lock/", get_root_data_dir());
-    ensure_directory_exists(&lock_directory);
-
-    for entry in std::fs::read_dir(&lock_directory).unwrap() {
-        if let Ok(entry) = entry {
-            if let Ok(ft) = entry.file_type() {
-                if ft.is_dir() {
-                    remove_directory(&entry.path().to_str().unwrap().to_string());
-                } else {
-                    remove_file(entry.path().to_str().unwrap().to_string());
-                }
-            }
-        }
+    let released = release_stale_locks();
+    if released > 0 {
+        println!("Released {} stale lock(s)", released);
     }
 
     true
@@ -0,0 +1,424 @@
+use std::{
+    cell::Cell,
+    fmt,
+    fs::{read_dir, read_to_string, rename, write, OpenOptions},
+};
+
+use crate::components::{
+    encryption::{fetch_encryption_key, EncryptionKey},
+    io::{fetch_file, save_file},
+    mapping::{get_file_name, Mapping},
+};
+
+use super::{
+    data::auto_compact_all_data,
+    io::{get_root_data_dir, obtain_lock_name},
+    lock::{acquire_read_lock, acquire_write_lock, LockGuard},
+    mapping::get_mappings_path,
+    storage::rotate_redis_storage,
+};
+
+const ROTATION_GATE: &str = "key_rotation_writers";
+
+thread_local! {
+    static ROTATION_GATE_DEPTH: Cell<usize> = Cell::new(0);
+}
+
+pub struct RotationGuard {
+    _lock: Option<LockGuard>,
+}
+
+impl Drop for RotationGuard {
+    fn drop(&mut self) {
+        ROTATION_GATE_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+fn enter_rotation_gate(exclusive: bool) -> RotationGuard {
+    let depth = ROTATION_GATE_DEPTH.with(|depth| depth.get());
+
+    let lock = if depth > 0 {
+        None
+    } else if exclusive {
+        Some(acquire_write_lock(ROTATION_GATE))
+    } else {
+        Some(acquire_read_lock(ROTATION_GATE))
+    };
+    ROTATION_GATE_DEPTH.with(|d| d.set(depth + 1));
+
+    RotationGuard { _lock: lock }
+}
+
+pub fn acquire_rotation_gate() -> RotationGuard {
+    enter_rotation_gate(false)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RotationPhase {
+    STAGE,
+    COMMIT,
+}
+
+impl Default for RotationPhase {
+    fn default() -> Self {
+        RotationPhase::STAGE
+    }
+}
+
+impl fmt::Display for RotationPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phase_txt = match self {
+            RotationPhase::STAGE => "STAGE".to_string(),
+            RotationPhase::COMMIT => "COMMIT".to_string(),
+        };
+
+        write!(f, "{}", phase_txt)
+    }
+}
+
+impl RotationPhase {
+    pub fn to(phase: RotationPhase) -> String {
+        return match phase.clone() {
+            RotationPhase::STAGE => "STAGE".to_string(),
+            RotationPhase::COMMIT => "COMMIT".to_string(),
+        };
+    }
+
+    pub fn from(phase_txt: &str) -> Option<RotationPhase> {
+        return match phase_txt.trim().to_uppercase().as_str() {
+            "STAGE" => Some(RotationPhase::STAGE),
+            "COMMIT" => Some(RotationPhase::COMMIT),
+            _ => None,
+        };
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct RotationState {
+    pub phase: RotationPhase,
+    pub old_key: String,
+    pub new_key: String,
+}
+
+impl RotationState {
+    pub fn to_string(state: RotationState) -> String {
+        format!(
+            "phase={}\nold_key={}\nnew_key={}",
+            RotationPhase::to(state.phase),
+            state.old_key,
+            state.new_key
+        )
+    }
+
+    pub fn from_string(state_str: &str) -> Option<RotationState> {
+        let mut phase: Option<RotationPhase> = None;
+        let mut old_key = String::new();
+        let mut new_key = String::new();
+
+        for line in state_str.split("\n") {
+            let current_line = line.trim().splitn(2, "=").collect::<Vec<&str>>();
+            if current_line.len() < 2 {
+                continue;
+            }
+
+            match current_line[0] {
+                "phase" => phase = RotationPhase::from(current_line[1]),
+                "old_key" => old_key = current_line[1].to_string(),
+                "new_key" => new_key = current_line[1].to_string(),
+                _ => {}
+            }
+        }
+
+        if old_key.len() < 1 || new_key.len() < 1 {
+            return None;
+        }
+
+        match phase {
+            Some(p) => Some(RotationState {
+                phase: p,
+                old_key: old_key,
+                new_key: new_key,
+            }),
+            None => None,
+        }
+    }
+}
+
+pub fn get_rotation_journal_path() -> String {
+    format!("{}/data/key_rotation.txt", get_root_data_dir())
+}
+
+pub fn get_staged_path(path: &str) -> String {
+    format!("{}.rotate", path)
+}
+
+fn sync_file(path: &str) {
+    match OpenOptions::new().write(true).open(path) {
+        Ok(f) => {
+            if let Err(e) = f.sync_all() {
+                println!("Error occured while syncing {}: {}", path, e);
+            }
+        }
+        Err(e) => println!("Error occured while opening {}: {}", path, e),
+    }
+}
+
+fn read_encrypted_lines(path: &str) -> Option<Vec<String>> {
+    let _lock = acquire_read_lock(&obtain_lock_name(path));
+    let content = match read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return None,
+    };
+
+    let lines = content
+        .split("\n")
+        .filter(|line| line.chars().count() >= 3)
+        .map(|line| line.to_string())
+        .collect::<Vec<String>>();
+
+    if lines.len() < 1 || lines[0] != ";|encrypted|;" {
+        return None;
+    }
+
+    Some(lines.into_iter().skip(1).collect())
+}
+
+pub fn stage_file(path: &str, old_key: &str, new_key: &str) -> Result<bool, (usize, String)> {
+    let encrypted_lines = match read_encrypted_lines(path) {
+        Some(l) => l,
+        None => return Ok(false),
+    };
+
+    let mut decrypted_lines = Vec::<String>::new();
+    for line in encrypted_lines {
+        match EncryptionKey::decrypt(line, old_key) {
+            Ok(d) => decrypted_lines.push(d.0),
+            Err(e) => return Err((500, format!("Error: Failed decrypting {} ({})", path, e))),
+        }
+    }
+
+    let staged_path = get_staged_path(path);
+    save_file(
+        staged_path.clone(),
+        decrypted_lines.join("\n"),
+        &new_key.to_string(),
+    );
+    sync_file(&staged_path);
+
+    Ok(true)
+}
+
+pub fn commit_file(path: &str) -> Result<bool, (usize, String)> {
+    let staged_path = get_staged_path(path);
+    if !std::path::Path::new(&staged_path).exists() {
+        return Ok(false);
+    }
+
+    let _lock = acquire_write_lock(&obtain_lock_name(path));
+    if let Err(e) = rename(&staged_path, path) {
+        return Err((500, format!("Error: Failed committing {} ({})", path, e)));
+    }
+
+    Ok(true)
+}
+
+fn collect_project_files(path: &str, all_files: &mut Vec<String>) {
+    let entries = match read_dir(path) {
+        Ok(e) => e,
+        Err(_) => return,
+    };
+
+    for entry in entries {
+        if let Ok(entry) = entry {
+            let entry_path = entry.path().to_string_lossy().to_string();
+            match entry.file_type() {
+                Ok(ft) if ft.is_dir() => collect_project_files(&entry_path, all_files),
+                Ok(_) if entry_path.ends_with(".txt") || entry_path.ends_with(".log") => {
+                    all_files.push(entry_path)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn get_rotation_targets(mappings: &Vec<Mapping>) -> Vec<String> {
+    let mut all_files = vec![get_mappings_path()];
+
+    for mapping in mappings.iter() {
+        if mapping.id == "encryption_key" {
+            continue;
+        }
+
+        if let Ok(path) = get_file_name(&mapping.id, mappings) {
+            all_files.push(path);
+        }
+    }
+
+    collect_project_files(
+        &format!("{}/data/projects", get_root_data_dir()),
+        &mut all_files,
+    );
+
+    all_files
+}
+
+pub fn persist_password(env_path: &str, password: &str) -> Result<(), (usize, String)> {
+    let quoted_password = if password.contains("'") {
+        format!(
+            "\"{}\"",
+            password
+                .replace("\\", "\\\\")
+                .replace("\"", "\\\"")
+                .replace("$", "\\$")
+        )
+    } else {
+        format!("'{}'", password)
+    };
+
+    let mut found = false;
+    let mut all_lines = Vec::<String>::new();
+    for line in read_to_string(env_path).unwrap_or_default().lines() {
+        if line.trim_start().starts_with("TMP_PASSWORD=") {
+            all_lines.push(format!("TMP_PASSWORD={}", quoted_password));
+            found = true;
+        } else {
+            all_lines.push(line.to_string());
+        }
+    }
+    if !found {
+        all_lines.push(format!("TMP_PASSWORD={}", quoted_password));
+    }
+
+    if let Err(e) = write(env_path, format!("{}\n", all_lines.join("\n"))) {
+        return Err((
+            500,
+            format!(
+                "Error: Failed persisting new password to {} ({})",
+                env_path, e
+            ),
+        ));
+    }
+    sync_file(env_path);
+
+    Ok(())
+}
+
+fn save_rotation_state(path: &str, state: &RotationState, password: &str) {
+    save_file(
+        path.to_string(),
+        RotationState::to_string(state.clone()),
+        &password.to_string(),
+    );
+    sync_file(path);
+}
+
+pub fn rotate_encryption_key(
+    mappings: &Vec<Mapping>,
+    password: &str,
+    new_password: &str,
+) -> Result<bool, (usize, String)> {
+    let _lock = acquire_write_lock("key_rotation");
+    let _gate = enter_rotation_gate(true);
+
+    let key_path = match get_file_name("encryption_key", mappings) {
+        Ok(p) => p,
+        Err(e) => return Err((500, e)),
+    };
+
+    if new_password.len() > 0 && (new_password.len() < 8 || new_password.len() > 100) {
+        return Err((
+            400,
+            String::from("Error: new_password should be between 8 and 100 characters"),
+        ));
+    }
+
+    let final_password = if new_password.len() > 0 {
+        new_password
+    } else {
+        password
+    };
+
+    let journal_path = get_rotation_journal_path();
+    let resumed = std::path::Path::new(&journal_path).exists();
+
+    let mut state = if resumed {
+        let journal = fetch_file(journal_path.clone(), &final_password.to_string());
+        match RotationState::from_string(&journal) {
+            Some(s) => s,
+            None => {
+                return Err((
+                    409,
+                    String::from(
+                        "Error: A key rotation is in progress and could not be resumed with these passwords",
+                    ),
+                ))
+            }
+        }
+    } else {
+        let old_key = match fetch_encryption_key(key_path.clone(), password) {
+            Ok(k) if k.trim().len() > 0 => k.trim().to_string(),
+            _ => return Err((403, String::from("Error: Incorrect password"))),
+        };
+
+        RotationState {
+            phase: RotationPhase::STAGE,
+            old_key: old_key,
+            new_key: EncryptionKey::generate(32).0,
+        }
+    };
+
+    if state.phase == RotationPhase::STAGE {
+        save_rotation_state(&journal_path, &state, final_password);
+        auto_compact_all_data(mappings);
+
+        for target in get_rotation_targets(mappings).iter() {
+            if let Err(e) = stage_file(target, &state.old_key, &state.new_key) {
+                return Err(e);
+            }
+        }
+
+        let staged_key_path = get_staged_path(&key_path);
+        save_file(
+            staged_key_path.clone(),
+            state.new_key.clone(),
+            &final_password.to_string(),
+        );
+        sync_file(&staged_key_path);
+
+        state.phase = RotationPhase::COMMIT;
+        save_rotation_state(&journal_path, &state, final_password);
+    }
+
+    if new_password.len() > 0 {
+        if let Err(e) = persist_password(".env", new_password) {
+            return Err(e);
+        }
+    }
+
+    for target in get_rotation_targets(mappings).iter() {
+        if let Err(e) = commit_file(target) {
+            return Err(e);
+        }
+    }
+
+    if let Err(e) = rotate_redis_storage(&state.old_key, &state.new_key) {
+        return Err(e);
+    }
+
+    if let Err(e) = commit_file(&key_path) {
+        return Err(e);
+    }
+
+    if new_password.len() > 0 {
+        std::env::set_var("TMP_PASSWORD", new_password);
+    }
+
+    if let Err(e) = std::fs::remove_file(&journal_path) {
+        println!("Error occured while removing {}: {}", &journal_path, e);
+    }
+
+    println!("Encryption Key Rotated!");
+
+    Ok(resumed)
+}
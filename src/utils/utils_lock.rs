@@ -0,0 +1,510 @@
+use chrono::prelude::*;
+use rocket::serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    fs::{create_dir_all, read_dir, read_to_string, remove_file, OpenOptions},
+    io::{prelude::*, ErrorKind},
+    sync::{Mutex, Once, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use crate::components::encryption::EncryptionKey;
+
+use super::io::get_root_data_dir;
+
+const LOCK_TIMEOUT: i64 = 60;
+const LOCK_RETRY_MS: u64 = 50;
+const LOCK_REFRESH_SECONDS: u64 = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum LockMode {
+    READ,
+    WRITE,
+}
+
+impl Default for LockMode {
+    fn default() -> Self {
+        LockMode::WRITE
+    }
+}
+
+impl fmt::Display for LockMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode_txt = match self {
+            LockMode::READ => "READ".to_string(),
+            LockMode::WRITE => "WRITE".to_string(),
+        };
+
+        write!(f, "{}", mode_txt)
+    }
+}
+
+impl LockMode {
+    pub fn to(mode: LockMode) -> String {
+        return match mode.clone() {
+            LockMode::READ => "READ".to_string(),
+            LockMode::WRITE => "WRITE".to_string(),
+        };
+    }
+
+    pub fn from(mode_txt: &str) -> Option<LockMode> {
+        return match mode_txt.trim().to_uppercase().as_str() {
+            "READ" => Some(LockMode::READ),
+            "WRITE" => Some(LockMode::WRITE),
+            _ => None,
+        };
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LockInfo {
+    pub name: String,
+    pub mode: LockMode,
+    pub pid: u32,
+    pub instance: String,
+    pub acquired_at: i64,
+    pub stale: bool,
+}
+
+impl LockInfo {
+    pub fn to_string(info: LockInfo) -> String {
+        format!(
+            "{};{};{};{};{}",
+            LockMode::to(info.mode),
+            info.pid,
+            info.instance,
+            info.acquired_at,
+            info.name
+        )
+    }
+
+    pub fn from_string(info_str: &str) -> Option<LockInfo> {
+        let mut current_info = info_str.trim().splitn(5, ";").collect::<Vec<&str>>();
+        let mut instance = String::new();
+
+        if current_info.len() == 5 && current_info[3].parse::<i64>().is_ok() {
+            instance = current_info.remove(2).to_string();
+        } else {
+            current_info = info_str.trim().splitn(4, ";").collect::<Vec<&str>>();
+        }
+
+        if current_info.len() < 4 {
+            return None;
+        }
+
+        let mode = match LockMode::from(current_info[0]) {
+            Some(m) => m,
+            None => return None,
+        };
+        let pid = match current_info[1].parse::<u32>() {
+            Ok(p) => p,
+            Err(_) => return None,
+        };
+        let acquired_at = match current_info[2].parse::<i64>() {
+            Ok(a) => a,
+            Err(_) => return None,
+        };
+
+        let mut info = LockInfo {
+            name: current_info[3].to_string(),
+            mode: mode,
+            pid: pid,
+            instance: instance,
+            acquired_at: acquired_at,
+            stale: false,
+        };
+        info.stale = is_stale(&info);
+
+        Some(info)
+    }
+}
+
+struct NamedLock {
+    rw: RwLock<()>,
+    readers: Mutex<usize>,
+}
+
+static NAMED_LOCKS: Mutex<Vec<(String, &'static NamedLock)>> = Mutex::new(Vec::new());
+static INSTANCE_ID: Mutex<String> = Mutex::new(String::new());
+static HELD_LOCK_FILES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static LOCK_REFRESHER: Once = Once::new();
+
+enum HeldLock {
+    Read(RwLockReadGuard<'static, ()>),
+    Write(RwLockWriteGuard<'static, ()>),
+}
+
+pub struct LockGuard {
+    name: String,
+    named_lock: Option<&'static NamedLock>,
+    held: Option<HeldLock>,
+    file_held: bool,
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        let named_lock = match self.named_lock {
+            Some(l) => l,
+            None => return,
+        };
+
+        match self.held {
+            Some(HeldLock::Read(_)) => {
+                let mut readers = named_lock.readers.lock().unwrap_or_else(|e| e.into_inner());
+                *readers -= 1;
+                if *readers == 0 {
+                    release_lock_file(&get_lock_path(&self.name, &LockMode::READ));
+                }
+            }
+            Some(HeldLock::Write(_)) => {
+                if self.file_held {
+                    release_lock_file(&get_lock_path(&self.name, &LockMode::WRITE));
+                }
+            }
+            None => {}
+        }
+
+        self.held = None;
+    }
+}
+
+fn get_named_lock(name: &str) -> &'static NamedLock {
+    let mut all_locks = NAMED_LOCKS.lock().unwrap_or_else(|e| e.into_inner());
+
+    for (lock_name, named_lock) in all_locks.iter() {
+        if lock_name == name {
+            return named_lock;
+        }
+    }
+
+    let named_lock: &'static NamedLock = Box::leak(Box::new(NamedLock {
+        rw: RwLock::new(()),
+        readers: Mutex::new(0),
+    }));
+    all_locks.push((name.to_string(), named_lock));
+
+    named_lock
+}
+
+pub fn get_lock_directory() -> String {
+    let lock_directory = format!("{}/.lock", get_root_data_dir());
+    if let Err(e) = create_dir_all(&lock_directory) {
+        println!(
+            "Error occured while creating directory at {}: {}",
+            lock_directory, e
+        );
+    }
+
+    lock_directory
+}
+
+fn get_lock_path(name: &str, mode: &LockMode) -> String {
+    match mode {
+        LockMode::WRITE => format!("{}/{}.write.lock", get_lock_directory(), name),
+        LockMode::READ => format!(
+            "{}/{}.read.{}.lock",
+            get_lock_directory(),
+            name,
+            std::process::id()
+        ),
+    }
+}
+
+pub fn get_instance_id() -> String {
+    let mut instance_id = INSTANCE_ID.lock().unwrap_or_else(|e| e.into_inner());
+    if instance_id.len() < 1 {
+        *instance_id = EncryptionKey::generate_block(16);
+    }
+
+    instance_id.clone()
+}
+
+fn is_process_alive(pid: u32, instance: &str) -> bool {
+    if pid == std::process::id() {
+        return instance == get_instance_id();
+    }
+
+    if !std::path::Path::new("/proc").exists() {
+        return true;
+    }
+
+    std::path::Path::new(&format!("/proc/{}", pid)).exists()
+}
+
+pub fn is_stale(info: &LockInfo) -> bool {
+    Utc::now().timestamp() - info.acquired_at > LOCK_TIMEOUT
+        || !is_process_alive(info.pid, &info.instance)
+}
+
+fn write_lock_info(path: &str, info: LockInfo, create: bool) -> Result<(), std::io::Error> {
+    let mut f = OpenOptions::new()
+        .write(true)
+        .truncate(!create)
+        .create_new(create)
+        .open(path)?;
+
+    if let Err(e) = f.write_all(LockInfo::to_string(info).as_bytes()) {
+        println!("Error occured while writing lock at {}: {}", path, e);
+    }
+    if let Err(e) = f.sync_all() {
+        println!("Error occured while syncing lock at {}: {}", path, e);
+    }
+
+    Ok(())
+}
+
+fn refresh_held_locks() {
+    let held_files = HELD_LOCK_FILES.lock().unwrap_or_else(|e| e.into_inner());
+
+    for path in held_files.iter() {
+        if let Some(mut info) = read_lock_file(path) {
+            if info.pid != std::process::id() || info.instance != get_instance_id() {
+                continue;
+            }
+
+            info.acquired_at = Utc::now().timestamp();
+            info.stale = false;
+            if let Err(e) = write_lock_info(path, info, false) {
+                println!("Error occured while refreshing lock at {}: {}", path, e);
+            }
+        }
+    }
+}
+
+fn track_lock_file(path: &str) {
+    LOCK_REFRESHER.call_once(|| {
+        std::thread::spawn(|| loop {
+            std::thread::sleep(std::time::Duration::from_secs(LOCK_REFRESH_SECONDS));
+            refresh_held_locks();
+        });
+    });
+
+    let mut held_files = HELD_LOCK_FILES.lock().unwrap_or_else(|e| e.into_inner());
+    if !held_files.iter().any(|p| p == path) {
+        held_files.push(path.to_string());
+    }
+}
+
+fn release_lock_file(path: &str) {
+    let mut held_files = HELD_LOCK_FILES.lock().unwrap_or_else(|e| e.into_inner());
+    held_files.retain(|p| p != path);
+    let _ = remove_file(path);
+}
+
+fn create_lock_file(name: &str, mode: &LockMode) -> Result<(), ErrorKind> {
+    let path = get_lock_path(name, mode);
+    let info = LockInfo {
+        name: name.to_string(),
+        mode: mode.clone(),
+        pid: std::process::id(),
+        instance: get_instance_id(),
+        acquired_at: Utc::now().timestamp(),
+        stale: false,
+    };
+
+    match write_lock_info(&path, info, true) {
+        Ok(_) => {
+            track_lock_file(&path);
+            Ok(())
+        }
+        Err(e) => {
+            if e.kind() != ErrorKind::AlreadyExists {
+                println!("Error occured while creating lock at {}: {}", &path, e);
+            }
+
+            Err(e.kind())
+        }
+    }
+}
+
+fn read_lock_file(path: &str) -> Option<LockInfo> {
+    match read_to_string(path) {
+        Ok(content) => LockInfo::from_string(&content),
+        Err(_) => None,
+    }
+}
+
+fn pause() {
+    std::thread::sleep(std::time::Duration::from_millis(LOCK_RETRY_MS));
+}
+
+fn acquire_write_file(name: &str) -> bool {
+    let write_path = get_lock_path(name, &LockMode::WRITE);
+
+    loop {
+        match create_lock_file(name, &LockMode::WRITE) {
+            Ok(_) => break,
+            Err(ErrorKind::AlreadyExists) => {}
+            Err(_) => return false,
+        }
+
+        if let Some(info) = read_lock_file(&write_path) {
+            if info.stale {
+                println!("Removing stale lock: {}", name);
+                let _ = remove_file(&write_path);
+                continue;
+            }
+        }
+
+        pause();
+    }
+
+    loop {
+        let other_readers = fetch_all_locks()
+            .into_iter()
+            .filter(|info| {
+                info.name == name && info.mode == LockMode::READ && info.pid != std::process::id()
+            })
+            .filter(|info| !info.stale)
+            .count();
+
+        if other_readers == 0 {
+            break;
+        }
+
+        pause();
+    }
+
+    true
+}
+
+fn acquire_read_file(name: &str) {
+    let read_path = get_lock_path(name, &LockMode::READ);
+    let write_path = get_lock_path(name, &LockMode::WRITE);
+
+    loop {
+        match create_lock_file(name, &LockMode::READ) {
+            Ok(_) => {}
+            Err(ErrorKind::AlreadyExists) => {
+                release_lock_file(&read_path);
+                continue;
+            }
+            Err(_) => break,
+        }
+
+        match read_lock_file(&write_path) {
+            Some(info) if info.stale => {
+                println!("Removing stale lock: {}", name);
+                let _ = remove_file(&write_path);
+                break;
+            }
+            Some(_) => {
+                release_lock_file(&read_path);
+                pause();
+            }
+            None => {
+                if std::path::Path::new(&write_path).exists() {
+                    release_lock_file(&read_path);
+                    pause();
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+pub fn acquire_read_lock(name: &str) -> LockGuard {
+    if name.trim().len() < 1 {
+        return LockGuard {
+            name: String::new(),
+            named_lock: None,
+            held: None,
+            file_held: false,
+        };
+    }
+
+    let named_lock = get_named_lock(name);
+    let guard = named_lock.rw.read().unwrap_or_else(|e| e.into_inner());
+
+    let mut readers = named_lock.readers.lock().unwrap_or_else(|e| e.into_inner());
+    if *readers == 0 {
+        acquire_read_file(name);
+    }
+    *readers += 1;
+
+    LockGuard {
+        name: name.to_string(),
+        named_lock: Some(named_lock),
+        held: Some(HeldLock::Read(guard)),
+        file_held: true,
+    }
+}
+
+pub fn acquire_write_lock(name: &str) -> LockGuard {
+    if name.trim().len() < 1 {
+        return LockGuard {
+            name: String::new(),
+            named_lock: None,
+            held: None,
+            file_held: false,
+        };
+    }
+
+    let named_lock = get_named_lock(name);
+    let guard = named_lock.rw.write().unwrap_or_else(|e| e.into_inner());
+
+    let file_held = acquire_write_file(name);
+
+    LockGuard {
+        name: name.to_string(),
+        named_lock: Some(named_lock),
+        held: Some(HeldLock::Write(guard)),
+        file_held: file_held,
+    }
+}
+
+pub fn fetch_all_locks() -> Vec<LockInfo> {
+    let mut all_locks = Vec::<LockInfo>::new();
+
+    let entries = match read_dir(get_lock_directory()) {
+        Ok(e) => e,
+        Err(_) => return all_locks,
+    };
+
+    for entry in entries {
+        if let Ok(entry) = entry {
+            if let Some(info) = read_lock_file(&entry.path().to_string_lossy()) {
+                all_locks.push(info);
+            }
+        }
+    }
+
+    all_locks
+}
+
+pub fn release_stale_locks() -> usize {
+    let mut released = 0;
+
+    let entries = match read_dir(get_lock_directory()) {
+        Ok(e) => e,
+        Err(_) => return released,
+    };
+
+    for entry in entries {
+        if let Ok(entry) = entry {
+            if let Ok(ft) = entry.file_type() {
+                if ft.is_dir() {
+                    continue;
+                }
+            }
+
+            let path = entry.path().to_string_lossy().to_string();
+            let stale = match read_lock_file(&path) {
+                Some(info) => info.stale,
+                None => match entry.metadata().and_then(|m| m.modified()) {
+                    Ok(modified) => match modified.elapsed() {
+                        Ok(elapsed) => elapsed.as_secs() as i64 > LOCK_TIMEOUT,
+                        Err(_) => false,
+                    },
+                    Err(_) => true,
+                },
+            };
+
+            if stale && remove_file(&path).is_ok() {
+                released += 1;
+            }
+        }
+    }
+
+    released
+}
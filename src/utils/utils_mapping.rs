@@ -1,37 +1,29 @@
-use crate::components::mapping::{fetch_all_mappings, save_all_mappings, Mapping};
+use crate::components::mapping::{stringify_mappings, unwrap_mappings, Mapping};
 
-use super::encryption_key::get_encryption_key;
+use super::storage::{get_tmp_encryption_key, FileStorage, StorageBackend, StorageLocation};
 
-pub fn auto_fetch_all_mappings() -> Vec<Mapping> {
-    let mappings_path = format!(
+pub fn get_mappings_path() -> String {
+    format!(
         "{}{}",
         match std::env::var("CURRENT_PATH") {
             Ok(path) => path,
             _ => "/tmp".to_string(),
         },
         "/data/mappings.txt"
-    );
+    )
+}
+
+pub fn auto_fetch_all_mappings() -> Vec<Mapping> {
+    let location = StorageLocation::new("", &get_mappings_path(), "");
 
-    fetch_all_mappings(&mappings_path, &String::new())
+    unwrap_mappings(FileStorage.fetch(&location))
 }
 
 pub fn auto_save_all_mappings(mappings: &Vec<Mapping>) -> Result<(), String> {
-    let mappings_path = format!(
-        "{}{}",
-        match std::env::var("CURRENT_PATH") {
-            Ok(path) => path,
-            _ => "/tmp".to_string(),
-        },
-        "/data/mappings.txt"
-    );
-
-    let tmp_password = match std::env::var("TMP_PASSWORD") {
-        Ok(pass) => pass,
-        _ => "password".to_string(),
-    };
+    let location =
+        StorageLocation::new("", &get_mappings_path(), &get_tmp_encryption_key(mappings));
 
-    let encryption_key = get_encryption_key(mappings, &tmp_password);
-    save_all_mappings(mappings, &mappings_path, &encryption_key);
+    FileStorage.save(&location, stringify_mappings(mappings));
 
     Ok(())
 }
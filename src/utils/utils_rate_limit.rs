@@ -0,0 +1,100 @@
+use std::net::IpAddr;
+use std::sync::Mutex;
+
+use crate::components::routing::core::core_rate_limit::RateLimit;
+use crate::components::routing::mod_route::RouteComponent;
+use crate::components::routing::submodules::sub_rate_limit_key::RateLimitKey;
+
+use crate::middlewares::token::{decode_jwt_payload, verify_api_key};
+
+use super::{mapping::auto_fetch_all_mappings, redis::increment_redis_counter};
+
+struct RateLimitCounter {
+    key: String,
+    count: u64,
+    reset_at: i64,
+}
+
+static RATE_LIMIT_COUNTERS: Mutex<Vec<RateLimitCounter>> = Mutex::new(Vec::new());
+
+pub fn get_rate_limit_identity(
+    current_route: &RouteComponent,
+    rate_limit: &RateLimit,
+    project_id: &str,
+    token: &str,
+    api_key: &str,
+    client_ip: &Option<IpAddr>,
+) -> String {
+    let ip = match client_ip {
+        Some(ip) => ip.to_string(),
+        None => String::from("unknown"),
+    };
+
+    match rate_limit.key {
+        RateLimitKey::GLOBAL => String::from("global"),
+        RateLimitKey::IP => ip,
+        RateLimitKey::AUTH => {
+            if let Some(aj) = &current_route.auth_jwt {
+                if aj.active && token.trim().len() > 0 {
+                    if let Ok(payload) = decode_jwt_payload(&auto_fetch_all_mappings(), token) {
+                        return format!("auth_{}", payload);
+                    }
+                }
+            }
+
+            if current_route.auth_api_key && api_key.trim().len() > 0 {
+                if let Ok(key) = verify_api_key(project_id, api_key.trim()) {
+                    return format!("key_{}", key.id);
+                }
+            }
+
+            ip
+        }
+    }
+}
+
+pub fn check_rate_limit(
+    project_id: &str,
+    route_id: &str,
+    rate_limit: &RateLimit,
+    identity: &str,
+) -> Result<(), u64> {
+    let key = format!("rate_limit:{}:{}:{}", project_id, route_id, identity);
+
+    let (count, retry_after) = match increment_redis_counter(&key, rate_limit.window) {
+        Ok(c) => c,
+        Err(_) => increment_local_counter(&key, rate_limit.window),
+    };
+
+    if count > rate_limit.requests as u64 {
+        return Err(retry_after);
+    }
+
+    Ok(())
+}
+
+fn increment_local_counter(key: &str, window: u32) -> (u64, u64) {
+    let now = chrono::Utc::now().timestamp();
+
+    let mut all_counters = match RATE_LIMIT_COUNTERS.lock() {
+        Ok(c) => c,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    all_counters.retain(|counter| counter.reset_at > now);
+
+    for counter in all_counters.iter_mut() {
+        if counter.key == key {
+            counter.count += 1;
+            return (counter.count, (counter.reset_at - now) as u64);
+        }
+    }
+
+    all_counters.push(RateLimitCounter {
+        key: key.to_string(),
+        count: 1,
+        reset_at: now + window as i64,
+    });
+
+    (1, window as u64)
+}
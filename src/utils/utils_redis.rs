@@ -109,3 +109,77 @@ pub fn init_redis() -> String {
 
     String::from("Redis Connection Successful!")
 }
+
+pub fn acquire_redis_lock(key: &str, ttl: u32) -> Result<bool, String> {
+    let mut connection = match get_redis_connection() {
+        Ok(conn) => conn,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+
+    match redis::cmd("SET")
+        .arg(key)
+        .arg(std::process::id())
+        .arg("NX")
+        .arg("EX")
+        .arg(ttl)
+        .query::<Option<String>>(&mut connection)
+    {
+        Ok(reply) => Ok(reply.is_some()),
+        Err(e) => {
+            println!("{}", e);
+            Err(String::from("Error: Failed acquiring Redis lock"))
+        }
+    }
+}
+
+pub fn increment_redis_counter(key: &str, window: u32) -> Result<(u64, u64), String> {
+    let mut connection = match get_redis_connection() {
+        Ok(conn) => conn,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+
+    let count: u64 = match redis::cmd("INCR").arg(key).query(&mut connection) {
+        Ok(c) => c,
+        Err(e) => {
+            println!("{}", e);
+            return Err(String::from("Error: Failed incrementing Redis counter"));
+        }
+    };
+
+    if count == 1 {
+        if let Err(e) = redis::cmd("EXPIRE")
+            .arg(key)
+            .arg(window)
+            .query::<()>(&mut connection)
+        {
+            println!("{}", e);
+            return Err(String::from("Error: Failed setting Redis counter expiry"));
+        }
+    }
+
+    let ttl: i64 = match redis::cmd("TTL").arg(key).query(&mut connection) {
+        Ok(t) => t,
+        Err(e) => {
+            println!("{}", e);
+            return Err(String::from("Error: Failed fetching Redis counter expiry"));
+        }
+    };
+
+    if ttl < 0 {
+        if let Err(e) = redis::cmd("EXPIRE")
+            .arg(key)
+            .arg(window)
+            .query::<()>(&mut connection)
+        {
+            println!("{}", e);
+            return Err(String::from("Error: Failed setting Redis counter expiry"));
+        }
+        return Ok((count, window as u64));
+    }
+
+    Ok((count, ttl as u64))
+}
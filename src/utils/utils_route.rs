@@ -1,54 +1,22 @@
-use crate::components::routing::mod_route::{
-    fetch_all_routes, save_all_routes, stringify_routes, unwrap_routes, RouteComponent,
-};
+use crate::components::routing::mod_route::{stringify_routes, unwrap_routes, RouteComponent};
 
-use super::{io::get_root_data_dir, redis::get_redis_connection};
+use super::{
+    mapping::auto_fetch_all_mappings,
+    storage::{get_storage_backend, StorageLocation},
+};
 
 pub fn auto_fetch_all_routes(project_id: &str) -> Result<Vec<RouteComponent>, String> {
-    let connection = get_redis_connection();
-
-    if let Ok(mut con) = connection {
-        let stringified_routes = match redis::pipe()
-            .cmd("GET")
-            .arg(format!("routes_{}", project_id))
-            .query(&mut con)
-        {
-            Ok(d) => Some(d),
-            _ => None,
-        };
-
-        if let Some(sr) = stringified_routes {
-            return Ok(unwrap_routes(sr));
-        }
-    }
+    let location = StorageLocation::project(project_id, "routes", "");
 
-    let all_routes_path = format!(
-        "{}/data/projects/{}/routes.txt",
-        get_root_data_dir(),
-        project_id,
-    );
-
-    let all_routes = fetch_all_routes(all_routes_path.clone(), &"".to_string());
-
-    Ok(all_routes)
+    Ok(unwrap_routes(
+        get_storage_backend(&auto_fetch_all_mappings()).fetch(&location),
+    ))
 }
 
 pub fn auto_save_all_routes(project_id: &str, routes: &Vec<RouteComponent>) -> Result<(), String> {
-    let all_routes_path = format!(
-        "{}/data/projects/{}/routes.txt",
-        get_root_data_dir(),
-        project_id
-    );
-
-    let connection = get_redis_connection();
-    if let Ok(mut con) = connection {
-        redis::cmd("SET")
-            .arg(format!("routes_{}", project_id))
-            .arg(stringify_routes(routes))
-            .execute(&mut con);
-    }
+    let location = StorageLocation::project(project_id, "routes", "");
 
-    save_all_routes(routes, all_routes_path, &"".to_string());
+    get_storage_backend(&auto_fetch_all_mappings()).save(&location, stringify_routes(routes));
 
     Ok(())
 }
@@ -0,0 +1,150 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+use crate::components::routing::mod_route::RouteComponent;
+use crate::routes::x::x::LocalParamData;
+
+use super::redis::get_redis_connection;
+
+struct CachedResponse {
+    key: String,
+    project_id: String,
+    collections: Vec<String>,
+    value: String,
+    expires_at: i64,
+}
+
+static ROUTE_CACHE: Mutex<Vec<CachedResponse>> = Mutex::new(Vec::new());
+
+pub fn get_route_cache_key(
+    current_route: &RouteComponent,
+    full_query: &str,
+    all_path_params: &Vec<LocalParamData>,
+    stream: &str,
+) -> String {
+    let mut hasher = DefaultHasher::new();
+    RouteComponent::to_string(current_route.clone()).hash(&mut hasher);
+    full_query.hash(&mut hasher);
+    for path_param in all_path_params.iter() {
+        path_param.key.hash(&mut hasher);
+        path_param.value.hash(&mut hasher);
+    }
+    stream.hash(&mut hasher);
+
+    format!(
+        "route_cache:{}:{}:{}",
+        current_route.project_id,
+        current_route.route_id,
+        hasher.finish()
+    )
+}
+
+pub fn fetch_cached_response(key: &str) -> Option<String> {
+    if let Ok(mut con) = get_redis_connection() {
+        return match redis::cmd("GET").arg(key).query::<Option<String>>(&mut con) {
+            Ok(value) => value,
+            _ => None,
+        };
+    }
+
+    let now = chrono::Utc::now().timestamp();
+
+    let mut all_cached = match ROUTE_CACHE.lock() {
+        Ok(c) => c,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    all_cached.retain(|cached| cached.expires_at > now);
+
+    for cached in all_cached.iter() {
+        if cached.key == key {
+            return Some(cached.value.clone());
+        }
+    }
+
+    None
+}
+
+pub fn cache_response(
+    key: &str,
+    project_id: &str,
+    collections: Vec<String>,
+    value: String,
+    ttl: u32,
+) {
+    if let Ok(mut con) = get_redis_connection() {
+        redis::cmd("SET")
+            .arg(key)
+            .arg(value)
+            .arg("EX")
+            .arg(ttl)
+            .execute(&mut con);
+
+        for collection_id in collections.iter() {
+            let index_key = format!("route_cache_index:{}:{}", project_id, collection_id);
+            redis::cmd("SADD")
+                .arg(&index_key)
+                .arg(key)
+                .execute(&mut con);
+
+            let current_ttl = match redis::cmd("TTL").arg(&index_key).query::<i64>(&mut con) {
+                Ok(t) => t,
+                _ => -1,
+            };
+
+            if current_ttl < ttl as i64 {
+                redis::cmd("EXPIRE")
+                    .arg(&index_key)
+                    .arg(ttl)
+                    .execute(&mut con);
+            }
+        }
+
+        return;
+    }
+
+    let mut all_cached = match ROUTE_CACHE.lock() {
+        Ok(c) => c,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    all_cached.retain(|cached| cached.key != key);
+    all_cached.push(CachedResponse {
+        key: key.to_string(),
+        project_id: project_id.to_string(),
+        collections: collections,
+        value: value,
+        expires_at: chrono::Utc::now().timestamp() + ttl as i64,
+    });
+}
+
+pub fn invalidate_route_cache(project_id: &str, collection_id: &str) {
+    if let Ok(mut con) = get_redis_connection() {
+        let index_key = format!("route_cache_index:{}:{}", project_id, collection_id);
+
+        let all_keys = match redis::cmd("SMEMBERS")
+            .arg(&index_key)
+            .query::<Vec<String>>(&mut con)
+        {
+            Ok(k) => k,
+            _ => vec![],
+        };
+
+        for key in all_keys.iter() {
+            redis::cmd("DEL").arg(key).execute(&mut con);
+        }
+        redis::cmd("DEL").arg(&index_key).execute(&mut con);
+
+        return;
+    }
+
+    let mut all_cached = match ROUTE_CACHE.lock() {
+        Ok(c) => c,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    all_cached.retain(|cached| {
+        cached.project_id != project_id || !cached.collections.contains(&collection_id.to_string())
+    });
+}
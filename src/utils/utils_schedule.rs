@@ -0,0 +1,22 @@
+use crate::components::schedule::{stringify_schedules, unwrap_schedules, Schedule};
+
+use super::{
+    mapping::auto_fetch_all_mappings,
+    storage::{get_storage_backend, StorageLocation},
+};
+
+pub fn auto_fetch_all_schedules(project_id: &str) -> Result<Vec<Schedule>, String> {
+    let location = StorageLocation::project(project_id, "schedules", "");
+
+    Ok(unwrap_schedules(
+        get_storage_backend(&auto_fetch_all_mappings()).fetch(&location),
+    ))
+}
+
+pub fn auto_save_all_schedules(project_id: &str, schedules: &Vec<Schedule>) -> Result<(), String> {
+    let location = StorageLocation::project(project_id, "schedules", "");
+
+    get_storage_backend(&auto_fetch_all_mappings()).save(&location, stringify_schedules(schedules));
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use chrono::prelude::*;
+use rocket::tokio::time::{sleep, Duration};
+
+use crate::components::cron::CronExpression;
+use crate::components::routing::mod_route::RouteComponent;
+use crate::components::schedule::{CatchUpPolicy, Schedule};
+use crate::routes::x::x::execute_route;
+
+use super::{
+    config::get_config_value,
+    event::auto_create_event,
+    mapping::auto_fetch_all_mappings,
+    project::auto_fetch_all_projects,
+    redis::acquire_redis_lock,
+    route::auto_fetch_all_routes,
+    schedule::{auto_fetch_all_schedules, auto_save_all_schedules},
+};
+
+const MISSED_AFTER: i64 = 120;
+const RUN_LOCK_TTL: u32 = 300;
+
+pub fn requires_auth(route: &RouteComponent) -> bool {
+    if route.auth_api_key {
+        return true;
+    }
+
+    match &route.auth_jwt {
+        Some(aj) => aj.active,
+        None => false,
+    }
+}
+
+pub fn get_due_runs(schedule: &Schedule, now: i64, max_catch_up: usize) -> Vec<i64> {
+    let cron = match CronExpression::parse(&schedule.cron) {
+        Ok(c) => c,
+        Err(e) => {
+            println!("{}", e.1);
+            return vec![];
+        }
+    };
+
+    match schedule.catch_up {
+        CatchUpPolicy::SKIP => {
+            let after = std::cmp::max(schedule.last_run, now - MISSED_AFTER);
+            match cron.occurrences(after, now, usize::MAX).last() {
+                Some(latest) => vec![*latest],
+                None => vec![],
+            }
+        }
+        CatchUpPolicy::ONCE => match cron.occurrences(schedule.last_run, now, 1).first() {
+            Some(_) => vec![now],
+            None => vec![],
+        },
+        CatchUpPolicy::ALL => cron.occurrences(schedule.last_run, now, max_catch_up),
+    }
+}
+
+pub async fn run_schedules(now: i64) {
+    let mappings = auto_fetch_all_mappings();
+    let all_projects = match auto_fetch_all_projects(&mappings) {
+        Ok(p) => p,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    let max_catch_up = get_config_value(&mappings, "SCHEDULER_MAX_CATCH_UP", "10")
+        .parse::<usize>()
+        .unwrap_or(10);
+
+    for project in all_projects {
+        let all_schedules = match auto_fetch_all_schedules(&project.id) {
+            Ok(s) => s,
+            Err(e) => {
+                println!("{}", e);
+                continue;
+            }
+        };
+
+        if all_schedules.len() < 1 {
+            continue;
+        }
+
+        let all_routes = match auto_fetch_all_routes(&project.id) {
+            Ok(r) => r,
+            Err(e) => {
+                println!("{}", e);
+                continue;
+            }
+        };
+
+        let mut processed_schedules = Vec::<String>::new();
+
+        for schedule in all_schedules.iter() {
+            let due_runs = get_due_runs(schedule, now, max_catch_up);
+            if due_runs.len() < 1 {
+                continue;
+            }
+
+            processed_schedules.push(schedule.id.clone());
+
+            for due_run in due_runs {
+                let lock_key = format!("schedule_lock:{}:{}:{}", project.id, schedule.id, due_run);
+                if let Ok(false) = acquire_redis_lock(&lock_key, RUN_LOCK_TTL) {
+                    continue;
+                }
+
+                let due_time = match Utc.timestamp_opt(due_run, 0).single() {
+                    Some(t) => t.to_string(),
+                    None => due_run.to_string(),
+                };
+
+                let (event_type, outcome) =
+                    match RouteComponent::get(&all_routes, &project.id, &schedule.route_id) {
+                        Ok(route) if requires_auth(&route) => (
+                            "schedule_fail",
+                            String::from("failed -> route requires authentication"),
+                        ),
+                        Ok(route) => {
+                            let response = execute_route(
+                                &route,
+                                &project.id,
+                                schedule.body.clone(),
+                                String::new(),
+                                vec![],
+                                String::new(),
+                                String::new(),
+                                None,
+                                false,
+                            )
+                            .await;
+
+                            (
+                                if response.status >= 200 && response.status < 300 {
+                                    "schedule_run"
+                                } else {
+                                    "schedule_fail"
+                                },
+                                format!("completed with status {}", response.status),
+                            )
+                        }
+                        Err(e) => ("schedule_fail", format!("failed -> {}", e.1)),
+                    };
+
+                if let Err(e) = auto_create_event(
+                    &mappings,
+                    event_type,
+                    format!(
+                        "The schedule <{}> for route <{}> under pro[{}] due at {} {}",
+                        schedule.id,
+                        schedule.route_id,
+                        project.id,
+                        due_time,
+                        outcome.replace(";", ",")
+                    ),
+                    format!("/routes/p/{}/r/v/{}", project.id, schedule.route_id),
+                ) {
+                    println!("{}", e.1);
+                }
+            }
+        }
+
+        if processed_schedules.len() < 1 {
+            continue;
+        }
+
+        let mut latest_schedules = match auto_fetch_all_schedules(&project.id) {
+            Ok(s) => s,
+            Err(e) => {
+                println!("{}", e);
+                continue;
+            }
+        };
+
+        for schedule_id in processed_schedules.iter() {
+            if let Err(e) = Schedule::update_last_run(&mut latest_schedules, schedule_id, now) {
+                println!("{}", e.1);
+            }
+        }
+
+        if let Err(e) = auto_save_all_schedules(&project.id, &latest_schedules) {
+            println!("{}", e);
+        }
+    }
+}
+
+pub async fn start_scheduler() {
+    println!("Scheduler started");
+
+    loop {
+        let seconds_left = 60 - Utc::now().timestamp().rem_euclid(60);
+        sleep(Duration::from_secs(seconds_left as u64)).await;
+
+        run_schedules(Utc::now().timestamp()).await;
+    }
+}
@@ -0,0 +1,550 @@
+use std::fmt;
+use std::sync::Mutex;
+
+use crate::components::{
+    data::{
+        commit_all_data, fetch_all_data, save_all_data, save_data_entries, stringify_data,
+        unwrap_data, Data,
+    },
+    data_log::LogEntry,
+    encryption::EncryptionKey,
+    io::{fetch_file, save_file},
+    mapping::{get_file_name, Mapping},
+};
+
+use super::{
+    config::get_config_value, encryption_key::get_encryption_key, io::get_root_data_dir,
+    key_rotation::acquire_rotation_gate, redis::get_redis_connection,
+};
+
+pub const REDIS_STORAGE_KEYS: &str = "storage_keys";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageType {
+    FILE,
+    MEMORY,
+    REDIS,
+}
+
+impl Default for StorageType {
+    fn default() -> Self {
+        StorageType::FILE
+    }
+}
+
+impl fmt::Display for StorageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let storage_txt = match self {
+            StorageType::FILE => "FILE".to_string(),
+            StorageType::MEMORY => "MEMORY".to_string(),
+            StorageType::REDIS => "REDIS".to_string(),
+        };
+
+        write!(f, "{}", storage_txt)
+    }
+}
+
+impl StorageType {
+    pub fn to(storage_type: StorageType) -> String {
+        return match storage_type.clone() {
+            StorageType::FILE => "FILE".to_string(),
+            StorageType::MEMORY => "MEMORY".to_string(),
+            StorageType::REDIS => "REDIS".to_string(),
+        };
+    }
+
+    pub fn from(storage_txt: &str) -> StorageType {
+        return match storage_txt.trim().to_uppercase().as_str() {
+            "FILE" => StorageType::FILE,
+            "MEMORY" => StorageType::MEMORY,
+            "REDIS" => StorageType::REDIS,
+            _ => StorageType::FILE,
+        };
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StorageLocation {
+    pub key: String,
+    pub path: String,
+    pub encryption_key: String,
+}
+
+impl StorageLocation {
+    pub fn new(key: &str, path: &str, encryption_key: &str) -> StorageLocation {
+        StorageLocation {
+            key: key.to_string(),
+            path: path.to_string(),
+            encryption_key: encryption_key.to_string(),
+        }
+    }
+
+    pub fn component(mappings: &Vec<Mapping>, name: &str) -> Result<StorageLocation, String> {
+        let path = match get_file_name(name, mappings) {
+            Ok(path) => path,
+            Err(e) => return Err(e),
+        };
+
+        Ok(StorageLocation::new(
+            name,
+            &path,
+            &get_tmp_encryption_key(mappings),
+        ))
+    }
+
+    pub fn project(project_id: &str, name: &str, encryption_key: &str) -> StorageLocation {
+        StorageLocation::new(
+            &format!("{}_{}", name, project_id),
+            &format!(
+                "{}/data/projects/{}/{}.txt",
+                get_root_data_dir(),
+                project_id,
+                name
+            ),
+            encryption_key,
+        )
+    }
+
+    pub fn collection(
+        mappings: &Vec<Mapping>,
+        project_id: &str,
+        collection_id: &str,
+    ) -> StorageLocation {
+        StorageLocation::new(
+            &format!("data_{}_{}", project_id, collection_id),
+            &format!(
+                "{}/data/projects/{}/{}/data.txt",
+                get_root_data_dir(),
+                project_id,
+                collection_id
+            ),
+            &get_tmp_encryption_key(mappings),
+        )
+    }
+}
+
+pub trait StorageBackend {
+    fn fetch(&self, location: &StorageLocation) -> String;
+    fn save(&self, location: &StorageLocation, data: String);
+
+    fn fetch_data(&self, location: &StorageLocation) -> Vec<Data> {
+        unwrap_data(self.fetch(location))
+    }
+
+    fn save_data(&self, location: &StorageLocation, all_data: &Vec<Data>) {
+        self.save(location, stringify_data(all_data));
+    }
+
+    fn save_data_entries(
+        &self,
+        location: &StorageLocation,
+        all_data: &Vec<Data>,
+        _entries: &Vec<LogEntry>,
+    ) {
+        self.save_data(location, all_data);
+    }
+
+    fn commit_data(&self, all_staged: &Vec<(StorageLocation, Vec<Data>)>) -> Result<(), String> {
+        for (location, all_data) in all_staged.iter() {
+            self.save(location, stringify_data(all_data));
+        }
+
+        Ok(())
+    }
+}
+
+pub struct FileStorage;
+
+impl StorageBackend for FileStorage {
+    fn fetch(&self, location: &StorageLocation) -> String {
+        if location.key.len() > 0 {
+            if let Some(cached) = redis_get(&location.key, &location.encryption_key) {
+                return cached;
+            }
+        }
+
+        fetch_file(location.path.clone(), &location.encryption_key)
+    }
+
+    fn save(&self, location: &StorageLocation, data: String) {
+        if location.key.len() > 0 {
+            redis_set(&location.key, &data, &location.encryption_key);
+        }
+
+        save_file(location.path.clone(), data, &location.encryption_key);
+    }
+
+    fn fetch_data(&self, location: &StorageLocation) -> Vec<Data> {
+        if let Some(cached) = redis_get(&location.key, &location.encryption_key) {
+            return unwrap_data(cached);
+        }
+
+        fetch_all_data(location.path.clone(), &location.encryption_key)
+    }
+
+    fn save_data(&self, location: &StorageLocation, all_data: &Vec<Data>) {
+        redis_set(
+            &location.key,
+            &stringify_data(all_data),
+            &location.encryption_key,
+        );
+        save_all_data(all_data, location.path.clone(), &location.encryption_key);
+    }
+
+    fn save_data_entries(
+        &self,
+        location: &StorageLocation,
+        all_data: &Vec<Data>,
+        entries: &Vec<LogEntry>,
+    ) {
+        redis_set(
+            &location.key,
+            &stringify_data(all_data),
+            &location.encryption_key,
+        );
+        save_data_entries(
+            all_data,
+            entries,
+            location.path.clone(),
+            &location.encryption_key,
+        );
+    }
+
+    fn commit_data(&self, all_staged: &Vec<(StorageLocation, Vec<Data>)>) -> Result<(), String> {
+        if let Err(e) = commit_staged_files(all_staged) {
+            return Err(e);
+        }
+
+        for (location, all_data) in all_staged.iter() {
+            redis_set(
+                &location.key,
+                &stringify_data(all_data),
+                &location.encryption_key,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+static MEMORY_STORAGE: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+pub struct MemoryStorage;
+
+impl MemoryStorage {
+    pub fn clear() {
+        if let Ok(mut all_entries) = MEMORY_STORAGE.lock() {
+            all_entries.clear();
+        }
+    }
+}
+
+impl StorageBackend for MemoryStorage {
+    fn fetch(&self, location: &StorageLocation) -> String {
+        let mut all_entries = match MEMORY_STORAGE.lock() {
+            Ok(e) => e,
+            Err(_) => return String::new(),
+        };
+
+        for (path, data) in all_entries.iter() {
+            if *path == location.path {
+                return data.clone();
+            }
+        }
+
+        let data = if std::path::Path::new(&location.path).exists() {
+            fetch_file(location.path.clone(), &location.encryption_key)
+        } else {
+            String::new()
+        };
+        all_entries.push((location.path.clone(), data.clone()));
+
+        data
+    }
+
+    fn save(&self, location: &StorageLocation, data: String) {
+        let mut all_entries = match MEMORY_STORAGE.lock() {
+            Ok(e) => e,
+            Err(_) => return,
+        };
+
+        for entry in all_entries.iter_mut() {
+            if entry.0 == location.path {
+                entry.1 = data;
+                return;
+            }
+        }
+
+        all_entries.push((location.path.clone(), data));
+    }
+
+    fn fetch_data(&self, location: &StorageLocation) -> Vec<Data> {
+        let seeded = match MEMORY_STORAGE.lock() {
+            Ok(all_entries) => all_entries.iter().any(|(path, _)| *path == location.path),
+            Err(_) => true,
+        };
+
+        if !seeded && std::path::Path::new(&location.path).exists() {
+            let all_data = fetch_all_data(location.path.clone(), &location.encryption_key);
+            self.save(location, stringify_data(&all_data));
+        }
+
+        unwrap_data(self.fetch(location))
+    }
+}
+
+pub struct RedisStorage;
+
+impl StorageBackend for RedisStorage {
+    fn fetch(&self, location: &StorageLocation) -> String {
+        let key = if location.key.len() > 0 {
+            location.key.clone()
+        } else {
+            location.path.clone()
+        };
+
+        if let Some(stored) = redis_get(&key, &location.encryption_key) {
+            return stored;
+        }
+
+        let data = fetch_file(location.path.clone(), &location.encryption_key);
+        if data.len() > 0 {
+            redis_set(&key, &data, &location.encryption_key);
+        }
+
+        data
+    }
+
+    fn save(&self, location: &StorageLocation, data: String) {
+        let key = if location.key.len() > 0 {
+            location.key.clone()
+        } else {
+            location.path.clone()
+        };
+
+        if !redis_set(&key, &data, &location.encryption_key) {
+            save_file(location.path.clone(), data, &location.encryption_key);
+        }
+    }
+
+    fn fetch_data(&self, location: &StorageLocation) -> Vec<Data> {
+        if let Some(stored) = redis_get(&location.key, &location.encryption_key) {
+            return unwrap_data(stored);
+        }
+
+        let all_data = fetch_all_data(location.path.clone(), &location.encryption_key);
+        if all_data.len() > 0 {
+            redis_set(
+                &location.key,
+                &stringify_data(&all_data),
+                &location.encryption_key,
+            );
+        }
+
+        all_data
+    }
+
+    fn save_data(&self, location: &StorageLocation, all_data: &Vec<Data>) {
+        if !redis_set(
+            &location.key,
+            &stringify_data(all_data),
+            &location.encryption_key,
+        ) {
+            save_all_data(all_data, location.path.clone(), &location.encryption_key);
+        }
+    }
+
+    fn save_data_entries(
+        &self,
+        location: &StorageLocation,
+        all_data: &Vec<Data>,
+        entries: &Vec<LogEntry>,
+    ) {
+        // Redis keeps full snapshots, only the file fallback appends to the data log
+        if !redis_set(
+            &location.key,
+            &stringify_data(all_data),
+            &location.encryption_key,
+        ) {
+            save_data_entries(
+                all_data,
+                entries,
+                location.path.clone(),
+                &location.encryption_key,
+            );
+        }
+    }
+
+    fn commit_data(&self, all_staged: &Vec<(StorageLocation, Vec<Data>)>) -> Result<(), String> {
+        let all_pairs = all_staged
+            .iter()
+            .map(|(location, all_data)| {
+                (
+                    location.key.clone(),
+                    seal_redis_value(&stringify_data(all_data), &location.encryption_key),
+                )
+            })
+            .collect::<Vec<(String, String)>>();
+
+        if redis_set_all(&all_pairs) {
+            return Ok(());
+        }
+
+        commit_staged_files(all_staged)
+    }
+}
+
+fn commit_staged_files(all_staged: &Vec<(StorageLocation, Vec<Data>)>) -> Result<(), String> {
+    let encryption_key = match all_staged.first() {
+        Some((location, _)) => location.encryption_key.clone(),
+        None => return Ok(()),
+    };
+
+    let all_files = all_staged
+        .iter()
+        .map(|(location, all_data)| (location.path.clone(), all_data.clone()))
+        .collect::<Vec<(String, Vec<Data>)>>();
+
+    commit_all_data(&all_files, &encryption_key)
+}
+
+pub fn seal_redis_value(data: &str, encryption_key: &str) -> String {
+    if encryption_key.len() > 2 {
+        return EncryptionKey::encrypt(data.to_string(), encryption_key);
+    }
+
+    data.to_string()
+}
+
+pub fn open_redis_value(stored: &str, encryption_key: &str) -> Option<String> {
+    if encryption_key.len() > 2 {
+        return match EncryptionKey::decrypt(stored.to_string(), encryption_key) {
+            Ok(d) => Some(d.0),
+            _ => None,
+        };
+    }
+
+    Some(stored.to_string())
+}
+
+fn redis_get(key: &str, encryption_key: &str) -> Option<String> {
+    let mut con = match get_redis_connection() {
+        Ok(con) => con,
+        _ => return None,
+    };
+
+    let stored = match redis::cmd("GET").arg(key).query::<Option<String>>(&mut con) {
+        Ok(Some(d)) => d,
+        _ => return None,
+    };
+
+    let data = open_redis_value(&stored, encryption_key);
+    if data.is_none() {
+        println!("Error: Failed decrypting {} from Redis", key);
+    }
+
+    data
+}
+
+fn redis_set(key: &str, data: &str, encryption_key: &str) -> bool {
+    redis_set_all(&vec![(
+        key.to_string(),
+        seal_redis_value(data, encryption_key),
+    )])
+}
+
+fn redis_set_all(all_pairs: &Vec<(String, String)>) -> bool {
+    let _gate = acquire_rotation_gate();
+
+    let mut con = match get_redis_connection() {
+        Ok(con) => con,
+        _ => return false,
+    };
+
+    let mut pipe = redis::pipe();
+    pipe.atomic();
+    for (key, data) in all_pairs.iter() {
+        pipe.set(key, data).ignore();
+        pipe.sadd(REDIS_STORAGE_KEYS, key).ignore();
+    }
+
+    match pipe.query::<()>(&mut con) {
+        Ok(_) => true,
+        Err(e) => {
+            println!("Error: Failed saving data to Redis ({})", e);
+            false
+        }
+    }
+}
+
+pub fn rotate_redis_storage(old_key: &str, new_key: &str) -> Result<(), (usize, String)> {
+    let mut con = match get_redis_connection() {
+        Ok(con) => con,
+        _ => return Ok(()),
+    };
+
+    let all_keys = match redis::cmd("SMEMBERS")
+        .arg(REDIS_STORAGE_KEYS)
+        .query::<Vec<String>>(&mut con)
+    {
+        Ok(k) => k,
+        Err(e) => {
+            return Err((
+                500,
+                format!("Error: Failed listing Redis storage keys ({})", e),
+            ))
+        }
+    };
+
+    for key in all_keys.iter() {
+        let stored = match redis::cmd("GET").arg(key).query::<Option<String>>(&mut con) {
+            Ok(Some(d)) => d,
+            Ok(None) => continue,
+            Err(e) => {
+                return Err((
+                    500,
+                    format!("Error: Failed reading {} from Redis ({})", key, e),
+                ))
+            }
+        };
+
+        let data = match open_redis_value(&stored, old_key) {
+            Some(d) => d,
+            None => continue,
+        };
+
+        if let Err(e) = redis::cmd("SET")
+            .arg(key)
+            .arg(seal_redis_value(&data, new_key))
+            .query::<()>(&mut con)
+        {
+            return Err((
+                500,
+                format!("Error: Failed rotating {} in Redis ({})", key, e),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn get_tmp_encryption_key(mappings: &Vec<Mapping>) -> String {
+    let tmp_password = match std::env::var("TMP_PASSWORD") {
+        Ok(pass) => pass,
+        _ => "password".to_string(),
+    };
+
+    get_encryption_key(mappings, &tmp_password)
+}
+
+pub fn get_storage_type(mappings: &Vec<Mapping>) -> StorageType {
+    StorageType::from(&get_config_value(mappings, "STORAGE_BACKEND", "FILE"))
+}
+
+pub fn get_storage_backend(mappings: &Vec<Mapping>) -> Box<dyn StorageBackend> {
+    match get_storage_type(mappings) {
+        StorageType::FILE => Box::new(FileStorage),
+        StorageType::MEMORY => Box::new(MemoryStorage),
+        StorageType::REDIS => Box::new(RedisStorage),
+    }
+}
@@ -2,24 +2,31 @@ use rocket::serde::{Deserialize, Serialize};
 
 use serde_json::Value;
 
+use crate::components::datapair::DataPair;
 use crate::components::raw_pair::RawPair;
 use crate::components::routing::mod_route::RouteComponent;
 use crate::routes::x::x::LocalParamData;
 
 use super::definitions::define_assign::define_assign;
+use super::definitions::define_auth::define_auth;
 use super::definitions::define_body::define_body;
+use super::definitions::define_call::define_call;
 use super::definitions::define_create::define_create;
+use super::definitions::define_delete::define_delete;
 use super::definitions::define_fetch::define_fetch;
 use super::definitions::define_filter::define_filter;
 use super::definitions::define_function::define_function;
 use super::definitions::define_loop::define_loop;
 use super::definitions::define_object::define_object;
 use super::definitions::define_param::define_param;
+use super::definitions::define_path::define_path;
 use super::definitions::define_property::define_property;
+use super::definitions::define_sort::define_sort;
 use super::definitions::define_template::define_template;
 use super::definitions::define_update::define_update;
 
 use super::global_block_order::GlobalBlockOrder;
+use super::transaction::Transaction;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DefinitionStore {
@@ -52,6 +59,10 @@ impl DefinitionStore {
         current_index: usize,
         actual_body: &Value,
         all_params: &Vec<LocalParamData>,
+        all_path_params: &Vec<LocalParamData>,
+        auth_claims: &Vec<DataPair>,
+        transaction: &mut Transaction,
+        call_depth: usize,
     ) -> Result<(), (usize, String)> {
         let mut actual_definition = DefinitionStore {
             block_name: block_name.to_string(),
@@ -85,11 +96,28 @@ impl DefinitionStore {
                     }
                 }
             }
+        } else if block_name == "PATH" {
+            match define_path(current_route, all_path_params, index) {
+                Ok((id, d)) => {
+                    actual_definition.ref_name = id;
+                    actual_definition.data = d;
+                }
+                Err(e) => {
+                    return Err(e);
+                }
+            }
+        } else if block_name == "AUTH" {
+            if let Some(aj) = &current_route.auth_jwt {
+                if index < aj.claims.len() {
+                    actual_definition.ref_name = aj.claims[index].clone();
+                    actual_definition.data = define_auth(&aj.claims[index], auth_claims);
+                }
+            }
         } else if block_name == "FETCH" {
             let fetch_block = current_route.flow.fetchers[index].clone();
 
             actual_definition.ref_name = fetch_block.local_name.clone();
-            match define_fetch(fetch_block, project_id) {
+            match define_fetch(fetch_block, project_id, transaction) {
                 Ok(d) => {
                     actual_definition.data = d;
                 }
@@ -138,11 +166,33 @@ impl DefinitionStore {
                     return Err(e);
                 }
             }
+        } else if block_name == "LOOP_INDEX" {
+            actual_definition.ref_name = current_route.flow.loops[index].index_name.clone();
+            actual_definition.data = DefinitionData::INTEGER(0);
         } else if block_name == "FILTER" {
             let filter_block = current_route.flow.filters[index].clone();
 
             actual_definition.ref_name = filter_block.local_name.clone();
-            match define_filter(filter_block, global_blocks, all_definitions, current_index) {
+            match define_filter(
+                filter_block,
+                global_blocks,
+                all_definitions,
+                current_index,
+                project_id,
+                transaction,
+            ) {
+                Ok(d) => {
+                    actual_definition.data = d;
+                }
+                Err(e) => {
+                    return Err(e);
+                }
+            }
+        } else if block_name == "SORT" {
+            let sort_block = current_route.flow.sorts[index].clone();
+
+            actual_definition.ref_name = sort_block.local_name.clone();
+            match define_sort(sort_block, global_blocks, all_definitions, current_index) {
                 Ok(d) => {
                     actual_definition.data = d;
                 }
@@ -150,6 +200,32 @@ impl DefinitionStore {
                     return Err(e);
                 }
             }
+        } else if block_name == "CALL" {
+            let call_block = current_route.flow.calls[index].clone();
+
+            actual_definition.ref_name = call_block.local_name.clone();
+            match define_call(
+                call_block,
+                global_blocks,
+                all_definitions,
+                current_index,
+                project_id,
+                &current_route.route_id,
+                auth_claims,
+                transaction,
+                call_depth,
+            ) {
+                Ok(d) => {
+                    actual_definition.data = d;
+                }
+                Err(e) => {
+                    return Err(e);
+                }
+            }
+        } else if block_name == "TRY" {
+            let try_block = current_route.flow.tries[index].clone();
+
+            actual_definition.ref_name = try_block.local_name.clone();
         } else if block_name == "PROPERTY" {
             let property_block = current_route.flow.properties[index].clone();
 
@@ -206,6 +282,7 @@ impl DefinitionStore {
                 all_definitions,
                 current_index,
                 project_id,
+                transaction,
             ) {
                 Ok(d) => {
                     actual_definition.data = d;
@@ -224,6 +301,26 @@ impl DefinitionStore {
                 all_definitions,
                 current_index,
                 project_id,
+                transaction,
+            ) {
+                Ok(d) => {
+                    actual_definition.data = d;
+                }
+                Err(e) => {
+                    return Err(e);
+                }
+            }
+        } else if block_name == "DELETE" {
+            let delete_block = current_route.flow.deletes[index].clone();
+
+            actual_definition.ref_name = format!("delete_{}_{}", delete_block.ref_col, index);
+            match define_delete(
+                delete_block,
+                global_blocks,
+                all_definitions,
+                current_index,
+                project_id,
+                transaction,
             ) {
                 Ok(d) => {
                     actual_definition.data = d;
@@ -234,27 +331,34 @@ impl DefinitionStore {
             }
         }
 
-        if current_index >= all_definitions.len() {
-            all_definitions.push(actual_definition);
-        } else {
-            all_definitions[current_index] = actual_definition;
+        for definition in all_definitions.iter_mut() {
+            if definition.block_name == actual_definition.block_name
+                && definition.index == actual_definition.index
+            {
+                *definition = actual_definition;
+                return Ok(());
+            }
         }
 
+        all_definitions.push(actual_definition);
+
         Ok(())
     }
 
-    pub fn update_definition_value(
+    pub fn set_definition_value(
         all_definitions: &mut Vec<DefinitionStore>,
-        position: usize,
+        block_name: &str,
+        index: usize,
         data: DefinitionData,
     ) -> Result<(), (usize, String)> {
-        if position > all_definitions.len() {
-            return Err((500, String::from("Error: Invalid position for definition")));
+        for definition in all_definitions.iter_mut() {
+            if definition.block_name == block_name && definition.index == index {
+                definition.data = data;
+                return Ok(());
+            }
         }
 
-        all_definitions[position].data = data;
-
-        Ok(())
+        Err((500, String::from("Error: Invalid position for definition")))
     }
 
     pub fn get_raw_definition(
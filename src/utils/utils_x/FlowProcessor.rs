@@ -0,0 +1,418 @@
+use rocket::serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::components::datapair::DataPair;
+use crate::components::raw_pair::{RawPair, StructurePair};
+use crate::components::routing::mod_route::RouteComponent;
+use crate::routes::x::x::LocalParamData;
+
+use super::definition_store::{DefinitionData, DefinitionStore};
+use super::global_block_order::GlobalBlockOrder;
+use super::loop_processor::LoopObject;
+use super::signal_processor::{obtain_signal, Signal};
+use super::transaction::Transaction;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceStep {
+    pub position: usize,
+    pub block_name: String,
+    pub ref_name: String,
+    pub signal: Signal,
+    pub definitions: Vec<DefinitionStore>,
+}
+
+pub struct FlowContext<'a> {
+    pub current_route: &'a RouteComponent,
+    pub global_blocks: &'a Vec<GlobalBlockOrder>,
+    pub all_loops: &'a Vec<LoopObject>,
+    pub project_id: &'a str,
+    pub body_data: &'a Value,
+    pub all_params: &'a Vec<LocalParamData>,
+    pub all_path_params: &'a Vec<LocalParamData>,
+    pub auth_claims: &'a Vec<DataPair>,
+    pub depth: usize,
+}
+
+pub fn record_step(
+    trace: &mut Option<&mut Vec<TraceStep>>,
+    all_definitions: &Vec<DefinitionStore>,
+    block: &GlobalBlockOrder,
+    position: usize,
+    result: &Result<Signal, (usize, String)>,
+) {
+    if let Some(all_steps) = trace {
+        all_steps.push(TraceStep {
+            position: position,
+            block_name: block.name.clone(),
+            ref_name: block.ref_name.clone(),
+            signal: match result {
+                Ok(signal) => signal.clone(),
+                Err(e) => Signal::FAIL(e.0, e.1.clone(), vec![]),
+            },
+            definitions: all_definitions.clone(),
+        });
+    }
+}
+
+pub fn process_block(
+    flow_context: &FlowContext,
+    all_definitions: &mut Vec<DefinitionStore>,
+    transaction: &mut Transaction,
+    block: &GlobalBlockOrder,
+    current_index: usize,
+) -> Result<Signal, (usize, String)> {
+    if let Err(e) = DefinitionStore::add_definition(
+        flow_context.current_route,
+        all_definitions,
+        flow_context.global_blocks,
+        flow_context.project_id,
+        &block.name,
+        block.index,
+        current_index,
+        flow_context.body_data,
+        flow_context.all_params,
+        flow_context.all_path_params,
+        flow_context.auth_claims,
+        transaction,
+        flow_context.depth,
+    ) {
+        return Err(e);
+    }
+
+    obtain_signal(
+        flow_context.current_route,
+        all_definitions,
+        flow_context.global_blocks,
+        &block.name,
+        block.index,
+        current_index,
+    )
+}
+
+pub fn execute_flow(
+    current_route: &RouteComponent,
+    project_id: &str,
+    body_data: &Value,
+    all_params: &Vec<LocalParamData>,
+    all_path_params: &Vec<LocalParamData>,
+    auth_claims: &Vec<DataPair>,
+    transaction: &mut Transaction,
+    trace: &mut Option<&mut Vec<TraceStep>>,
+    depth: usize,
+) -> Result<Signal, (usize, String)> {
+    let mut global_blocks = Vec::<GlobalBlockOrder>::new();
+    GlobalBlockOrder::process_blocks(current_route, &mut global_blocks);
+
+    let all_loops = match LoopObject::detect_loops(&global_blocks, current_route) {
+        Ok(l) => l,
+        Err(e) => return Err(e),
+    };
+
+    let flow_context = FlowContext {
+        current_route: current_route,
+        global_blocks: &global_blocks,
+        all_loops: &all_loops,
+        project_id: project_id,
+        body_data: body_data,
+        all_params: all_params,
+        all_path_params: all_path_params,
+        auth_claims: auth_claims,
+        depth: depth,
+    };
+
+    let mut all_definitions = Vec::<DefinitionStore>::new();
+
+    process_flow(
+        &flow_context,
+        &mut all_definitions,
+        transaction,
+        trace,
+        0,
+        global_blocks.len(),
+        false,
+    )
+}
+
+pub fn process_flow(
+    flow_context: &FlowContext,
+    all_definitions: &mut Vec<DefinitionStore>,
+    transaction: &mut Transaction,
+    trace: &mut Option<&mut Vec<TraceStep>>,
+    start_index: usize,
+    end_index: usize,
+    in_loop: bool,
+) -> Result<Signal, (usize, String)> {
+    let mut current_index = start_index;
+
+    while current_index < end_index {
+        let current_block = &flow_context.global_blocks[current_index];
+
+        if current_block.name == "TRY" {
+            match process_try(
+                flow_context,
+                all_definitions,
+                transaction,
+                trace,
+                current_index,
+                end_index,
+                in_loop,
+            ) {
+                Ok((Signal::RETURN(r, s, h), _)) => return Ok(Signal::RETURN(r, s, h)),
+                Ok((Signal::FAIL(s, m, h), _)) => return Ok(Signal::FAIL(s, m, h)),
+                Ok((Signal::BREAK, _)) if in_loop => return Ok(Signal::BREAK),
+                Ok((Signal::CONTINUE, _)) if in_loop => return Ok(Signal::CONTINUE),
+                Ok((_, next_index)) => {
+                    current_index = next_index;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        if current_block.name == "LOOP" {
+            let mut current_loop: Option<&LoopObject> = None;
+            for cur_loop in flow_context.all_loops.iter() {
+                if cur_loop.start_index == current_index {
+                    current_loop = Some(cur_loop);
+                    break;
+                }
+            }
+
+            if let Some(cur_loop) = current_loop {
+                match process_loop(flow_context, all_definitions, transaction, trace, cur_loop) {
+                    Ok(Signal::RETURN(r, s, h)) => return Ok(Signal::RETURN(r, s, h)),
+                    Ok(Signal::FAIL(s, m, h)) => return Ok(Signal::FAIL(s, m, h)),
+                    Ok(_) => {}
+                    Err(e) => return Err(e),
+                }
+
+                current_index = cur_loop.end_index;
+                continue;
+            }
+        }
+
+        let result = process_block(
+            flow_context,
+            all_definitions,
+            transaction,
+            current_block,
+            current_index,
+        );
+        record_step(
+            trace,
+            all_definitions,
+            current_block,
+            current_index,
+            &result,
+        );
+
+        match result {
+            Ok(Signal::RETURN(r, s, h)) => return Ok(Signal::RETURN(r, s, h)),
+            Ok(Signal::FAIL(s, m, h)) => return Ok(Signal::FAIL(s, m, h)),
+            Ok(Signal::BREAK) if in_loop => return Ok(Signal::BREAK),
+            Ok(Signal::CONTINUE) if in_loop => return Ok(Signal::CONTINUE),
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        current_index += 1;
+    }
+
+    Ok(Signal::NONE)
+}
+
+pub fn process_loop(
+    flow_context: &FlowContext,
+    all_definitions: &mut Vec<DefinitionStore>,
+    transaction: &mut Transaction,
+    trace: &mut Option<&mut Vec<TraceStep>>,
+    cur_loop: &LoopObject,
+) -> Result<Signal, (usize, String)> {
+    let global_blocks = flow_context.global_blocks;
+    let loop_index = global_blocks[cur_loop.start_index].index;
+    let mut iteration = 0;
+
+    loop {
+        if iteration == 0 {
+            for n in cur_loop.start_index..cur_loop.body_index {
+                let result = process_block(
+                    flow_context,
+                    all_definitions,
+                    transaction,
+                    &global_blocks[n],
+                    n,
+                );
+                record_step(trace, all_definitions, &global_blocks[n], n, &result);
+
+                match result {
+                    Ok(Signal::FAIL(s, m, h)) => return Ok(Signal::FAIL(s, m, h)),
+                    Ok(_) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        } else {
+            let next_value = match LoopObject::next_value(
+                global_blocks,
+                all_definitions,
+                flow_context.current_route,
+                cur_loop,
+                iteration,
+            ) {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+
+            if next_value == DefinitionData::UNDEFINED {
+                break;
+            }
+
+            if let Err(e) = DefinitionStore::set_definition_value(
+                all_definitions,
+                "LOOP",
+                loop_index,
+                next_value,
+            ) {
+                return Err(e);
+            }
+
+            if cur_loop.body_index > cur_loop.start_index + 1 {
+                if let Err(e) = DefinitionStore::set_definition_value(
+                    all_definitions,
+                    "LOOP_INDEX",
+                    loop_index,
+                    DefinitionData::INTEGER(iteration as isize),
+                ) {
+                    return Err(e);
+                }
+            }
+        }
+
+        match LoopObject::check_completed(
+            global_blocks,
+            all_definitions,
+            flow_context.current_route,
+            cur_loop,
+            iteration,
+        ) {
+            Ok(true) => break,
+            Ok(false) => {}
+            Err(e) => return Err(e),
+        }
+
+        match process_flow(
+            flow_context,
+            all_definitions,
+            transaction,
+            trace,
+            cur_loop.body_index,
+            cur_loop.end_index,
+            true,
+        ) {
+            Ok(Signal::BREAK) => break,
+            Ok(Signal::RETURN(r, s, h)) => return Ok(Signal::RETURN(r, s, h)),
+            Ok(Signal::FAIL(s, m, h)) => return Ok(Signal::FAIL(s, m, h)),
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+
+        iteration += 1;
+    }
+
+    Ok(Signal::NONE)
+}
+
+pub fn process_try(
+    flow_context: &FlowContext,
+    all_definitions: &mut Vec<DefinitionStore>,
+    transaction: &mut Transaction,
+    trace: &mut Option<&mut Vec<TraceStep>>,
+    current_index: usize,
+    end_index: usize,
+    in_loop: bool,
+) -> Result<(Signal, usize), (usize, String)> {
+    let global_blocks = flow_context.global_blocks;
+    let try_index = global_blocks[current_index].index;
+    let try_block = flow_context.current_route.flow.tries[try_index].clone();
+
+    let result = process_block(
+        flow_context,
+        all_definitions,
+        transaction,
+        &global_blocks[current_index],
+        current_index,
+    );
+    record_step(
+        trace,
+        all_definitions,
+        &global_blocks[current_index],
+        current_index,
+        &result,
+    );
+
+    if let Err(e) = result {
+        return Err(e);
+    }
+
+    let mut try_end_index = match GlobalBlockOrder::get_position(global_blocks, try_block.end + 1) {
+        Some(position) => position,
+        None => global_blocks.len(),
+    };
+    if try_end_index > end_index {
+        try_end_index = end_index;
+    }
+
+    let mut fallback_index = match GlobalBlockOrder::get_position(global_blocks, try_block.fallback)
+    {
+        Some(position) => position,
+        None => global_blocks.len(),
+    };
+    if fallback_index > end_index {
+        fallback_index = end_index;
+    }
+
+    let staged_transaction = transaction.clone();
+
+    match process_flow(
+        flow_context,
+        all_definitions,
+        transaction,
+        trace,
+        current_index + 1,
+        try_end_index,
+        in_loop,
+    ) {
+        Ok(signal) => Ok((signal, try_end_index)),
+        Err(e) => {
+            *transaction = staged_transaction;
+
+            let caught_error = DefinitionData::DATA(RawPair {
+                data_id: String::new(),
+                structures: vec![
+                    StructurePair {
+                        id: String::from("status"),
+                        value: e.0.to_string(),
+                        rtype: String::from("INTEGER"),
+                    },
+                    StructurePair {
+                        id: String::from("message"),
+                        value: e.1,
+                        rtype: String::from("STRING"),
+                    },
+                ],
+                custom_structures: vec![],
+                published: false,
+            });
+
+            if let Err(e) = DefinitionStore::set_definition_value(
+                all_definitions,
+                "TRY",
+                try_index,
+                caught_error,
+            ) {
+                return Err(e);
+            }
+
+            Ok((Signal::NONE, fallback_index))
+        }
+    }
+}
@@ -1,8 +1,10 @@
 use rocket::serde::{Deserialize, Serialize};
 
 use crate::components::routing::blocks::assignment_block::AssignmentBlock;
+use crate::components::routing::blocks::call_block::CallBlock;
 use crate::components::routing::blocks::condition_block::ConditionBlock;
 use crate::components::routing::blocks::create_block::CreateBlock;
+use crate::components::routing::blocks::delete_block::DeleteBlock;
 use crate::components::routing::blocks::fetch_block::FetchBlock;
 use crate::components::routing::blocks::filter_block::FilterBlock;
 use crate::components::routing::blocks::function_block::FunctionBlock;
@@ -10,7 +12,9 @@ use crate::components::routing::blocks::loop_block::LoopBlock;
 use crate::components::routing::blocks::object_block::ObjectBlock;
 use crate::components::routing::blocks::property_block::PropertyBlock;
 use crate::components::routing::blocks::return_block::ReturnBlock;
+use crate::components::routing::blocks::sort_block::SortBlock;
 use crate::components::routing::blocks::template_block::TemplateBlock;
+use crate::components::routing::blocks::try_block::TryBlock;
 use crate::components::routing::blocks::update_block::UpdateBlock;
 
 use crate::components::routing::mod_route::RouteComponent;
@@ -48,18 +52,47 @@ impl GlobalBlockOrder {
             }
         }
 
+        let path_variables = current_route
+            .route_path
+            .split("/")
+            .filter(|segment| segment.starts_with(":"))
+            .collect::<Vec<&str>>();
+        for (i, segment) in path_variables.iter().enumerate() {
+            global_blocks.push(GlobalBlockOrder {
+                index: i,
+                block_index: -1,
+                name: String::from("PATH"),
+                ref_name: segment[1..].to_string(),
+            });
+        }
+
+        if let Some(aj) = &current_route.auth_jwt {
+            if aj.active {
+                for (i, claim) in aj.claims.iter().enumerate() {
+                    global_blocks.push(GlobalBlockOrder {
+                        index: i,
+                        block_index: -1,
+                        name: String::from("AUTH"),
+                        ref_name: claim.clone(),
+                    });
+                }
+            }
+        }
+
         let mut current_global_index: u32 = 0;
         let mut current_block_name: &str;
         let mut current_index_position: usize = 0;
         let mut current_ref_name: String;
         let mut current_block_index: isize;
+        let mut current_index_name: String;
 
-        let mut indexes: Vec<usize> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let mut indexes: Vec<usize> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
 
         loop {
             current_block_name = "";
             current_ref_name = String::from("");
             current_block_index = -1;
+            current_index_name = String::from("");
 
             if let Some(block) = FetchBlock::get(&current_route.flow.fetchers, current_global_index)
             {
@@ -94,6 +127,7 @@ impl GlobalBlockOrder {
                 current_index_position = 4;
                 current_ref_name = block.local_name;
                 current_block_index = block.block_index as isize;
+                current_index_name = block.index_name;
             } else if let Some(block) =
                 FilterBlock::get(&current_route.flow.filters, current_global_index)
             {
@@ -144,6 +178,37 @@ impl GlobalBlockOrder {
                 current_block_name = "RETURN";
                 current_index_position = 11;
                 current_block_index = block.block_index as isize;
+            } else if let Some(block) =
+                DeleteBlock::get(&current_route.flow.deletes, current_global_index)
+            {
+                current_block_name = "DELETE";
+                current_index_position = 12;
+                current_ref_name = format!(
+                    "delete_{}_{}",
+                    block.ref_col, indexes[current_index_position]
+                );
+                current_block_index = block.block_index as isize;
+            } else if let Some(block) =
+                SortBlock::get(&current_route.flow.sorts, current_global_index)
+            {
+                current_block_name = "SORT";
+                current_index_position = 13;
+                current_ref_name = block.local_name;
+                current_block_index = block.block_index as isize;
+            } else if let Some(block) =
+                CallBlock::get(&current_route.flow.calls, current_global_index)
+            {
+                current_block_name = "CALL";
+                current_index_position = 14;
+                current_ref_name = block.local_name;
+                current_block_index = block.block_index as isize;
+            } else if let Some(block) =
+                TryBlock::get(&current_route.flow.tries, current_global_index)
+            {
+                current_block_name = "TRY";
+                current_index_position = 15;
+                current_ref_name = block.local_name;
+                current_block_index = block.block_index as isize;
             }
 
             if current_block_name.len() > 0 {
@@ -153,6 +218,16 @@ impl GlobalBlockOrder {
                     name: current_block_name.to_string(),
                     ref_name: current_ref_name,
                 });
+
+                if current_index_name.len() > 0 {
+                    global_blocks.push(GlobalBlockOrder {
+                        index: indexes[current_index_position],
+                        block_index: current_block_index,
+                        name: String::from("LOOP_INDEX"),
+                        ref_name: current_index_name,
+                    });
+                }
+
                 indexes[current_index_position] += 1;
             } else {
                 break;
@@ -173,6 +248,24 @@ impl GlobalBlockOrder {
         block_names
     }
 
+    pub fn get_position(global_blocks: &Vec<GlobalBlockOrder>, global_index: u32) -> Option<usize> {
+        let mut current_global_index: u32 = 0;
+
+        for (i, block) in global_blocks.iter().enumerate() {
+            if block.block_index < 0 || block.name == "LOOP_INDEX" {
+                continue;
+            }
+
+            if current_global_index == global_index {
+                return Some(i);
+            }
+
+            current_global_index += 1;
+        }
+
+        None
+    }
+
     pub fn get_ref_index(
         global_blocks: &Vec<GlobalBlockOrder>,
         ref_name: &str,
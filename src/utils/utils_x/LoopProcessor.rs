@@ -11,65 +11,199 @@ use super::{
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoopObject {
     pub start_index: usize,
+    pub body_index: usize,
     pub end_index: usize,
     pub ref_var: String,
+    pub depth: usize,
+}
+
+struct LoopScope {
+    block_index: isize,
+    names: Vec<String>,
 }
 
 impl LoopObject {
     pub fn detect_loops(
         global_blocks: &Vec<GlobalBlockOrder>,
-        current_block: &GlobalBlockOrder,
+        current_route: &RouteComponent,
     ) -> Result<Vec<LoopObject>, (usize, String)> {
         let mut loop_objects = Vec::<LoopObject>::new();
 
-        let block_index = current_block.block_index;
-        let mut start_index = 0;
-        let mut is_processing_block = false;
-        let mut loop_var = String::new();
+        let all_depths = match LoopObject::derive_depths(global_blocks, current_route) {
+            Ok(d) => d,
+            Err(e) => return Err(e),
+        };
 
-        for (i, block) in global_blocks.iter().enumerate() {
-            if is_processing_block && (block.name == "LOOP" || block.block_index > block_index) {
-                is_processing_block = false;
-                loop_objects.push(LoopObject {
-                    start_index: start_index,
-                    end_index: i,
-                    ref_var: loop_var.clone(),
-                });
-                start_index = 0;
+        for (start_index, block) in global_blocks.iter().enumerate() {
+            if block.name != "LOOP" {
+                continue;
             }
 
-            if block.block_index == block_index && block.name == "LOOP" {
-                is_processing_block = true;
-                start_index = i;
-                loop_var = block.ref_name.clone();
+            let depth = all_depths[start_index];
+
+            let mut body_index = start_index + 1;
+            if body_index < global_blocks.len() && global_blocks[body_index].name == "LOOP_INDEX" {
+                body_index += 1;
+            }
+
+            let mut end_index = global_blocks.len();
+            for i in body_index..global_blocks.len() {
+                let next_block = &global_blocks[i];
+                if next_block.block_index > block.block_index {
+                    end_index = i;
+                    break;
+                }
+
+                if next_block.name == "LOOP" && all_depths[i] <= depth {
+                    end_index = i;
+                    break;
+                }
             }
-        }
 
-        if is_processing_block {
             loop_objects.push(LoopObject {
                 start_index: start_index,
-                end_index: global_blocks.len(),
-                ref_var: loop_var,
+                body_index: body_index,
+                end_index: end_index,
+                ref_var: block.ref_name.clone(),
+                depth: depth,
             });
         }
 
         Ok(loop_objects)
     }
 
+    // A loop is nested in the innermost open loop whose body defines a name it iterates over
+    fn derive_depths(
+        global_blocks: &Vec<GlobalBlockOrder>,
+        current_route: &RouteComponent,
+    ) -> Result<Vec<usize>, (usize, String)> {
+        let mut all_depths = vec![0; global_blocks.len()];
+        let mut open_scopes = Vec::<LoopScope>::new();
+
+        for (position, block) in global_blocks.iter().enumerate() {
+            while let Some(scope) = open_scopes.last() {
+                if scope.block_index >= block.block_index {
+                    break;
+                }
+                open_scopes.pop();
+            }
+
+            if block.name != "LOOP" {
+                if let Some(scope) = open_scopes.last_mut() {
+                    scope.names.push(block.ref_name.clone());
+                }
+                continue;
+            }
+
+            if block.index >= current_route.flow.loops.len() {
+                return Err((
+                    500,
+                    format!("Error: Loop '{}' could not be found", block.ref_name),
+                ));
+            }
+
+            let loop_block = &current_route.flow.loops[block.index];
+            let mut all_refs = vec![loop_block.min.clone(), loop_block.max.clone()];
+            if let Some(each) = loop_block.each.clone() {
+                all_refs.push(each);
+            }
+            let used_names = all_refs
+                .iter()
+                .filter(|r| r.ref_var)
+                .map(|r| r.data.clone())
+                .collect::<Vec<String>>();
+
+            match open_scopes
+                .iter()
+                .rposition(|scope| scope.names.iter().any(|n| used_names.contains(n)))
+            {
+                Some(parent) => open_scopes.truncate(parent + 1),
+                None => open_scopes.clear(),
+            }
+
+            all_depths[position] = open_scopes.len();
+            open_scopes.push(LoopScope {
+                block_index: block.block_index,
+                names: vec![block.ref_name.clone()],
+            });
+        }
+
+        Ok(all_depths)
+    }
+
+    pub fn next_value(
+        global_blocks: &Vec<GlobalBlockOrder>,
+        all_definitions: &Vec<DefinitionStore>,
+        current_route: &RouteComponent,
+        current_loop: &LoopObject,
+        iteration: usize,
+    ) -> Result<DefinitionData, (usize, String)> {
+        let loop_block =
+            current_route.flow.loops[global_blocks[current_loop.start_index].index].clone();
+
+        if let Some(each) = loop_block.each {
+            return match resolve_ref_data(
+                &each,
+                global_blocks,
+                all_definitions,
+                current_loop.start_index,
+            ) {
+                Ok(DefinitionData::ARRAY(items)) => {
+                    if iteration < items.len() {
+                        Ok(items[iteration].clone())
+                    } else {
+                        Ok(DefinitionData::NULL)
+                    }
+                }
+                Ok(_) => Err((500, format!("Error: '{}' is not an array", each.data))),
+                Err(e) => Err(e),
+            };
+        }
+
+        let current = match DefinitionStore::get_raw_definition(
+            all_definitions,
+            &current_loop.ref_var,
+            global_blocks[current_loop.start_index].index,
+        ) {
+            Some(def) => def.data,
+            None => DefinitionData::UNDEFINED,
+        };
+
+        match current {
+            DefinitionData::INTEGER(i) => Ok(DefinitionData::INTEGER(i + 1)),
+            DefinitionData::FLOAT(f) => Ok(DefinitionData::FLOAT(f + 1.0)),
+            _ => Ok(DefinitionData::UNDEFINED),
+        }
+    }
+
     pub fn check_completed(
         global_blocks: &Vec<GlobalBlockOrder>,
         all_definitions: &Vec<DefinitionStore>,
         current_route: &RouteComponent,
         current_loop: &LoopObject,
+        iteration: usize,
     ) -> Result<bool, (usize, String)> {
-        let mut global_loop_index = 0;
+        let global_loop_index = global_blocks[current_loop.start_index].index;
+
+        if let Some(each) = current_route.flow.loops[global_loop_index].each.clone() {
+            return match resolve_ref_data(
+                &each,
+                global_blocks,
+                all_definitions,
+                current_loop.start_index,
+            ) {
+                Ok(DefinitionData::ARRAY(items)) => Ok(iteration >= items.len()),
+                Ok(_) => Err((500, format!("Error: '{}' is not an array", each.data))),
+                Err(e) => Err(e),
+            };
+        }
+
         let current = match GlobalBlockOrder::get_ref_index(
             global_blocks,
             &current_loop.ref_var,
             current_loop.start_index + 1,
         ) {
             Ok(ri) => {
-                global_loop_index = ri.0;
                 let current_definition = DefinitionStore::get_raw_definition(
                     all_definitions,
                     &current_loop.ref_var,
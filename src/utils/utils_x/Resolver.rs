@@ -1,7 +1,11 @@
+use regex::Regex;
+
 use crate::components::routing::submodules::sub_body_data_type::BodyDataType;
 use crate::components::routing::submodules::sub_condition::Condition;
 use crate::components::routing::submodules::sub_condition_type::ConditionType;
-use crate::components::routing::submodules::sub_next_condition_type::NextConditionType;
+use crate::components::routing::submodules::sub_next_condition_type::{
+    NextConditionType, MAX_GROUP_DEPTH,
+};
 use crate::components::routing::submodules::sub_operation::Operation;
 use crate::components::routing::submodules::sub_operation_type::OperationType;
 use crate::components::routing::submodules::sub_ref_data::RefData;
@@ -9,6 +13,22 @@ use crate::components::routing::submodules::sub_ref_data::RefData;
 use super::definition_store::{DefinitionData, DefinitionStore};
 use super::global_block_order::GlobalBlockOrder;
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditionTerm {
+    pub open: u32,
+    pub value: bool,
+    pub close: u32,
+    pub next: NextConditionType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum GroupToken {
+    OPEN,
+    CLOSE,
+    VALUE(bool),
+    NEXT(NextConditionType),
+}
+
 pub fn resolve_raw_data(
     name: &str,
     global_blocks: &Vec<GlobalBlockOrder>,
@@ -209,6 +229,29 @@ pub fn resolve_ref_data(
     }
 }
 
+fn resolve_regex(value: &str, pattern: &str) -> Result<bool, (usize, String)> {
+    match Regex::new(&format!(r"{}", pattern)) {
+        Ok(re) => Ok(re.is_match(value)),
+        Err(_) => Err((500, format!("Error: Invalid regex pattern '{}'", pattern))),
+    }
+}
+
+fn resolve_is_null(
+    rdata: &RefData,
+    global_blocks: &Vec<GlobalBlockOrder>,
+    all_definitions: &Vec<DefinitionStore>,
+    current_index: usize,
+) -> Result<bool, (usize, String)> {
+    if !rdata.ref_var {
+        return Ok(false);
+    }
+
+    match resolve_raw_data(&rdata.data, global_blocks, all_definitions, current_index) {
+        Ok(v) => Ok(v == DefinitionData::NULL),
+        Err(e) => Err(e),
+    }
+}
+
 pub fn resolve_conditions(
     conditions: &Vec<Condition>,
     global_blocks: &Vec<GlobalBlockOrder>,
@@ -219,28 +262,38 @@ pub fn resolve_conditions(
         return Ok(true);
     }
 
-    let mut next = NextConditionType::NONE;
-    let mut current_eval = false;
+    let mut all_terms = Vec::<ConditionTerm>::new();
 
     for condition in conditions {
-        let left = match resolve_ref_data(
-            &condition.left,
-            global_blocks,
-            all_definitions,
-            current_index,
-        ) {
-            Ok(d) => d,
-            Err(e) => return Err(e),
+        let is_unary = condition.condition_type == ConditionType::IS_EMPTY
+            || condition.condition_type == ConditionType::IS_NULL;
+
+        let left = if condition.condition_type == ConditionType::IS_NULL {
+            DefinitionData::NULL
+        } else {
+            match resolve_ref_data(
+                &condition.left,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => d,
+                Err(e) => return Err(e),
+            }
         };
 
-        let right = match resolve_ref_data(
-            &condition.right,
-            global_blocks,
-            all_definitions,
-            current_index,
-        ) {
-            Ok(d) => d,
-            Err(e) => return Err(e),
+        let right = if is_unary {
+            DefinitionData::NULL
+        } else {
+            match resolve_ref_data(
+                &condition.right,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => d,
+                Err(e) => return Err(e),
+            }
         };
 
         let mut local_eval: bool;
@@ -430,26 +483,221 @@ pub fn resolve_conditions(
                     ));
                 }
             },
+            ConditionType::MATCHES_REGEX => match (left, right) {
+                (DefinitionData::STRING(l), DefinitionData::STRING(r)) => {
+                    local_eval = match resolve_regex(&l, &r) {
+                        Ok(m) => m,
+                        Err(e) => return Err(e),
+                    };
+                }
+                _ => {
+                    return Err((
+                        500,
+                        format!(
+                            "Error: Cannot compare '{}' and '{}' with 'matches_regex'",
+                            condition.left.data, condition.right.data
+                        ),
+                    ));
+                }
+            },
+            ConditionType::STARTS_WITH => match (left, right) {
+                (DefinitionData::STRING(l), DefinitionData::STRING(r)) => {
+                    local_eval = l.starts_with(&r);
+                }
+                _ => {
+                    return Err((
+                        500,
+                        format!(
+                            "Error: Cannot compare '{}' and '{}' with 'starts_with'",
+                            condition.left.data, condition.right.data
+                        ),
+                    ));
+                }
+            },
+            ConditionType::ENDS_WITH => match (left, right) {
+                (DefinitionData::STRING(l), DefinitionData::STRING(r)) => {
+                    local_eval = l.ends_with(&r);
+                }
+                _ => {
+                    return Err((
+                        500,
+                        format!(
+                            "Error: Cannot compare '{}' and '{}' with 'ends_with'",
+                            condition.left.data, condition.right.data
+                        ),
+                    ));
+                }
+            },
+            ConditionType::EQUAL_TO_IGNORE_CASE => match (left, right) {
+                (DefinitionData::STRING(l), DefinitionData::STRING(r)) => {
+                    local_eval = l.to_lowercase() == r.to_lowercase();
+                }
+                _ => {
+                    return Err((
+                        500,
+                        format!(
+                            "Error: Cannot compare '{}' and '{}' with 'equal_to_ignore_case'",
+                            condition.left.data, condition.right.data
+                        ),
+                    ));
+                }
+            },
+            ConditionType::IS_EMPTY => match left {
+                DefinitionData::NULL => {
+                    local_eval = true;
+                }
+                DefinitionData::STRING(l) => {
+                    local_eval = l.len() < 1;
+                }
+                DefinitionData::ARRAY(l) => {
+                    local_eval = l.len() < 1;
+                }
+                _ => {
+                    local_eval = false;
+                }
+            },
+            ConditionType::IS_NULL => {
+                local_eval = match resolve_is_null(
+                    &condition.left,
+                    global_blocks,
+                    all_definitions,
+                    current_index,
+                ) {
+                    Ok(n) => n,
+                    Err(e) => return Err(e),
+                };
+            }
         }
 
         if condition.not {
             local_eval = !local_eval;
         }
 
-        if next == NextConditionType::NONE {
-            current_eval = local_eval;
-        } else if next == NextConditionType::AND {
-            current_eval = current_eval && local_eval;
-        } else if next == NextConditionType::OR {
-            current_eval = current_eval || local_eval;
+        all_terms.push(ConditionTerm {
+            open: condition.open,
+            value: local_eval,
+            close: condition.close,
+            next: condition.next.clone(),
+        });
+    }
+
+    evaluate_terms(&all_terms)
+}
+
+pub fn evaluate_terms(terms: &Vec<ConditionTerm>) -> Result<bool, (usize, String)> {
+    if terms.len() == 0 {
+        return Ok(true);
+    }
+
+    let mut depth: u32 = 0;
+    for term in terms.iter() {
+        if term.open > MAX_GROUP_DEPTH || depth + term.open > MAX_GROUP_DEPTH {
+            return Err((
+                500,
+                format!(
+                    "Error: Condition groups cannot be nested deeper than {}",
+                    MAX_GROUP_DEPTH
+                ),
+            ));
         }
 
-        next = condition.next.clone();
+        depth += term.open;
+
+        if term.close > depth {
+            return Err((500, String::from("Error: Unbalanced condition groups")));
+        }
+        depth -= term.close;
+    }
+
+    let mut tokens = Vec::<GroupToken>::new();
+    for (i, term) in terms.iter().enumerate() {
+        for _ in 0..term.open {
+            tokens.push(GroupToken::OPEN);
+        }
+
+        tokens.push(GroupToken::VALUE(term.value));
+
+        for _ in 0..term.close {
+            tokens.push(GroupToken::CLOSE);
+        }
+
+        if i < terms.len() - 1 {
+            tokens.push(GroupToken::NEXT(term.next.clone()));
+        }
+    }
+
+    let mut position = 0;
+    let result = match evaluate_sequence(&tokens, &mut position) {
+        Ok(r) => r,
+        Err(e) => return Err(e),
+    };
+
+    if position != tokens.len() {
+        return Err((500, String::from("Error: Unbalanced condition groups")));
+    }
+
+    Ok(result)
+}
+
+fn evaluate_sequence(
+    tokens: &Vec<GroupToken>,
+    position: &mut usize,
+) -> Result<bool, (usize, String)> {
+    let mut current_eval = match evaluate_group(tokens, position) {
+        Ok(r) => r,
+        Err(e) => return Err(e),
+    };
+
+    while *position < tokens.len() {
+        let next = match &tokens[*position] {
+            GroupToken::NEXT(n) => n.clone(),
+            _ => break,
+        };
+        *position += 1;
+
+        let value = match evaluate_group(tokens, position) {
+            Ok(r) => r,
+            Err(e) => return Err(e),
+        };
+
+        current_eval = match next {
+            NextConditionType::AND => current_eval && value,
+            NextConditionType::OR => current_eval || value,
+            NextConditionType::NONE => value,
+        };
     }
 
     Ok(current_eval)
 }
 
+fn evaluate_group(tokens: &Vec<GroupToken>, position: &mut usize) -> Result<bool, (usize, String)> {
+    if *position >= tokens.len() {
+        return Err((500, String::from("Error: Unbalanced condition groups")));
+    }
+
+    match tokens[*position] {
+        GroupToken::VALUE(b) => {
+            *position += 1;
+            Ok(b)
+        }
+        GroupToken::OPEN => {
+            *position += 1;
+            let current_eval = match evaluate_sequence(tokens, position) {
+                Ok(r) => r,
+                Err(e) => return Err(e),
+            };
+
+            if *position >= tokens.len() || tokens[*position] != GroupToken::CLOSE {
+                return Err((500, String::from("Error: Unbalanced condition groups")));
+            }
+            *position += 1;
+
+            Ok(current_eval)
+        }
+        _ => Err((500, String::from("Error: Unbalanced condition groups"))),
+    }
+}
+
 pub fn resolve_operations(
     operations: &Vec<Operation>,
     global_blocks: &Vec<GlobalBlockOrder>,
@@ -460,24 +708,35 @@ pub fn resolve_operations(
     let mut current_eval = DefinitionData::NULL;
 
     for operation in operations {
-        let left = match resolve_ref_data(
-            &operation.left,
-            global_blocks,
-            all_definitions,
-            current_index,
-        ) {
-            Ok(d) => d,
-            Err(e) => return Err(e),
+        let is_unary = operation.operation_type == OperationType::IS_EMPTY
+            || operation.operation_type == OperationType::IS_NULL;
+
+        let left = if operation.operation_type == OperationType::IS_NULL {
+            DefinitionData::NULL
+        } else {
+            match resolve_ref_data(
+                &operation.left,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => d,
+                Err(e) => return Err(e),
+            }
         };
 
-        let right = match resolve_ref_data(
-            &operation.right,
-            global_blocks,
-            all_definitions,
-            current_index,
-        ) {
-            Ok(d) => d,
-            Err(e) => return Err(e),
+        let right = if is_unary {
+            DefinitionData::NULL
+        } else {
+            match resolve_ref_data(
+                &operation.right,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => d,
+                Err(e) => return Err(e),
+            }
         };
 
         let mut local_eval;
@@ -1029,6 +1288,90 @@ pub fn resolve_operations(
                     ));
                 }
             },
+            OperationType::MATCHES_REGEX => match (left, right) {
+                (DefinitionData::STRING(l), DefinitionData::STRING(r)) => {
+                    local_eval = match resolve_regex(&l, &r) {
+                        Ok(m) => DefinitionData::BOOLEAN(m),
+                        Err(e) => return Err(e),
+                    };
+                }
+                _ => {
+                    return Err((
+                        500,
+                        format!(
+                            "Error: Cannot compare '{}' and '{}' with 'matches_regex'",
+                            operation.left.data, operation.right.data
+                        ),
+                    ));
+                }
+            },
+            OperationType::STARTS_WITH => match (left, right) {
+                (DefinitionData::STRING(l), DefinitionData::STRING(r)) => {
+                    local_eval = DefinitionData::BOOLEAN(l.starts_with(&r));
+                }
+                _ => {
+                    return Err((
+                        500,
+                        format!(
+                            "Error: Cannot compare '{}' and '{}' with 'starts_with'",
+                            operation.left.data, operation.right.data
+                        ),
+                    ));
+                }
+            },
+            OperationType::ENDS_WITH => match (left, right) {
+                (DefinitionData::STRING(l), DefinitionData::STRING(r)) => {
+                    local_eval = DefinitionData::BOOLEAN(l.ends_with(&r));
+                }
+                _ => {
+                    return Err((
+                        500,
+                        format!(
+                            "Error: Cannot compare '{}' and '{}' with 'ends_with'",
+                            operation.left.data, operation.right.data
+                        ),
+                    ));
+                }
+            },
+            OperationType::EQUAL_TO_IGNORE_CASE => match (left, right) {
+                (DefinitionData::STRING(l), DefinitionData::STRING(r)) => {
+                    local_eval = DefinitionData::BOOLEAN(l.to_lowercase() == r.to_lowercase());
+                }
+                _ => {
+                    return Err((
+                        500,
+                        format!(
+                            "Error: Cannot compare '{}' and '{}' with 'equal_to_ignore_case'",
+                            operation.left.data, operation.right.data
+                        ),
+                    ));
+                }
+            },
+            OperationType::IS_EMPTY => match left {
+                DefinitionData::NULL => {
+                    local_eval = DefinitionData::BOOLEAN(true);
+                }
+                DefinitionData::STRING(l) => {
+                    local_eval = DefinitionData::BOOLEAN(l.len() < 1);
+                }
+                DefinitionData::ARRAY(l) => {
+                    local_eval = DefinitionData::BOOLEAN(l.len() < 1);
+                }
+                _ => {
+                    local_eval = DefinitionData::BOOLEAN(false);
+                }
+            },
+            OperationType::IS_NULL => {
+                local_eval = match resolve_is_null(
+                    &operation.left,
+                    global_blocks,
+                    all_definitions,
+                    current_index,
+                ) {
+                    Ok(n) => DefinitionData::BOOLEAN(n),
+                    Err(e) => return Err(e),
+                };
+            }
             OperationType::NONE => {
                 if left != DefinitionData::NULL && left != DefinitionData::UNDEFINED {
                     local_eval = left;
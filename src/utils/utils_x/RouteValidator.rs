@@ -0,0 +1,606 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::components::collection::Collection;
+use crate::components::routing::mod_route::RouteComponent;
+use crate::components::routing::submodules::sub_body_data_type::BodyDataType;
+use crate::components::routing::submodules::sub_condition::Condition;
+use crate::components::routing::submodules::sub_condition_type::ConditionType;
+use crate::components::routing::submodules::sub_function_list::FunctionList;
+use crate::components::routing::submodules::sub_next_condition_type::MAX_GROUP_DEPTH;
+use crate::components::routing::submodules::sub_operation_type::OperationType;
+use crate::components::routing::submodules::sub_ref_data::RefData;
+use crate::components::routing::submodules::sub_update_target::UpdateTarget;
+use crate::utils::field_encryption::is_encrypted_property;
+
+use super::global_block_order::GlobalBlockOrder;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Diagnostic {
+    pub block_name: String,
+    pub global_index: isize,
+    pub message: String,
+}
+
+struct BlockRefs {
+    global_index: u32,
+    ref_data: Vec<RefData>,
+    names: Vec<String>,
+    conditions: Vec<Condition>,
+    collection: Option<String>,
+    properties: Vec<String>,
+    comparisons: Vec<(String, bool)>,
+    property_source: Option<String>,
+    groups: Vec<Vec<(u32, u32)>>,
+    patterns: Vec<RefData>,
+}
+
+impl BlockRefs {
+    fn new(global_index: u32) -> BlockRefs {
+        BlockRefs {
+            global_index: global_index,
+            ref_data: vec![],
+            names: vec![],
+            conditions: vec![],
+            collection: None,
+            properties: vec![],
+            comparisons: vec![],
+            property_source: None,
+            groups: vec![],
+            patterns: vec![],
+        }
+    }
+}
+
+pub fn validate_route(
+    current_route: &RouteComponent,
+    project_id: &str,
+    all_collections: &Vec<Collection>,
+    all_routes: &Vec<RouteComponent>,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::<Diagnostic>::new();
+
+    if let Some(aj) = &current_route.auth_jwt {
+        if aj.active {
+            match Collection::get(all_collections, project_id, &aj.ref_col) {
+                Ok(collection) => {
+                    let mut auth_fields = aj.claims.clone();
+                    for rule in aj.rules.iter() {
+                        auth_fields.push(rule.field.clone());
+                    }
+
+                    for auth_field in auth_fields.iter() {
+                        if !collection
+                            .structures
+                            .iter()
+                            .any(|structure| structure.id == *auth_field)
+                        {
+                            diagnostics.push(Diagnostic {
+                                block_name: String::from("AUTH"),
+                                global_index: -1,
+                                message: format!(
+                                    "Error: Structure '{}' does not exist in Collection '{}'",
+                                    auth_field, aj.ref_col
+                                ),
+                            });
+                        }
+                    }
+                }
+                Err(_) => {
+                    diagnostics.push(Diagnostic {
+                        block_name: String::from("AUTH"),
+                        global_index: -1,
+                        message: format!("Error: Collection '{}' does not exist", aj.ref_col),
+                    });
+                }
+            }
+        }
+    }
+
+    if current_route.cache_ttl > 0 && !RouteComponent::is_read_only(current_route) {
+        diagnostics.push(Diagnostic {
+            block_name: String::from("CACHE"),
+            global_index: -1,
+            message: String::from("Error: Only read-only routes can be cached"),
+        });
+    }
+
+    let mut global_blocks = Vec::<GlobalBlockOrder>::new();
+    GlobalBlockOrder::process_blocks(current_route, &mut global_blocks);
+
+    let mut sources = HashMap::<String, String>::new();
+
+    for (position, block) in global_blocks.iter().enumerate() {
+        if block.block_index < 0 || block.name == "LOOP_INDEX" {
+            continue;
+        }
+
+        let block_refs = match collect_block_refs(current_route, block) {
+            Some(r) => r,
+            None => continue,
+        };
+
+        let mut push = |message: String| {
+            diagnostics.push(Diagnostic {
+                block_name: block.name.clone(),
+                global_index: block_refs.global_index as isize,
+                message: message,
+            });
+        };
+
+        let defined_names = global_blocks[..position]
+            .iter()
+            .filter(|b| b.ref_name.len() > 0)
+            .map(|b| b.ref_name.clone())
+            .collect::<Vec<String>>();
+
+        let mut all_names = block_refs.names.clone();
+        for ref_data in block_refs.ref_data.iter() {
+            if ref_data.ref_var {
+                all_names.push(ref_data.data.clone());
+            }
+        }
+        for condition in block_refs.conditions.iter() {
+            for ref_data in [&condition.left, &condition.right].iter() {
+                if ref_data.ref_var {
+                    all_names.push(ref_data.data.clone());
+                }
+            }
+
+            if !compatible_types(
+                &condition.left.rtype,
+                &condition.right.rtype,
+                &condition.condition_type,
+            ) {
+                push(format!(
+                    "Error: Cannot compare {} '{}' and {} '{}' with {}",
+                    condition.left.rtype,
+                    condition.left.data,
+                    condition.right.rtype,
+                    condition.right.data,
+                    condition.condition_type
+                ));
+            }
+        }
+
+        let mut all_patterns = block_refs.patterns.clone();
+        for condition in block_refs.conditions.iter() {
+            if condition.condition_type == ConditionType::MATCHES_REGEX {
+                all_patterns.push(condition.right.clone());
+            }
+        }
+        for pattern in all_patterns.iter() {
+            if pattern.ref_var {
+                continue;
+            }
+
+            if let Err(_) = Regex::new(&format!(r"{}", pattern.data)) {
+                push(format!("Error: Invalid regex pattern '{}'", pattern.data));
+            }
+        }
+
+        let mut all_groups = block_refs.groups.clone();
+        all_groups.push(
+            block_refs
+                .conditions
+                .iter()
+                .map(|c| (c.open, c.close))
+                .collect::<Vec<(u32, u32)>>(),
+        );
+        for groups in all_groups.iter() {
+            if let Err(e) = check_groups(groups) {
+                push(e);
+            }
+        }
+
+        for name in all_names.iter() {
+            if !defined_names.contains(name) {
+                push(format!("Error: Referencing undefined variable '{}'", name));
+            }
+        }
+
+        if let Some(ref_col) = &block_refs.collection {
+            match Collection::get(all_collections, project_id, ref_col) {
+                Ok(collection) => {
+                    for property in block_refs.properties.iter() {
+                        if !property_exists(&collection, property) {
+                            push(format!(
+                                "Error: Property '{}' does not exist in collection '{}'",
+                                property, ref_col
+                            ));
+                        }
+                    }
+
+                    for (property, equality_only) in block_refs.comparisons.iter() {
+                        if !equality_only && is_encrypted_property(&collection, property) {
+                            push(format!(
+                                "Error: Only equality filters are supported on encrypted property '{}'",
+                                property
+                            ));
+                        }
+                    }
+
+                    if block.name == "FETCH" {
+                        sources.insert(block.ref_name.clone(), collection.id.clone());
+                    }
+                }
+                Err(_) => {
+                    push(format!("Error: Collection '{}' does not exist", ref_col));
+                }
+            }
+        }
+
+        if let Some(source) = &block_refs.property_source {
+            if let Some(ref_col) = sources.get(source).cloned() {
+                if let Ok(collection) = Collection::get(all_collections, project_id, &ref_col) {
+                    for property in block_refs.properties.iter() {
+                        if !property_exists(&collection, property) {
+                            push(format!(
+                                "Error: Property '{}' does not exist in collection '{}'",
+                                property, ref_col
+                            ));
+                        }
+                    }
+
+                    for (property, equality_only) in block_refs.comparisons.iter() {
+                        if !equality_only && is_encrypted_property(&collection, property) {
+                            push(format!(
+                                "Error: Only equality filters are supported on encrypted property '{}'",
+                                property
+                            ));
+                        }
+                    }
+                }
+
+                sources.insert(block.ref_name.clone(), ref_col);
+            }
+        }
+
+        if block.name == "FUNCTION" {
+            let func = current_route.flow.functions[block.index].func.clone();
+            let (min, max) = FunctionList::params_range(func.id.clone());
+            if func.params.len() < min || func.params.len() > max {
+                push(format!(
+                    "Error: Invalid number of parameters for {} function (expected {} to {}, found {})",
+                    func.id,
+                    min,
+                    max,
+                    func.params.len()
+                ));
+            }
+        }
+
+        if block.name == "TRY" {
+            let try_block = current_route.flow.tries[block.index].clone();
+            for target in [try_block.end, try_block.fallback].iter() {
+                if let None = GlobalBlockOrder::get_position(&global_blocks, *target) {
+                    push(format!(
+                        "Error: Block at global_index {} does not exist",
+                        target
+                    ));
+                }
+            }
+        }
+
+        if block.name == "DELETE" && current_route.flow.deletes[block.index].targets.len() < 1 {
+            push(format!(
+                "Error: Delete on '{}' requires at least one target",
+                current_route.flow.deletes[block.index].ref_col
+            ));
+        }
+
+        if block.name == "CALL" {
+            let route_id = current_route.flow.calls[block.index].route_id.clone();
+            if route_id != current_route.route_id {
+                match RouteComponent::get(all_routes, &current_route.project_id, &route_id) {
+                    Ok(route) if RouteComponent::has_access_checks(&route) => push(format!(
+                        "Error: Route '{}' requires authentication or rate limiting and cannot be called",
+                        route_id
+                    )),
+                    Ok(_) => {}
+                    Err(_) => push(format!("Error: Route '{}' does not exist", route_id)),
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+fn collect_block_refs(
+    current_route: &RouteComponent,
+    block: &GlobalBlockOrder,
+) -> Option<BlockRefs> {
+    let flow = &current_route.flow;
+
+    let block_refs = match block.name.as_str() {
+        "FETCH" => {
+            let b = &flow.fetchers[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.collection = Some(b.ref_col.clone());
+            block_refs
+        }
+        "ASSIGN" => {
+            let b = &flow.assignments[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.conditions = b.conditions.clone();
+            for operation in b.operations.iter() {
+                block_refs.ref_data.push(operation.left.clone());
+                block_refs.ref_data.push(operation.right.clone());
+                if operation.operation_type == OperationType::MATCHES_REGEX {
+                    block_refs.patterns.push(operation.right.clone());
+                }
+            }
+            block_refs
+        }
+        "TEMPLATE" => {
+            let b = &flow.templates[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.ref_data = b.data.clone();
+            block_refs.conditions = b.conditions.clone();
+            block_refs
+        }
+        "CONDITION" => {
+            let b = &flow.conditions[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.conditions = b.conditions.clone();
+            if let Some(fail) = &b.fail {
+                for header in fail.headers.iter() {
+                    block_refs.ref_data.push(header.data.clone());
+                }
+            }
+            block_refs
+        }
+        "LOOP" => {
+            let b = &flow.loops[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            match &b.each {
+                Some(each) => block_refs.ref_data.push(each.clone()),
+                None => {
+                    block_refs.ref_data.push(b.min.clone());
+                    block_refs.ref_data.push(b.max.clone());
+                }
+            }
+            block_refs
+        }
+        "FILTER" => {
+            let b = &flow.filters[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.names.push(b.ref_var.clone());
+            for filter in b.filters.iter() {
+                block_refs.ref_data.push(filter.right.clone());
+                if filter.operation_type == OperationType::MATCHES_REGEX {
+                    block_refs.patterns.push(filter.right.clone());
+                }
+            }
+            block_refs.groups.push(
+                b.filters
+                    .iter()
+                    .map(|f| (f.open, f.close))
+                    .collect::<Vec<(u32, u32)>>(),
+            );
+            block_refs.properties.push(b.ref_property.clone());
+            block_refs.comparisons.push((
+                b.ref_property.clone(),
+                b.filters
+                    .iter()
+                    .all(|f| is_equality_operation(&f.operation_type)),
+            ));
+            block_refs.property_source = Some(b.ref_var.clone());
+            block_refs
+        }
+        "PROPERTY" => {
+            let b = &flow.properties[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.ref_data.push(b.property.data.clone());
+            block_refs
+        }
+        "FUNCTION" => {
+            let b = &flow.functions[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.ref_data = b.func.params.clone();
+            block_refs
+        }
+        "OBJECT" => {
+            let b = &flow.objects[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            for pair in b.pairs.iter() {
+                block_refs.ref_data.push(pair.data.clone());
+            }
+            block_refs
+        }
+        "UPDATE" => {
+            let b = &flow.updates[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.conditions = b.conditions.clone();
+            block_refs.collection = Some(b.ref_col.clone());
+            if b.ref_property.trim().len() > 0 {
+                block_refs.properties.push(b.ref_property.clone());
+            }
+            collect_targets(&mut block_refs, &b.targets);
+            if let Some(add) = &b.add {
+                block_refs.ref_data.push(add.clone());
+            }
+            if let Some(set) = &b.set {
+                block_refs.ref_data.push(set.clone());
+            }
+            if let Some(filter) = &b.filter {
+                block_refs.comparisons.push((
+                    b.ref_property.clone(),
+                    is_equality_operation(&filter.operation_type),
+                ));
+                block_refs.ref_data.push(filter.right.clone());
+                if filter.operation_type == OperationType::MATCHES_REGEX {
+                    block_refs.patterns.push(filter.right.clone());
+                }
+            }
+            block_refs
+        }
+        "CREATE" => {
+            let b = &flow.creates[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.conditions = b.conditions.clone();
+            block_refs.collection = Some(b.ref_col.clone());
+            block_refs.names.push(b.ref_object.clone());
+            block_refs
+        }
+        "RETURN" => {
+            let b = &flow.returns[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.conditions = b.conditions.clone();
+            for pair in b.pairs.iter() {
+                block_refs.ref_data.push(pair.data.clone());
+            }
+            for header in b.headers.iter() {
+                block_refs.ref_data.push(header.data.clone());
+            }
+            block_refs
+        }
+        "DELETE" => {
+            let b = &flow.deletes[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.conditions = b.conditions.clone();
+            block_refs.collection = Some(b.ref_col.clone());
+            collect_targets(&mut block_refs, &b.targets);
+            block_refs
+        }
+        "SORT" => {
+            let b = &flow.sorts[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            block_refs.names.push(b.ref_var.clone());
+            block_refs.properties.push(b.ref_property.clone());
+            for key in b.keys.iter() {
+                block_refs.properties.push(key.property.clone());
+            }
+            block_refs.property_source = Some(b.ref_var.clone());
+            block_refs
+        }
+        "TRY" => {
+            let b = &flow.tries[block.index];
+            BlockRefs::new(b.global_index)
+        }
+        "CALL" => {
+            let b = &flow.calls[block.index];
+            let mut block_refs = BlockRefs::new(b.global_index);
+            for pair in b.pairs.iter() {
+                block_refs.ref_data.push(pair.data.clone());
+            }
+            block_refs
+        }
+        _ => return None,
+    };
+
+    Some(block_refs)
+}
+
+fn collect_targets(block_refs: &mut BlockRefs, targets: &Vec<UpdateTarget>) {
+    for target in targets.iter() {
+        block_refs.properties.push(target.field.clone());
+        block_refs.comparisons.push((
+            target.field.clone(),
+            target.conditions.iter().all(|c| {
+                c.condition_type == ConditionType::EQUAL_TO
+                    || c.condition_type == ConditionType::NOT_EQUAL_TO
+            }),
+        ));
+        for condition in target.conditions.iter() {
+            block_refs.ref_data.push(condition.right.clone());
+            if condition.condition_type == ConditionType::MATCHES_REGEX {
+                block_refs.patterns.push(condition.right.clone());
+            }
+        }
+        block_refs.groups.push(
+            target
+                .conditions
+                .iter()
+                .map(|c| (c.open, c.close))
+                .collect::<Vec<(u32, u32)>>(),
+        );
+    }
+}
+
+fn check_groups(groups: &Vec<(u32, u32)>) -> Result<(), String> {
+    let mut depth: i64 = 0;
+
+    for (open, close) in groups.iter() {
+        depth += *open as i64;
+        if depth > MAX_GROUP_DEPTH as i64 {
+            return Err(format!(
+                "Error: Condition groups cannot be nested deeper than {}",
+                MAX_GROUP_DEPTH
+            ));
+        }
+
+        depth -= *close as i64;
+        if depth < 0 {
+            return Err(String::from("Error: Unbalanced condition groups"));
+        }
+    }
+
+    if depth != 0 {
+        return Err(String::from("Error: Unbalanced condition groups"));
+    }
+
+    Ok(())
+}
+
+fn is_equality_operation(operation_type: &OperationType) -> bool {
+    *operation_type == OperationType::EQUAL_TO || *operation_type == OperationType::NOT_EQUAL_TO
+}
+
+fn property_exists(collection: &Collection, property: &str) -> bool {
+    let broken_property = property.split(".").collect::<Vec<&str>>();
+
+    if broken_property.len() == 1 {
+        for structure in collection.structures.iter() {
+            if structure.id == broken_property[0] {
+                return true;
+            }
+        }
+    } else if broken_property.len() == 2 {
+        for custom_structure in collection.custom_structures.iter() {
+            if custom_structure.id == broken_property[0] {
+                for structure in custom_structure.structures.iter() {
+                    if structure.id == broken_property[1] {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+
+    false
+}
+
+fn compatible_types(
+    left: &BodyDataType,
+    right: &BodyDataType,
+    condition_type: &ConditionType,
+) -> bool {
+    if *left == BodyDataType::OTHER || *right == BodyDataType::OTHER {
+        return true;
+    }
+
+    let is_numeric = |t: &BodyDataType| *t == BodyDataType::INTEGER || *t == BodyDataType::FLOAT;
+    let is_scalar = |t: &BodyDataType| is_numeric(t) || *t == BodyDataType::STRING;
+
+    match condition_type {
+        ConditionType::EQUAL_TO | ConditionType::NOT_EQUAL_TO => {
+            left == right || (is_numeric(left) && is_numeric(right))
+        }
+        ConditionType::INCLUDES
+        | ConditionType::MATCHES_REGEX
+        | ConditionType::STARTS_WITH
+        | ConditionType::ENDS_WITH
+        | ConditionType::EQUAL_TO_IGNORE_CASE => {
+            *left == BodyDataType::STRING && *right == BodyDataType::STRING
+        }
+        ConditionType::IS_EMPTY | ConditionType::IS_NULL => true,
+        _ => {
+            (is_scalar(left) && is_scalar(right))
+                || (*left == BodyDataType::ARRAY && *right == BodyDataType::ARRAY)
+        }
+    }
+}
@@ -3,6 +3,7 @@ use serde_json::Map;
 
 use crate::components::routing::mod_route::RouteComponent;
 use crate::components::routing::submodules::sub_condition_action::ConditionAction;
+use crate::components::routing::submodules::sub_object_pair::ObjectPair;
 
 use super::convertors::convert_definition_to_value::definition_to_value;
 use super::global_block_order::GlobalBlockOrder;
@@ -11,13 +12,49 @@ use super::{definition_store::DefinitionStore, resolver::resolve_ref_data};
 
 use serde_json::value::Value;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResponseHeader {
+    pub key: String,
+    pub value: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum Signal {
     NONE,
     BREAK,
     CONTINUE,
-    FAIL(usize, String),
-    RETURN(Value),
+    FAIL(usize, String, Vec<ResponseHeader>),
+    RETURN(Value, usize, Vec<ResponseHeader>),
+}
+
+pub fn resolve_headers(
+    headers: &Vec<ObjectPair>,
+    all_definitions: &Vec<DefinitionStore>,
+    global_blocks: &Vec<GlobalBlockOrder>,
+    current_index: usize,
+) -> Result<Vec<ResponseHeader>, (usize, String)> {
+    let mut all_headers = Vec::<ResponseHeader>::new();
+
+    for header in headers.iter() {
+        let value =
+            match resolve_ref_data(&header.data, global_blocks, all_definitions, current_index) {
+                Ok(def) => definition_to_value(def),
+                Err(e) => {
+                    return Err(e);
+                }
+            };
+
+        all_headers.push(ResponseHeader {
+            key: header.id.clone(),
+            value: match value {
+                Value::String(s) => s,
+                Value::Null => String::new(),
+                _ => value.to_string(),
+            },
+        });
+    }
+
+    Ok(all_headers)
 }
 
 pub fn obtain_signal(
@@ -46,11 +83,24 @@ pub fn obtain_signal(
             match condition_block.action {
                 ConditionAction::FAIL => {
                     if let Some(f_obj) = condition_block.fail {
-                        return Ok(Signal::FAIL(f_obj.status as usize, f_obj.message));
+                        let headers = match resolve_headers(
+                            &f_obj.headers,
+                            all_definitions,
+                            global_blocks,
+                            current_index,
+                        ) {
+                            Ok(h) => h,
+                            Err(e) => {
+                                return Err(e);
+                            }
+                        };
+
+                        return Ok(Signal::FAIL(f_obj.status as usize, f_obj.message, headers));
                     } else {
                         return Ok(Signal::FAIL(
                             500,
                             String::from("Error: Unexpected problem occurred"),
+                            vec![],
                         ));
                     }
                 }
@@ -88,7 +138,23 @@ pub fn obtain_signal(
                 return_pairs.insert(pair.id, value);
             }
 
-            return Ok(Signal::RETURN(Value::Object(return_pairs)));
+            let headers = match resolve_headers(
+                &return_block.headers,
+                all_definitions,
+                global_blocks,
+                current_index,
+            ) {
+                Ok(h) => h,
+                Err(e) => {
+                    return Err(e);
+                }
+            };
+
+            return Ok(Signal::RETURN(
+                Value::Object(return_pairs),
+                return_block.status as usize,
+                headers,
+            ));
         }
     }
 
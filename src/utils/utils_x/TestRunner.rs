@@ -0,0 +1,105 @@
+use rocket::serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::components::routing::core::core_test_case::{json_contains, RouteTestCase};
+use crate::components::routing::mod_route::RouteComponent;
+use crate::middlewares::token::create_jwt;
+use crate::routes::x::x::{execute_route, match_route_path};
+use crate::utils::mapping::auto_fetch_all_mappings;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TestResult {
+    pub route_id: String,
+    pub test_id: String,
+    pub passed: bool,
+    pub expected_status: usize,
+    pub actual_status: usize,
+    pub message: String,
+}
+
+pub async fn run_test_case(
+    route: &RouteComponent,
+    project_id: &str,
+    test_case: &RouteTestCase,
+) -> TestResult {
+    let mut result = TestResult {
+        route_id: route.route_id.clone(),
+        test_id: test_case.id.clone(),
+        passed: false,
+        expected_status: test_case.status,
+        actual_status: 0,
+        message: String::new(),
+    };
+
+    let path = if test_case.path.len() > 0 {
+        test_case.path.clone()
+    } else {
+        route.route_path.clone()
+    };
+
+    let all_path_params = match match_route_path(&route.route_path, &path) {
+        Some((_, path_params)) => path_params,
+        None => {
+            result.message =
+                String::from("Error: path does not match the route_path of this Route");
+            return result;
+        }
+    };
+
+    let token = if test_case.token.len() > 0 {
+        match create_jwt(&auto_fetch_all_mappings(), test_case.token.clone()) {
+            Ok(t) => t,
+            Err(e) => {
+                result.message = format!("Error: Failed creating token -> {}", e);
+                return result;
+            }
+        }
+    } else {
+        String::new()
+    };
+
+    let response = execute_route(
+        route,
+        project_id,
+        test_case.body.to_string(),
+        test_case.params.clone(),
+        all_path_params,
+        token,
+        String::new(),
+        None,
+        true,
+    )
+    .await;
+
+    result.actual_status = response.status;
+
+    if response.status != test_case.status {
+        result.message = format!(
+            "Expected status {} but got {}",
+            test_case.status, response.status
+        );
+    } else if test_case.expected != Value::Null
+        && !json_contains(&response.body, &test_case.expected)
+    {
+        result.message = format!(
+            "Expected payload to contain {} but got {}",
+            test_case.expected.to_string(),
+            response.body.to_string()
+        );
+    } else {
+        result.passed = true;
+        result.message = String::from("Passed");
+    }
+
+    result
+}
+
+pub async fn run_route_tests(route: &RouteComponent, project_id: &str) -> Vec<TestResult> {
+    let mut results = Vec::<TestResult>::new();
+
+    for test_case in route.tests.iter() {
+        results.push(run_test_case(route, project_id, test_case).await);
+    }
+
+    results
+}
@@ -0,0 +1,278 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::components::{
+    data::{stringify_data, Data},
+    data_log::lock_data_log,
+    mapping::Mapping,
+};
+use crate::utils::data::auto_fetch_all_data;
+use crate::utils::field_encryption::{
+    auto_fetch_data_key, check_encrypted_comparison, has_encrypted_data, is_deterministic_field,
+    is_encrypted_field, mask_data, restore_encrypted_data, reveal_data,
+};
+use crate::utils::key_rotation::acquire_rotation_gate;
+use crate::utils::lock::LockGuard;
+use crate::utils::route_cache::invalidate_route_cache;
+use crate::utils::storage::{get_storage_backend, StorageLocation};
+
+#[derive(Debug, Clone)]
+pub struct StagedCollection {
+    pub project_id: String,
+    pub collection_id: String,
+    pub checksum: u64,
+    pub original: Vec<Data>,
+    pub data: Option<Vec<Data>>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Transaction {
+    pub collections: Vec<StagedCollection>,
+    pub dry_run: bool,
+    pub reveal: bool,
+}
+
+impl Transaction {
+    pub fn new(dry_run: bool, reveal: bool) -> Transaction {
+        Transaction {
+            collections: vec![],
+            dry_run: dry_run,
+            reveal: reveal,
+        }
+    }
+
+    pub fn fetch_data(
+        &mut self,
+        mappings: &Vec<Mapping>,
+        project_id: &str,
+        collection_id: &str,
+    ) -> Result<Vec<Data>, (usize, String)> {
+        for staged in self.collections.iter() {
+            if staged.project_id == project_id && staged.collection_id == collection_id {
+                if let Some(data) = &staged.data {
+                    return Ok(self.present(mappings, project_id, data.clone()));
+                }
+            }
+        }
+
+        let all_data = match auto_fetch_all_data(mappings, project_id, collection_id) {
+            Ok(d) => d,
+            _ => {
+                return Err((500, String::from("Error: Failed fetching data")));
+            }
+        };
+
+        if !self.is_tracked(project_id, collection_id) {
+            self.collections.push(StagedCollection {
+                project_id: project_id.to_string(),
+                collection_id: collection_id.to_string(),
+                checksum: Self::checksum(&all_data),
+                original: all_data.clone(),
+                data: None,
+            });
+        }
+
+        Ok(self.present(mappings, project_id, all_data))
+    }
+
+    pub fn stage_data(
+        &mut self,
+        mappings: &Vec<Mapping>,
+        project_id: &str,
+        collection_id: &str,
+        mut data: Vec<Data>,
+    ) -> Result<(), (usize, String)> {
+        if !self.is_tracked(project_id, collection_id) {
+            if let Err(e) = self.fetch_data(mappings, project_id, collection_id) {
+                return Err(e);
+            }
+        }
+
+        for staged in self.collections.iter_mut() {
+            if staged.project_id == project_id && staged.collection_id == collection_id {
+                let current_data = match &staged.data {
+                    Some(d) => d,
+                    None => &staged.original,
+                };
+
+                if has_encrypted_data(current_data) {
+                    restore_encrypted_data(
+                        &mut data,
+                        current_data,
+                        &auto_fetch_data_key(mappings, project_id),
+                    );
+                }
+
+                staged.data = Some(data);
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn encrypted_field(&self, data_id: &str, property: &str) -> Option<bool> {
+        let broken_property = property.split(".").collect::<Vec<&str>>();
+        let (custom_structure_id, structure_id) = match broken_property.len() {
+            1 => ("", broken_property[0]),
+            2 => (broken_property[0], broken_property[1]),
+            _ => return None,
+        };
+
+        for staged in self.collections.iter() {
+            let current_data = match &staged.data {
+                Some(d) => d,
+                None => &staged.original,
+            };
+
+            for data in current_data.iter().filter(|d| d.id == data_id) {
+                for pair in data.pairs.iter() {
+                    if pair.structure_id == structure_id
+                        && pair.custom_structure_id == custom_structure_id
+                        && is_encrypted_field(&pair.value)
+                    {
+                        return Some(is_deterministic_field(&pair.value));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn check_comparison(
+        &self,
+        data_id: &str,
+        property: &str,
+        equality_only: bool,
+    ) -> Result<(), (usize, String)> {
+        let deterministic = match self.encrypted_field(data_id, property) {
+            Some(d) => d,
+            None => return Ok(()),
+        };
+
+        if let Err(e) = self.require_reveal(property) {
+            return Err(e);
+        }
+
+        check_encrypted_comparison(property, deterministic, equality_only)
+    }
+
+    pub fn require_reveal(&self, property: &str) -> Result<(), (usize, String)> {
+        if !self.reveal {
+            return Err((
+                403,
+                format!(
+                    "Error: Encrypted property '{}' can only be compared in authenticated routes",
+                    property
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn commit(&self, mappings: &Vec<Mapping>) -> Result<(), (usize, String)> {
+        if self.dry_run {
+            return Ok(());
+        }
+
+        let mut all_staged = self
+            .collections
+            .iter()
+            .filter(|staged| staged.data.is_some())
+            .collect::<Vec<&StagedCollection>>();
+        if all_staged.len() < 1 {
+            return Ok(());
+        }
+
+        all_staged.sort_by(|a, b| {
+            (&a.project_id, &a.collection_id).cmp(&(&b.project_id, &b.collection_id))
+        });
+
+        let all_locations = all_staged
+            .iter()
+            .map(|staged| {
+                StorageLocation::collection(mappings, &staged.project_id, &staged.collection_id)
+            })
+            .collect::<Vec<StorageLocation>>();
+
+        let _gate = acquire_rotation_gate();
+        let _locks = all_locations
+            .iter()
+            .map(|location| lock_data_log(&location.path))
+            .collect::<Vec<LockGuard>>();
+
+        for staged in all_staged.iter() {
+            let current_data =
+                match auto_fetch_all_data(mappings, &staged.project_id, &staged.collection_id) {
+                    Ok(d) => d,
+                    _ => {
+                        return Err((500, String::from("Error: Failed fetching data")));
+                    }
+                };
+
+            if Self::checksum(&current_data) != staged.checksum {
+                return Err((
+                    409,
+                    format!(
+                        "Error: Conflicting write detected on collection '{}'",
+                        staged.collection_id
+                    ),
+                ));
+            }
+        }
+
+        let mut all_data = Vec::<(StorageLocation, Vec<Data>)>::new();
+        for (location, staged) in all_locations.into_iter().zip(all_staged.iter()) {
+            if let Some(data) = &staged.data {
+                all_data.push((location, data.clone()));
+            }
+        }
+
+        if let Err(e) = get_storage_backend(mappings).commit_data(&all_data) {
+            return Err((500, e));
+        }
+
+        for staged in all_staged.iter() {
+            invalidate_route_cache(&staged.project_id, &staged.collection_id);
+        }
+
+        Ok(())
+    }
+
+    fn is_tracked(&self, project_id: &str, collection_id: &str) -> bool {
+        for staged in self.collections.iter() {
+            if staged.project_id == project_id && staged.collection_id == collection_id {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn present(
+        &self,
+        mappings: &Vec<Mapping>,
+        project_id: &str,
+        mut all_data: Vec<Data>,
+    ) -> Vec<Data> {
+        if !has_encrypted_data(&all_data) {
+            return all_data;
+        }
+
+        if self.reveal {
+            reveal_data(&mut all_data, &auto_fetch_data_key(mappings, project_id));
+        } else {
+            mask_data(&mut all_data);
+        }
+
+        all_data
+    }
+
+    fn checksum(data: &Vec<Data>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        stringify_data(data).hash(&mut hasher);
+        hasher.finish()
+    }
+}
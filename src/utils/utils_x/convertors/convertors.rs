@@ -12,3 +12,6 @@ pub mod convert_rawpair_to_data;
 
 #[path = "data_to_rawpair.rs"]
 pub mod convert_data_to_rawpair;
+
+#[path = "value_to_definition.rs"]
+pub mod convert_value_to_definition;
@@ -8,7 +8,12 @@ use crate::{
         structure::Structure,
     },
     utils::{
-        data::auto_fetch_all_data, mapping::auto_fetch_all_mappings, validate_stype::validate_stype,
+        field_encryption::{
+            auto_fetch_data_key, decrypt_field, encrypt_field, get_field_encryption_mode,
+            has_encrypted_structures, FieldEncryptionMode,
+        },
+        mapping::auto_fetch_all_mappings,
+        validate_stype::validate_stype,
     },
 };
 use regex::Regex;
@@ -24,14 +29,25 @@ pub fn rawpair_to_data(
 
     let mut all_pairs = Vec::<DataPair>::new();
 
+    let (data_key, encryption_mode) = if has_encrypted_structures(collection) {
+        let mappings = auto_fetch_all_mappings();
+        (
+            auto_fetch_data_key(&mappings, &collection.project_id),
+            get_field_encryption_mode(&mappings),
+        )
+    } else {
+        (String::new(), FieldEncryptionMode::RANDOM)
+    };
+
     if let Err(e) = process_structures(
         &mut all_pairs,
         &structure_pairs,
         &collection.structures,
         "",
-        &collection.project_id,
-        &collection.id,
+        all_data,
         updating,
+        &data_key,
+        &encryption_mode,
     ) {
         return Err(e);
     }
@@ -55,9 +71,10 @@ pub fn rawpair_to_data(
             &target_custom_structure_pair.structures,
             &custom_structure.structures,
             &custom_structure_id,
-            &collection.project_id,
-            &collection.id,
+            all_data,
             updating,
+            &data_key,
+            &encryption_mode,
         ) {
             return Err(e);
         }
@@ -102,9 +119,10 @@ fn process_structures(
     structure_pairs: &Vec<StructurePair>,
     structures: &Vec<Structure>,
     custom_structure_id: &str,
-    project_id: &str,
-    collection_id: &str,
+    existing_data: &Vec<Data>,
     updating: bool,
+    data_key: &str,
+    encryption_mode: &FieldEncryptionMode,
 ) -> Result<(), (usize, String)> {
     for structure in structures {
         let structure_id = structure.id.clone();
@@ -134,6 +152,14 @@ fn process_structures(
             used_default = true;
         }
 
+        let mut encrypted_value: Option<String> = None;
+        if structure.encrypted {
+            if let Some(v) = decrypt_field(&value, data_key) {
+                encrypted_value = Some(value.clone());
+                value = v;
+            }
+        }
+
         let mut broken_data: Vec<&str> = vec![&value];
         if array {
             broken_data = value.split(",").collect::<Vec<&str>>();
@@ -199,18 +225,17 @@ fn process_structures(
 
         let mut found = false;
         let mut count = 0;
-        let all_mappings = auto_fetch_all_mappings();
-        let all_data = match auto_fetch_all_data(&all_mappings, project_id, collection_id) {
-            Ok(data) => data,
-            Err(_) => {
-                return Err((500, format!("Error: Failed fetching data")));
-            }
-        };
 
         if unique && final_data.len() > 0 && !used_default {
-            for d in all_data {
+            for d in existing_data.iter() {
                 for pair in d.pairs.iter() {
-                    if pair.structure_id == structure_id && pair.value == final_data {
+                    let pair_value = if structure.encrypted && pair.structure_id == structure_id {
+                        decrypt_field(&pair.value, data_key).unwrap_or(pair.value.clone())
+                    } else {
+                        pair.value.clone()
+                    };
+
+                    if pair.structure_id == structure_id && pair_value == final_data {
                         if count > 0 || !updating {
                             found = true;
                             break;
@@ -229,11 +254,20 @@ fn process_structures(
             ));
         }
 
+        let final_value = if structure.encrypted && final_data.len() > 0 {
+            match encrypted_value {
+                Some(v) => v,
+                None => encrypt_field(&final_data, data_key, encryption_mode),
+            }
+        } else {
+            final_data
+        };
+
         all_pairs.push(DataPair {
             id: pair_id,
             structure_id: structure.id.to_string(),
             custom_structure_id: custom_structure_id.to_string(),
-            value: final_data,
+            value: final_value,
             dtype: processed_dtype,
         });
     }
@@ -0,0 +1,92 @@
+use serde_json::value::Value;
+
+use crate::{
+    components::raw_pair::{CustomStructurePair, RawPair, StructurePair},
+    utils::x::definition_store::DefinitionData,
+};
+
+pub fn value_to_definition(value: Value) -> DefinitionData {
+    match value {
+        Value::Null => DefinitionData::NULL,
+        Value::Bool(b) => DefinitionData::BOOLEAN(b),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                DefinitionData::INTEGER(i as isize)
+            } else {
+                DefinitionData::FLOAT(n.as_f64().unwrap_or(0.0))
+            }
+        }
+        Value::String(s) => DefinitionData::STRING(s),
+        Value::Array(a) => {
+            let mut all_definitions = Vec::<DefinitionData>::new();
+            for v in a {
+                all_definitions.push(value_to_definition(v));
+            }
+            DefinitionData::ARRAY(all_definitions)
+        }
+        Value::Object(o) => {
+            let mut raw_pair = RawPair::default();
+
+            for (key, v) in o {
+                if key == "data_id" {
+                    if let Value::String(s) = v {
+                        raw_pair.data_id = s;
+                    }
+                } else if key == "published" {
+                    if let Value::Bool(b) = v {
+                        raw_pair.published = b;
+                    }
+                } else if let Value::Object(inner) = v {
+                    let mut custom_structure = CustomStructurePair {
+                        id: key,
+                        structures: vec![],
+                    };
+
+                    for (inner_key, inner_value) in inner {
+                        custom_structure
+                            .structures
+                            .push(value_to_structure(inner_key, inner_value));
+                    }
+
+                    raw_pair.custom_structures.push(custom_structure);
+                } else {
+                    raw_pair.structures.push(value_to_structure(key, v));
+                }
+            }
+
+            DefinitionData::DATA(raw_pair)
+        }
+    }
+}
+
+fn value_to_structure(id: String, value: Value) -> StructurePair {
+    let (value, rtype) = match value {
+        Value::Bool(b) => (b.to_string(), "BOOLEAN"),
+        Value::Number(n) => {
+            if n.is_i64() {
+                (n.to_string(), "INTEGER")
+            } else {
+                (n.to_string(), "FLOAT")
+            }
+        }
+        Value::String(s) => (s, "STRING"),
+        Value::Array(a) => {
+            let mut all_values = Vec::<String>::new();
+            for v in a {
+                all_values.push(match v {
+                    Value::String(s) => s,
+                    _ => v.to_string(),
+                });
+            }
+            (all_values.join(","), "ARRAY")
+        }
+        Value::Null => (String::new(), "STRING"),
+        Value::Object(_) => (value.to_string(), "OTHER"),
+    };
+
+    StructurePair {
+        id: id,
+        value: value,
+        rtype: rtype.to_string(),
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{components::datapair::DataPair, utils::x::definition_store::DefinitionData};
+
+pub fn define_auth(claim: &str, auth_claims: &Vec<DataPair>) -> DefinitionData {
+    for pair in auth_claims.iter() {
+        if pair.structure_id != claim {
+            continue;
+        }
+
+        return match pair.dtype.to_uppercase().as_str() {
+            "INTEGER" => match pair.value.trim().parse::<isize>() {
+                Ok(value) => DefinitionData::INTEGER(value),
+                Err(_) => DefinitionData::STRING(pair.value.clone()),
+            },
+            "FLOAT" => match pair.value.trim().parse::<f64>() {
+                Ok(value) => DefinitionData::FLOAT(value),
+                Err(_) => DefinitionData::STRING(pair.value.clone()),
+            },
+            "PASSWORD" => DefinitionData::NULL,
+            "BOOLEAN" => DefinitionData::BOOLEAN(pair.value.trim().to_lowercase() == "true"),
+            _ => DefinitionData::STRING(pair.value.clone()),
+        };
+    }
+
+    DefinitionData::NULL
+}
@@ -0,0 +1,120 @@
+use serde_json::{Map, Value};
+
+use crate::{
+    components::{
+        datapair::DataPair,
+        routing::{blocks::call_block::CallBlock, route_component::RouteComponent},
+    },
+    routes::x::x::{validate_body_schema, LocalParamData},
+    utils::{
+        route::auto_fetch_all_routes,
+        x::{
+            convertors::{
+                convert_definition_to_value::definition_to_value,
+                convert_value_to_definition::value_to_definition,
+            },
+            definition_store::{DefinitionData, DefinitionStore},
+            flow_processor::execute_flow,
+            global_block_order::GlobalBlockOrder,
+            resolver::resolve_ref_data,
+            signal_processor::Signal,
+            transaction::Transaction,
+        },
+    },
+};
+
+pub const MAX_CALL_DEPTH: usize = 8;
+
+pub fn define_call(
+    call_block: CallBlock,
+    global_blocks: &Vec<GlobalBlockOrder>,
+    all_definitions: &mut Vec<DefinitionStore>,
+    current_index: usize,
+    project_id: &str,
+    caller_route_id: &str,
+    auth_claims: &Vec<DataPair>,
+    transaction: &mut Transaction,
+    call_depth: usize,
+) -> Result<DefinitionData, (usize, String)> {
+    if call_depth >= MAX_CALL_DEPTH {
+        return Err((
+            500,
+            format!(
+                "Error: Maximum call depth of {} exceeded while calling '{}'",
+                MAX_CALL_DEPTH, call_block.route_id
+            ),
+        ));
+    }
+
+    let all_routes = match auto_fetch_all_routes(project_id) {
+        Ok(r) => r,
+        Err(_) => return Err((500, String::from("Error: Failed fetching routes"))),
+    };
+
+    let mut target_route = None;
+    for route in all_routes {
+        if route.route_id == call_block.route_id {
+            target_route = Some(route);
+            break;
+        }
+    }
+
+    let target_route = match target_route {
+        Some(r) => r,
+        None => {
+            return Err((
+                404,
+                format!("Error: Route '{}' not found", call_block.route_id),
+            ));
+        }
+    };
+
+    if target_route.route_id != caller_route_id && RouteComponent::has_access_checks(&target_route)
+    {
+        return Err((
+            403,
+            format!(
+                "Error: Route '{}' requires authentication or rate limiting and cannot be called",
+                call_block.route_id
+            ),
+        ));
+    }
+
+    let mut body = Map::new();
+    for pair in call_block.pairs.iter() {
+        let current_data =
+            match resolve_ref_data(&pair.data, global_blocks, all_definitions, current_index) {
+                Ok(d) => d,
+                Err(e) => return Err(e),
+            };
+
+        body.insert(pair.id.clone(), definition_to_value(current_data));
+    }
+
+    for bdata in target_route.body.iter() {
+        if let Err(e) = validate_body_schema(
+            bdata,
+            body.get(&bdata.id).cloned().unwrap_or(Value::Null),
+            &format!("/{}", bdata.id),
+        ) {
+            return Err(e);
+        }
+    }
+
+    match execute_flow(
+        &target_route,
+        project_id,
+        &Value::Object(body),
+        &Vec::<LocalParamData>::new(),
+        &Vec::<LocalParamData>::new(),
+        auth_claims,
+        transaction,
+        &mut None,
+        call_depth + 1,
+    ) {
+        Ok(Signal::RETURN(r, _, _)) => Ok(value_to_definition(r)),
+        Ok(Signal::FAIL(status, message, _)) => Err((status, message)),
+        Ok(_) => Ok(DefinitionData::NULL),
+        Err(e) => Err(e),
+    }
+}
@@ -5,12 +5,9 @@ use crate::{
         definition_store::{DefinitionData, DefinitionStore},
         global_block_order::GlobalBlockOrder,
         resolver::{resolve_conditions, resolve_raw_data},
+        transaction::Transaction,
     },
-    utils::{
-        collection::auto_fetch_all_collections,
-        data::{auto_fetch_all_data, auto_save_all_data},
-        mapping::auto_fetch_all_mappings,
-    },
+    utils::{collection::auto_fetch_all_collections, mapping::auto_fetch_all_mappings},
 };
 
 pub fn define_create(
@@ -19,6 +16,7 @@ pub fn define_create(
     all_definitions: &mut Vec<DefinitionStore>,
     current_index: usize,
     project_id: &str,
+    transaction: &mut Transaction,
 ) -> Result<DefinitionData, (usize, String)> {
     let res_condition = match resolve_conditions(
         &create_block.conditions,
@@ -39,10 +37,10 @@ pub fn define_create(
     let return_definition: DefinitionData;
 
     let mappings = auto_fetch_all_mappings();
-    let all_data = match auto_fetch_all_data(&mappings, project_id, &create_block.ref_col) {
+    let all_data = match transaction.fetch_data(&mappings, project_id, &create_block.ref_col) {
         Ok(d) => d,
-        _ => {
-            return Err((500, String::from("Error: Failed fetching data")));
+        Err(e) => {
+            return Err(e);
         }
     };
 
@@ -75,7 +73,7 @@ pub fn define_create(
             DefinitionData::DATA(d) => {
                 return_definition = DefinitionData::DATA(d.clone());
 
-                match rawpair_to_data(&mut current_data, &collection, &d, true) {
+                match rawpair_to_data(&mut current_data, &collection, &d, false) {
                     Err(e) => {
                         return Err(e);
                     }
@@ -98,11 +96,10 @@ pub fn define_create(
     }
 
     if create_block.save {
-        match auto_save_all_data(&mappings, &project_id, &create_block.ref_col, &current_data) {
-            Err(e) => {
-                return Err((500, e));
-            }
-            _ => {}
+        if let Err(e) =
+            transaction.stage_data(&mappings, project_id, &create_block.ref_col, current_data)
+        {
+            return Err(e);
         }
     }
 
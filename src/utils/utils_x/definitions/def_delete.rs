@@ -0,0 +1,259 @@
+use crate::{
+    components::{
+        collection::Collection,
+        data::Data,
+        raw_pair::RawPair,
+        routing::{
+            blocks::delete_block::DeleteBlock,
+            submodules::{
+                sub_body_data_type::BodyDataType, sub_condition::Condition,
+                sub_condition_type::ConditionType, sub_next_condition_type::NextConditionType,
+                sub_ref_data::RefData,
+            },
+        },
+    },
+    utils::x::{
+        convertors::convert_data_to_rawpair::data_to_rawpair,
+        definition_store::{DefinitionData, DefinitionStore},
+        global_block_order::GlobalBlockOrder,
+        resolver::resolve_conditions,
+        transaction::Transaction,
+    },
+    utils::{collection::auto_fetch_all_collections, mapping::auto_fetch_all_mappings},
+};
+
+pub fn define_delete(
+    delete_block: DeleteBlock,
+    global_blocks: &Vec<GlobalBlockOrder>,
+    all_definitions: &mut Vec<DefinitionStore>,
+    current_index: usize,
+    project_id: &str,
+    transaction: &mut Transaction,
+) -> Result<DefinitionData, (usize, String)> {
+    if delete_block.targets.len() < 1 {
+        return Err((
+            400,
+            format!(
+                "Error: Delete on '{}' requires at least one target",
+                delete_block.ref_col
+            ),
+        ));
+    }
+
+    let res_condition = match resolve_conditions(
+        &delete_block.conditions,
+        global_blocks,
+        all_definitions,
+        current_index,
+    ) {
+        Ok(c) => c,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+
+    if !res_condition {
+        return Ok(DefinitionData::NULL);
+    }
+
+    let mappings = auto_fetch_all_mappings();
+    let mut all_data = match transaction.fetch_data(&mappings, &project_id, &delete_block.ref_col) {
+        Ok(d) => d,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+
+    let all_collections = match auto_fetch_all_collections(&mappings) {
+        Ok(u) => u,
+        _ => {
+            return Err((500, String::from("Error: Failed fetching collections")));
+        }
+    };
+
+    let collection = match Collection::get(&all_collections, project_id, &delete_block.ref_col) {
+        Ok(p) => p,
+        Err(_) => {
+            return Err((
+                404,
+                String::from("Error: No Collection with this collection_id found"),
+            ));
+        }
+    };
+
+    let current_data = Data::get_all(&all_data, project_id, &delete_block.ref_col);
+    let mut current_raw_pairs = Vec::<RawPair>::new();
+
+    for data in current_data {
+        match data_to_rawpair(&data, &collection) {
+            Ok(rp) => {
+                current_raw_pairs.push(rp);
+            }
+            Err(e) => {
+                return Err(e);
+            }
+        };
+    }
+
+    let mut deleted_data = Vec::<RawPair>::new();
+    let mut current_definition: DefinitionData;
+
+    for raw_pair in current_raw_pairs.iter() {
+        let mut is_raw_pair_valid = false;
+
+        let mut current_err: (usize, String) = (0, String::new());
+
+        for target in delete_block.targets.iter() {
+            let mut current_value = String::new();
+            let mut current_rtype = BodyDataType::STRING;
+
+            let broken_property = target.field.split(".").collect::<Vec<&str>>();
+            if broken_property.len() > 2 || broken_property[0].trim().len() < 1 {
+                return Err((
+                    500,
+                    format!("Error: Invalid property '{}' in delete", target.field),
+                ));
+            }
+
+            if broken_property.len() < 2 {
+                for structure in raw_pair.structures.iter() {
+                    if structure.id == broken_property[0] {
+                        current_value = structure.value.clone();
+                        current_rtype = BodyDataType::from(&structure.rtype);
+                        break;
+                    }
+                }
+            } else {
+                for custom_structure in raw_pair.custom_structures.iter() {
+                    if custom_structure.id == broken_property[0] {
+                        for structure in custom_structure.structures.iter() {
+                            if structure.id == broken_property[1] {
+                                current_value = structure.value.clone();
+                                current_rtype = BodyDataType::from(&structure.rtype);
+                                break;
+                            }
+                        }
+                    }
+
+                    if current_value.len() > 0 {
+                        break;
+                    }
+                }
+            }
+
+            if let Err(e) = transaction.check_comparison(
+                &raw_pair.data_id,
+                &target.field,
+                target.conditions.iter().all(|condition| {
+                    condition.condition_type == ConditionType::EQUAL_TO
+                        || condition.condition_type == ConditionType::NOT_EQUAL_TO
+                }),
+            ) {
+                return Err(e);
+            }
+
+            match RefData::create(
+                false,
+                &BodyDataType::to(current_rtype.clone()),
+                &current_value,
+            ) {
+                Ok(left) => {
+                    let mut current_conditions = Vec::<Condition>::new();
+                    for condition in target.conditions.iter() {
+                        Condition::create(
+                            &mut current_conditions,
+                            left.clone(),
+                            condition.right.clone(),
+                            &ConditionType::to(condition.condition_type.clone()),
+                            condition.not,
+                            &NextConditionType::to(condition.next.clone()),
+                        );
+                        if let Some(last_condition) = current_conditions.last_mut() {
+                            if let Err(e) = Condition::set_group(
+                                last_condition,
+                                condition.open,
+                                condition.close,
+                            ) {
+                                return Err(e);
+                            }
+                        }
+                    }
+
+                    match resolve_conditions(
+                        &current_conditions,
+                        global_blocks,
+                        all_definitions,
+                        current_index,
+                    ) {
+                        Ok(b) => {
+                            current_definition = DefinitionData::BOOLEAN(b);
+                        }
+                        Err(e) => {
+                            current_definition = DefinitionData::UNDEFINED;
+                            current_err = e;
+                        }
+                    }
+                }
+                Err(e) => {
+                    current_definition = DefinitionData::UNDEFINED;
+                    current_err = e;
+                }
+            }
+
+            if current_definition == DefinitionData::UNDEFINED && current_err.0 > 0 {
+                return Err((
+                    current_err.0,
+                    format!(
+                        "Error: Failed processing Delete: '{}'",
+                        current_err
+                            .1
+                            .splitn(2, "Error: ")
+                            .nth(1)
+                            .unwrap_or(&current_err.1)
+                    ),
+                ));
+            } else {
+                match current_definition {
+                    DefinitionData::BOOLEAN(b) => {
+                        is_raw_pair_valid = b;
+                    }
+                    _ => {
+                        return Err((
+                            500,
+                            format!("Error: Invalid data type for last target condition in Delete"),
+                        ));
+                    }
+                }
+            }
+
+            if !is_raw_pair_valid {
+                break;
+            }
+        }
+
+        if is_raw_pair_valid {
+            deleted_data.push(raw_pair.clone());
+        }
+    }
+
+    if delete_block.save && deleted_data.len() > 0 {
+        for raw_pair in deleted_data.iter() {
+            if let Err(e) = Data::delete(&mut all_data, &raw_pair.data_id) {
+                return Err(e);
+            }
+        }
+
+        if let Err(e) =
+            transaction.stage_data(&mappings, &project_id, &delete_block.ref_col, all_data)
+        {
+            return Err(e);
+        }
+    }
+
+    let mut final_data = Vec::<DefinitionData>::new();
+    for raw_pair in deleted_data.iter() {
+        final_data.push(DefinitionData::DATA(raw_pair.clone()));
+    }
+
+    Ok(DefinitionData::ARRAY(final_data))
+}
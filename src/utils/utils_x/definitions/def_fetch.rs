@@ -2,22 +2,21 @@ use crate::{
     components::{collection::Collection, data::Data, routing::blocks::fetch_block::FetchBlock},
     utils::x::{
         convertors::convert_data_to_rawpair::data_to_rawpair, definition_store::DefinitionData,
+        transaction::Transaction,
     },
-    utils::{
-        collection::auto_fetch_all_collections, data::auto_fetch_all_data,
-        mapping::auto_fetch_all_mappings,
-    },
+    utils::{collection::auto_fetch_all_collections, mapping::auto_fetch_all_mappings},
 };
 
 pub fn define_fetch(
     fetch_block: FetchBlock,
     project_id: &str,
+    transaction: &mut Transaction,
 ) -> Result<DefinitionData, (usize, String)> {
     let mappings = auto_fetch_all_mappings();
-    let all_data = match auto_fetch_all_data(&mappings, &project_id, &fetch_block.ref_col) {
+    let all_data = match transaction.fetch_data(&mappings, &project_id, &fetch_block.ref_col) {
         Ok(d) => d,
-        _ => {
-            return Err((500, String::from("Error: Failed fetching data")));
+        Err(e) => {
+            return Err(e);
         }
     };
 
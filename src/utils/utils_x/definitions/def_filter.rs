@@ -12,7 +12,15 @@ use crate::{
     utils::x::{
         definition_store::{DefinitionData, DefinitionStore},
         global_block_order::GlobalBlockOrder,
-        resolver::{resolve_operations, resolve_raw_data},
+        resolver::{evaluate_terms, resolve_operations, resolve_raw_data, ConditionTerm},
+        transaction::Transaction,
+    },
+    utils::{
+        field_encryption::{
+            auto_fetch_data_key, check_encrypted_comparison, decrypt_field, is_deterministic_field,
+            is_encrypted_field,
+        },
+        mapping::auto_fetch_all_mappings,
     },
 };
 
@@ -21,6 +29,8 @@ pub fn define_filter(
     global_blocks: &Vec<GlobalBlockOrder>,
     all_definitions: &mut Vec<DefinitionStore>,
     current_index: usize,
+    project_id: &str,
+    transaction: &Transaction,
 ) -> Result<DefinitionData, (usize, String)> {
     let current_ref_data = match resolve_raw_data(
         &filter_block.ref_var,
@@ -57,6 +67,7 @@ pub fn define_filter(
 
     let mut final_current_data = Vec::<RawPair>::new();
     let mut current_definition: DefinitionData;
+    let mut data_key = String::new();
 
     for raw_pair_data in current_data.iter() {
         let raw_pair = match raw_pair_data {
@@ -66,10 +77,6 @@ pub fn define_filter(
             }
         };
 
-        if filter_block.filters.len() < 1 {
-            final_current_data.push(raw_pair.clone());
-        }
-
         let mut current_value = String::new();
         if broken_property.len() < 2 {
             for structure in raw_pair.structures.iter() {
@@ -99,11 +106,56 @@ pub fn define_filter(
             continue;
         }
 
-        current_definition = DefinitionData::STRING(current_value);
+        let equality_only = filter_block.filters.iter().all(|filter| {
+            filter.operation_type == OperationType::EQUAL_TO
+                || filter.operation_type == OperationType::NOT_EQUAL_TO
+        });
+
+        let encrypted_check = if is_encrypted_field(&current_value) {
+            if let Err(e) = transaction.require_reveal(&filter_block.ref_property) {
+                return Err(e);
+            }
+
+            check_encrypted_comparison(
+                &filter_block.ref_property,
+                is_deterministic_field(&current_value),
+                equality_only,
+            )
+        } else {
+            transaction.check_comparison(
+                &raw_pair.data_id,
+                &filter_block.ref_property,
+                equality_only,
+            )
+        };
+
+        if let Err(e) = encrypted_check {
+            return Err(e);
+        }
+
+        if is_encrypted_field(&current_value) {
+            if data_key.len() < 1 {
+                data_key = auto_fetch_data_key(&auto_fetch_all_mappings(), project_id);
+            }
+
+            current_value = match decrypt_field(&current_value, &data_key) {
+                Some(v) => v,
+                None => continue,
+            };
+        }
+
+        current_definition = DefinitionData::STRING(current_value.clone());
         let mut current_operations: Vec<Operation>;
         let mut current_err: (usize, String) = (0, String::new());
 
-        for filter in filter_block.filters.iter() {
+        let mut all_terms = Vec::<ConditionTerm>::new();
+        let mut term_open: Option<u32> = None;
+
+        for (i, filter) in filter_block.filters.iter().enumerate() {
+            if let None = term_open {
+                term_open = Some(filter.open);
+            }
+
             let mut rtype = BodyDataType::to(filter.right.rtype.clone());
             if rtype == String::from("OTHER") {
                 rtype = String::from("STRING");
@@ -160,6 +212,28 @@ pub fn define_filter(
                     break;
                 }
             }
+
+            if filter.next != NextConditionType::NONE || i == filter_block.filters.len() - 1 {
+                match current_definition {
+                    DefinitionData::BOOLEAN(b) => {
+                        all_terms.push(ConditionTerm {
+                            open: term_open.unwrap_or(0),
+                            value: b,
+                            close: filter.close,
+                            next: filter.next.clone(),
+                        });
+                    }
+                    _ => {
+                        return Err((
+                            500,
+                            format!("Error: Invalid data type for last operation in Filter"),
+                        ));
+                    }
+                }
+
+                current_definition = DefinitionData::STRING(current_value.clone());
+                term_open = None;
+            }
         }
 
         if current_definition == DefinitionData::UNDEFINED && current_err.0 > 0 {
@@ -171,18 +245,10 @@ pub fn define_filter(
                 ),
             ));
         } else {
-            match current_definition {
-                DefinitionData::BOOLEAN(b) => {
-                    if b {
-                        final_current_data.push(raw_pair.clone());
-                    }
-                }
-                _ => {
-                    return Err((
-                        500,
-                        format!("Error: Invalid data type for last operation in Filter"),
-                    ));
-                }
+            match evaluate_terms(&all_terms) {
+                Ok(true) => final_current_data.push(raw_pair.clone()),
+                Ok(false) => {}
+                Err(e) => return Err(e),
             }
         }
     }
@@ -1,4 +1,7 @@
+use argon2::{self, Config};
 use chrono::prelude::*;
+use chrono::Duration;
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 use crate::{
@@ -7,12 +10,19 @@ use crate::{
     },
     middlewares::paginate::paginate,
     utils::x::{
+        convertors::{
+            convert_definition_to_string::definition_to_string,
+            convert_definition_to_value::definition_to_value,
+            convert_value_to_definition::value_to_definition,
+        },
         definition_store::{DefinitionData, DefinitionStore},
         global_block_order::GlobalBlockOrder,
         resolver::resolve_ref_data,
     },
 };
 
+const MAX_DURATION_SECONDS: i64 = i64::MAX / 1000;
+
 pub fn define_function(
     function_block: FunctionBlock,
     global_blocks: &Vec<GlobalBlockOrder>,
@@ -131,7 +141,636 @@ pub fn define_function(
             let paginated_data = paginate(data_to_paginate, limit, offset);
             final_data = DefinitionData::ARRAY(paginated_data);
         }
+        FunctionList::UPPERCASE | FunctionList::LOWERCASE | FunctionList::TRIM => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 1) {
+                return Err(e);
+            }
+
+            let text = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match string_param(d, "text", &func) {
+                    Ok(t) => t,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            final_data = DefinitionData::STRING(match func {
+                FunctionList::UPPERCASE => text.to_uppercase(),
+                FunctionList::LOWERCASE => text.to_lowercase(),
+                _ => text.trim().to_string(),
+            });
+        }
+        FunctionList::SPLIT => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 2) {
+                return Err(e);
+            }
+
+            let text = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match string_param(d, "text", &func) {
+                    Ok(t) => t,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let delimiter = match resolve_param(
+                &function_block,
+                1,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match string_param(d, "delimiter", &func) {
+                    Ok(t) => t,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            if delimiter.len() < 1 {
+                return Err(invalid_param("delimiter", &func));
+            }
+
+            let mut all_parts = Vec::<DefinitionData>::new();
+            for part in text.split(&delimiter) {
+                all_parts.push(DefinitionData::STRING(part.to_string()));
+            }
+            final_data = DefinitionData::ARRAY(all_parts);
+        }
+        FunctionList::JOIN => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 1) {
+                return Err(e);
+            }
+
+            let items = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(DefinitionData::ARRAY(a)) => a,
+                Ok(_) => return Err(invalid_param("data", &func)),
+                Err(e) => return Err(e),
+            };
+
+            let delimiter = if function_block.func.params.len() > 1 {
+                match resolve_param(
+                    &function_block,
+                    1,
+                    global_blocks,
+                    all_definitions,
+                    current_index,
+                ) {
+                    Ok(d) => match string_param(d, "delimiter", &func) {
+                        Ok(t) => t,
+                        Err(e) => return Err(e),
+                    },
+                    Err(e) => return Err(e),
+                }
+            } else {
+                String::from(",")
+            };
+
+            let mut all_parts = Vec::<String>::new();
+            for item in items {
+                match definition_to_string(item, "", "JOIN", false) {
+                    Ok(t) => all_parts.push(t),
+                    Err(e) => return Err(e),
+                }
+            }
+            final_data = DefinitionData::STRING(all_parts.join(&delimiter));
+        }
+        FunctionList::REPLACE => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 3) {
+                return Err(e);
+            }
+
+            let mut all_texts = Vec::<String>::new();
+            for (i, param) in ["text", "from", "to"].iter().enumerate() {
+                match resolve_param(
+                    &function_block,
+                    i,
+                    global_blocks,
+                    all_definitions,
+                    current_index,
+                ) {
+                    Ok(d) => match string_param(d, param, &func) {
+                        Ok(t) => all_texts.push(t),
+                        Err(e) => return Err(e),
+                    },
+                    Err(e) => return Err(e),
+                }
+            }
+
+            if all_texts[1].len() < 1 {
+                return Err(invalid_param("from", &func));
+            }
+
+            final_data = DefinitionData::STRING(all_texts[0].replace(&all_texts[1], &all_texts[2]));
+        }
+        FunctionList::SUBSTRING => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 2) {
+                return Err(e);
+            }
+
+            let text = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match string_param(d, "text", &func) {
+                    Ok(t) => t,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let start = match resolve_param(
+                &function_block,
+                1,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match integer_param(d, "start", &func) {
+                    Ok(i) if i >= 0 => i as usize,
+                    Ok(_) => return Err(invalid_param("start", &func)),
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let length = if function_block.func.params.len() > 2 {
+                match resolve_param(
+                    &function_block,
+                    2,
+                    global_blocks,
+                    all_definitions,
+                    current_index,
+                ) {
+                    Ok(d) => match integer_param(d, "length", &func) {
+                        Ok(i) if i >= 0 => i as usize,
+                        Ok(_) => return Err(invalid_param("length", &func)),
+                        Err(e) => return Err(e),
+                    },
+                    Err(e) => return Err(e),
+                }
+            } else {
+                text.chars().count()
+            };
+
+            final_data = DefinitionData::STRING(text.chars().skip(start).take(length).collect());
+        }
+        FunctionList::HASH_ARGON2 => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 1) {
+                return Err(e);
+            }
+
+            let text = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match string_param(d, "text", &func) {
+                    Ok(t) => t,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let salt = Uuid::new_v4();
+            let config = Config::default();
+            final_data = match argon2::hash_encoded(text.as_bytes(), salt.as_bytes(), &config) {
+                Ok(h) => DefinitionData::STRING(h),
+                Err(e) => {
+                    return Err((
+                        500,
+                        format!("Error: Failed hashing in the {} function -> {}", func, e),
+                    ))
+                }
+            };
+        }
+        FunctionList::VERIFY_ARGON2 => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 2) {
+                return Err(e);
+            }
+
+            let hash = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match string_param(d, "hash", &func) {
+                    Ok(t) => t,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let text = match resolve_param(
+                &function_block,
+                1,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match string_param(d, "text", &func) {
+                    Ok(t) => t,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            final_data = match argon2::verify_encoded(&hash, text.as_bytes()) {
+                Ok(b) => DefinitionData::BOOLEAN(b),
+                Err(_) => return Err(invalid_param("hash", &func)),
+            };
+        }
+        FunctionList::SHA256 => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 1) {
+                return Err(e);
+            }
+
+            let text = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match string_param(d, "text", &func) {
+                    Ok(t) => t,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            final_data = DefinitionData::STRING(format!("{:x}", Sha256::digest(text.as_bytes())));
+        }
+        FunctionList::DATE_ADD => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 2) {
+                return Err(e);
+            }
+
+            let datetime = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match datetime_param(d, "datetime", &func) {
+                    Ok(dt) => dt,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let amount = match resolve_param(
+                &function_block,
+                1,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match integer_param(d, "amount", &func) {
+                    Ok(i) => i,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let unit = match unit_param(
+                &function_block,
+                2,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(u) => u,
+                Err(e) => return Err(e),
+            };
+
+            let multiplier: i64 = match unit.as_str() {
+                "SECONDS" => 1,
+                "MINUTES" => 60,
+                "HOURS" => 3600,
+                "DAYS" => 86400,
+                _ => 604800,
+            };
+
+            let seconds = match (amount as i64).checked_mul(multiplier) {
+                Some(s) if s >= -MAX_DURATION_SECONDS && s <= MAX_DURATION_SECONDS => s,
+                _ => return Err(invalid_param("amount", &func)),
+            };
+
+            final_data = match datetime.checked_add_signed(Duration::seconds(seconds)) {
+                Some(dt) => {
+                    DefinitionData::STRING(dt.format("%Y-%m-%d %H:%M:%S%.f %:z").to_string())
+                }
+                None => return Err(invalid_param("amount", &func)),
+            };
+        }
+        FunctionList::DATE_DIFF => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 2) {
+                return Err(e);
+            }
+
+            let start = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match datetime_param(d, "start", &func) {
+                    Ok(dt) => dt,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let end = match resolve_param(
+                &function_block,
+                1,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match datetime_param(d, "end", &func) {
+                    Ok(dt) => dt,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let unit = match unit_param(
+                &function_block,
+                2,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(u) => u,
+                Err(e) => return Err(e),
+            };
+
+            let difference = end.signed_duration_since(start);
+            final_data = DefinitionData::INTEGER(match unit.as_str() {
+                "SECONDS" => difference.num_seconds(),
+                "MINUTES" => difference.num_minutes(),
+                "HOURS" => difference.num_hours(),
+                "DAYS" => difference.num_days(),
+                _ => difference.num_weeks(),
+            } as isize);
+        }
+        FunctionList::RANDOM_INT => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 2) {
+                return Err(e);
+            }
+
+            let min = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match integer_param(d, "min", &func) {
+                    Ok(i) => i,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let max = match resolve_param(
+                &function_block,
+                1,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match integer_param(d, "max", &func) {
+                    Ok(i) => i,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            if min > max {
+                return Err(invalid_param("max", &func));
+            }
+
+            final_data = DefinitionData::INTEGER(fastrand::isize(min..=max));
+        }
+        FunctionList::JSON_PARSE => {
+            let func = function_block.func.id.clone();
+            if let Err(e) = check_params(&function_block, 1) {
+                return Err(e);
+            }
+
+            let text = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => match string_param(d, "json", &func) {
+                    Ok(t) => t,
+                    Err(e) => return Err(e),
+                },
+                Err(e) => return Err(e),
+            };
+
+            final_data = match serde_json::from_str::<serde_json::Value>(&text) {
+                Ok(v) => value_to_definition(v),
+                Err(_) => return Err(invalid_param("json", &func)),
+            };
+        }
+        FunctionList::JSON_STRINGIFY => {
+            if let Err(e) = check_params(&function_block, 1) {
+                return Err(e);
+            }
+
+            let data = match resolve_param(
+                &function_block,
+                0,
+                global_blocks,
+                all_definitions,
+                current_index,
+            ) {
+                Ok(d) => d,
+                Err(e) => return Err(e),
+            };
+
+            final_data = DefinitionData::STRING(definition_to_value(data).to_string());
+        }
     }
 
     Ok(final_data)
 }
+
+fn check_params(function_block: &FunctionBlock, required: usize) -> Result<(), (usize, String)> {
+    if function_block.func.params.len() < required {
+        return Err((
+            500,
+            format!(
+                "Error: Invalid number of parameters for {} function",
+                function_block.func.id
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn invalid_param(param: &str, func: &FunctionList) -> (usize, String) {
+    (
+        500,
+        format!(
+            "Error: Invalid data type for the '{}' param of the {} function",
+            param, func
+        ),
+    )
+}
+
+fn resolve_param(
+    function_block: &FunctionBlock,
+    position: usize,
+    global_blocks: &Vec<GlobalBlockOrder>,
+    all_definitions: &Vec<DefinitionStore>,
+    current_index: usize,
+) -> Result<DefinitionData, (usize, String)> {
+    resolve_ref_data(
+        &function_block.func.params[position],
+        global_blocks,
+        all_definitions,
+        current_index,
+    )
+}
+
+fn string_param(
+    data: DefinitionData,
+    param: &str,
+    func: &FunctionList,
+) -> Result<String, (usize, String)> {
+    match data {
+        DefinitionData::STRING(s) => Ok(s),
+        DefinitionData::INTEGER(i) => Ok(i.to_string()),
+        DefinitionData::FLOAT(f) => Ok(f.to_string()),
+        DefinitionData::BOOLEAN(b) => Ok(b.to_string()),
+        _ => Err(invalid_param(param, func)),
+    }
+}
+
+fn integer_param(
+    data: DefinitionData,
+    param: &str,
+    func: &FunctionList,
+) -> Result<isize, (usize, String)> {
+    match data {
+        DefinitionData::INTEGER(i) => Ok(i),
+        DefinitionData::FLOAT(f) => Ok(f as isize),
+        DefinitionData::STRING(s) => match s.trim().parse::<isize>() {
+            Ok(i) => Ok(i),
+            Err(_) => Err(invalid_param(param, func)),
+        },
+        _ => Err(invalid_param(param, func)),
+    }
+}
+
+fn datetime_param(
+    data: DefinitionData,
+    param: &str,
+    func: &FunctionList,
+) -> Result<DateTime<FixedOffset>, (usize, String)> {
+    let text = match string_param(data, param, func) {
+        Ok(t) => t,
+        Err(e) => return Err(e),
+    };
+
+    if let Ok(dt) = DateTime::parse_from_str(text.trim(), "%Y-%m-%d %H:%M:%S%.f %:z") {
+        return Ok(dt);
+    }
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(text.trim()) {
+        return Ok(dt);
+    }
+
+    match NaiveDateTime::parse_from_str(
+        text.trim().trim_end_matches("UTC").trim(),
+        "%Y-%m-%d %H:%M:%S%.f",
+    ) {
+        Ok(ndt) => Ok(FixedOffset::east(0).from_utc_datetime(&ndt)),
+        Err(_) => Err(invalid_param(param, func)),
+    }
+}
+
+fn unit_param(
+    function_block: &FunctionBlock,
+    position: usize,
+    global_blocks: &Vec<GlobalBlockOrder>,
+    all_definitions: &Vec<DefinitionStore>,
+    current_index: usize,
+) -> Result<String, (usize, String)> {
+    let func = &function_block.func.id;
+    if function_block.func.params.len() <= position {
+        return Ok(String::from("SECONDS"));
+    }
+
+    let unit = match resolve_param(
+        function_block,
+        position,
+        global_blocks,
+        all_definitions,
+        current_index,
+    ) {
+        Ok(d) => match string_param(d, "unit", func) {
+            Ok(u) => u.trim().to_uppercase(),
+            Err(e) => return Err(e),
+        },
+        Err(e) => return Err(e),
+    };
+
+    let unit = if unit.ends_with("S") {
+        unit
+    } else {
+        format!("{}S", unit)
+    };
+
+    match unit.as_str() {
+        "SECONDS" | "MINUTES" | "HOURS" | "DAYS" | "WEEKS" => Ok(unit),
+        _ => Err(invalid_param("unit", func)),
+    }
+}
@@ -13,6 +13,20 @@ pub fn define_loop(
     all_definitions: &mut Vec<DefinitionStore>,
     current_index: usize,
 ) -> Result<DefinitionData, (usize, String)> {
+    if let Some(each) = loop_block.each {
+        return match resolve_ref_data(&each, global_blocks, all_definitions, current_index) {
+            Ok(DefinitionData::ARRAY(items)) => {
+                if items.len() > 0 {
+                    Ok(items[0].clone())
+                } else {
+                    Ok(DefinitionData::NULL)
+                }
+            }
+            Ok(_) => Err((500, format!("Error: '{}' is not an array", each.data))),
+            Err(e) => Err(e),
+        };
+    }
+
     resolve_ref_data(
         &loop_block.min,
         global_blocks,
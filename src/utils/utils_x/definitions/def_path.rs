@@ -0,0 +1,74 @@
+use crate::{
+    components::routing::{
+        mod_route::RouteComponent, submodules::sub_body_data_type::BodyDataType,
+    },
+    routes::x::x::LocalParamData,
+    utils::x::definition_store::DefinitionData,
+};
+
+pub fn define_path(
+    current_route: &RouteComponent,
+    all_path_params: &Vec<LocalParamData>,
+    index: usize,
+) -> Result<(String, DefinitionData), (usize, String)> {
+    let path_variables = current_route
+        .route_path
+        .split("/")
+        .filter(|segment| segment.starts_with(":"))
+        .map(|segment| segment[1..].to_string())
+        .collect::<Vec<String>>();
+
+    if index >= path_variables.len() {
+        return Ok((String::new(), DefinitionData::NULL));
+    }
+    let id = path_variables[index].clone();
+
+    let mut bdtype = BodyDataType::STRING;
+    for pair in current_route.path_params.iter() {
+        if pair.id == id {
+            bdtype = pair.bdtype.clone();
+            break;
+        }
+    }
+
+    let mut current_value = String::new();
+    for local_path_data in all_path_params {
+        if local_path_data.key == id {
+            current_value = local_path_data.value.clone();
+            break;
+        }
+    }
+
+    let data = match bdtype {
+        BodyDataType::STRING => DefinitionData::STRING(current_value),
+        BodyDataType::INTEGER => DefinitionData::INTEGER(match current_value.parse::<isize>() {
+            Ok(value) => value,
+            Err(_) => {
+                return Err((
+                    400,
+                    format!("Error: Invalid integer value for path segment '{}'", id),
+                ))
+            }
+        }),
+        BodyDataType::FLOAT => DefinitionData::FLOAT(match current_value.parse::<f64>() {
+            Ok(value) => value,
+            Err(_) => {
+                return Err((
+                    400,
+                    format!("Error: Invalid float value for path segment '{}'", id),
+                ))
+            }
+        }),
+        BodyDataType::BOOLEAN => {
+            DefinitionData::BOOLEAN(current_value.to_lowercase().trim() == "true")
+        }
+        _ => {
+            return Err((
+                400,
+                format!("Error: Invalid data type for path segment '{}'", id),
+            ))
+        }
+    };
+
+    Ok((id, data))
+}
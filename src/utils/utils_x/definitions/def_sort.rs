@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+
+use chrono::{DateTime, NaiveDate};
+
+use crate::{
+    components::{
+        raw_pair::{RawPair, StructurePair},
+        routing::{
+            blocks::sort_block::SortBlock,
+            submodules::{sub_sort_direction::SortDirection, sub_sort_key::SortKey},
+        },
+    },
+    utils::x::{
+        definition_store::{DefinitionData, DefinitionStore},
+        global_block_order::GlobalBlockOrder,
+        resolver::resolve_raw_data,
+    },
+};
+
+pub fn define_sort(
+    sort_block: SortBlock,
+    global_blocks: &Vec<GlobalBlockOrder>,
+    all_definitions: &mut Vec<DefinitionStore>,
+    current_index: usize,
+) -> Result<DefinitionData, (usize, String)> {
+    let current_ref_data = match resolve_raw_data(
+        &sort_block.ref_var,
+        &global_blocks,
+        &all_definitions,
+        current_index,
+    ) {
+        Ok(rd) => rd,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+
+    let current_data = match current_ref_data {
+        DefinitionData::ARRAY(a) => a,
+        _ => {
+            return Err((
+                500,
+                format!("Error: Invalid data type for '{}'", sort_block.ref_var),
+            ));
+        }
+    };
+
+    let mut all_keys = vec![SortKey {
+        property: sort_block.ref_property.clone(),
+        direction: sort_block.direction.clone(),
+    }];
+    all_keys.extend(sort_block.keys.clone());
+
+    for key in all_keys.iter() {
+        let broken_property = key.property.split(".").collect::<Vec<&str>>();
+        if broken_property.len() > 2 || broken_property[0].trim().len() < 1 {
+            return Err((
+                500,
+                format!("Error: Invalid property '{}' in sort", key.property),
+            ));
+        }
+    }
+
+    let mut all_pairs = Vec::<RawPair>::new();
+    for raw_pair_data in current_data.iter() {
+        match raw_pair_data {
+            DefinitionData::DATA(d) => all_pairs.push(d.clone()),
+            _ => {
+                return Err((
+                    500,
+                    format!(
+                        "Error: '{}' should only contain data to be sorted",
+                        sort_block.ref_var
+                    ),
+                ));
+            }
+        };
+    }
+
+    all_pairs.sort_by(|a, b| {
+        for key in all_keys.iter() {
+            let left = get_structure(a, &key.property);
+            let right = get_structure(b, &key.property);
+
+            let ordering = match (left, right) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(l), Some(r)) => {
+                    let current_ordering = compare_structures(&l, &r);
+                    if key.direction == SortDirection::DESC {
+                        current_ordering.reverse()
+                    } else {
+                        current_ordering
+                    }
+                }
+            };
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    });
+
+    Ok(DefinitionData::ARRAY(
+        all_pairs
+            .into_iter()
+            .map(|pair| DefinitionData::DATA(pair))
+            .collect::<Vec<DefinitionData>>(),
+    ))
+}
+
+fn get_structure(raw_pair: &RawPair, property: &str) -> Option<StructurePair> {
+    let broken_property = property.split(".").collect::<Vec<&str>>();
+
+    if broken_property.len() < 2 {
+        for structure in raw_pair.structures.iter() {
+            if structure.id == broken_property[0] && structure.value.len() > 0 {
+                return Some(structure.clone());
+            }
+        }
+    } else {
+        for custom_structure in raw_pair.custom_structures.iter() {
+            if custom_structure.id == broken_property[0] {
+                for structure in custom_structure.structures.iter() {
+                    if structure.id == broken_property[1] && structure.value.len() > 0 {
+                        return Some(structure.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn compare_structures(left: &StructurePair, right: &StructurePair) -> Ordering {
+    if left.rtype == "INTEGER" || left.rtype == "FLOAT" {
+        if let (Ok(l), Ok(r)) = (
+            left.value.trim().parse::<f64>(),
+            right.value.trim().parse::<f64>(),
+        ) {
+            return l.partial_cmp(&r).unwrap_or(Ordering::Equal);
+        }
+    }
+
+    if let (Ok(l), Ok(r)) = (
+        DateTime::parse_from_str(left.value.trim(), "%Y-%m-%d %H:%M:%S %:z"),
+        DateTime::parse_from_str(right.value.trim(), "%Y-%m-%d %H:%M:%S %:z"),
+    ) {
+        return l.cmp(&r);
+    }
+
+    if let (Ok(l), Ok(r)) = (
+        NaiveDate::parse_from_str(left.value.trim(), "%Y-%m-%d"),
+        NaiveDate::parse_from_str(right.value.trim(), "%Y-%m-%d"),
+    ) {
+        return l.cmp(&r);
+    }
+
+    left.value.cmp(&right.value)
+}
@@ -21,11 +21,9 @@ use crate::{
         definition_store::{DefinitionData, DefinitionStore},
         global_block_order::GlobalBlockOrder,
         resolver::{resolve_conditions, resolve_operations, resolve_ref_data},
+        transaction::Transaction,
     },
-    utils::{
-        collection::auto_fetch_all_collections, data::auto_fetch_all_data,
-        data::auto_save_all_data, mapping::auto_fetch_all_mappings,
-    },
+    utils::{collection::auto_fetch_all_collections, mapping::auto_fetch_all_mappings},
 };
 
 pub fn define_update(
@@ -34,6 +32,7 @@ pub fn define_update(
     all_definitions: &mut Vec<DefinitionStore>,
     current_index: usize,
     project_id: &str,
+    transaction: &mut Transaction,
 ) -> Result<DefinitionData, (usize, String)> {
     let res_condition = match resolve_conditions(
         &update_block.conditions,
@@ -52,10 +51,10 @@ pub fn define_update(
     }
 
     let mappings = auto_fetch_all_mappings();
-    let all_data = match auto_fetch_all_data(&mappings, &project_id, &update_block.ref_col) {
+    let all_data = match transaction.fetch_data(&mappings, &project_id, &update_block.ref_col) {
         Ok(d) => d,
-        _ => {
-            return Err((500, String::from("Error: Failed fetching data")));
+        Err(e) => {
+            return Err(e);
         }
     };
 
@@ -140,6 +139,17 @@ pub fn define_update(
                 }
             }
 
+            if let Err(e) = transaction.check_comparison(
+                &raw_pair.data_id,
+                &target.field,
+                target.conditions.iter().all(|condition| {
+                    condition.condition_type == ConditionType::EQUAL_TO
+                        || condition.condition_type == ConditionType::NOT_EQUAL_TO
+                }),
+            ) {
+                return Err(e);
+            }
+
             match RefData::create(
                 false,
                 &BodyDataType::to(current_rtype.clone()),
@@ -156,6 +166,15 @@ pub fn define_update(
                             condition.not,
                             &NextConditionType::to(condition.next.clone()),
                         );
+                        if let Some(last_condition) = current_conditions.last_mut() {
+                            if let Err(e) = Condition::set_group(
+                                last_condition,
+                                condition.open,
+                                condition.close,
+                            ) {
+                                return Err(e);
+                            }
+                        }
                     }
 
                     match resolve_conditions(
@@ -279,6 +298,15 @@ pub fn define_update(
             }
 
             if let Some(filter) = update_block.filter.clone() {
+                if let Err(e) = transaction.check_comparison(
+                    &raw_pair.data_id,
+                    &update_block.ref_property,
+                    filter.operation_type == OperationType::EQUAL_TO
+                        || filter.operation_type == OperationType::NOT_EQUAL_TO,
+                ) {
+                    return Err(e);
+                }
+
                 if current_rtype.clone() == BodyDataType::ARRAY
                     && filter.right.rtype != BodyDataType::ARRAY
                 {
@@ -456,22 +484,19 @@ pub fn define_update(
                 &mut final_data_to_save_converted,
                 &collection,
                 raw_pair,
-                true,
+                false,
             ) {
                 return Err(e);
             }
         }
 
-        match auto_save_all_data(
+        if let Err(e) = transaction.stage_data(
             &mappings,
             &project_id,
             &update_block.ref_col,
-            &final_data_to_save_converted,
+            final_data_to_save_converted,
         ) {
-            Err(e) => {
-                return Err((500, e));
-            }
-            _ => {}
+            return Err(e);
         }
     }
 
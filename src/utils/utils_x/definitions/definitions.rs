@@ -4,6 +4,12 @@ pub mod define_body;
 #[path = "def_param.rs"]
 pub mod define_param;
 
+#[path = "def_path.rs"]
+pub mod define_path;
+
+#[path = "def_auth.rs"]
+pub mod define_auth;
+
 #[path = "def_fetch.rs"]
 pub mod define_fetch;
 
@@ -33,3 +39,12 @@ pub mod define_create;
 
 #[path = "def_update.rs"]
 pub mod define_update;
+
+#[path = "def_delete.rs"]
+pub mod define_delete;
+
+#[path = "def_sort.rs"]
+pub mod define_sort;
+
+#[path = "def_call.rs"]
+pub mod define_call;
@@ -21,3 +21,15 @@ pub mod convertors;
 
 #[path = "definitions/definitions.rs"]
 pub mod definitions;
+
+#[path = "Transaction.rs"]
+pub mod transaction;
+
+#[path = "FlowProcessor.rs"]
+pub mod flow_processor;
+
+#[path = "RouteValidator.rs"]
+pub mod route_validator;
+
+#[path = "TestRunner.rs"]
+pub mod test_runner;